@@ -1,10 +1,17 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
+use rayon::prelude::*;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
-use crate::config::I18nConfig;
-use crate::i18n::store::{TranslationLocation, TranslationStore};
+use crate::config::{DynamicKeyPolicy, I18nConfig, PlaceholderSyntax};
+use crate::i18n::icu::{self, IcuSyntaxError, MessageArgument, PluralArgument};
+use crate::i18n::key_finder::KeyKind;
+use crate::i18n::plural;
+use crate::i18n::store::{TranslationEntry, TranslationLocation, TranslationStore, Variants};
+use crate::i18n::suppression::Suppressions;
 use crate::scanner::{CodeScanner, ScannedFile};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +20,10 @@ pub struct AuditReport {
     pub missing: Vec<MissingTranslation>,
     pub unused: Vec<UnusedKey>,
     pub placeholder_issues: Vec<PlaceholderIssue>,
+    #[serde(default)]
+    pub dynamic_keys: Vec<DynamicKeyUsage>,
+    #[serde(default)]
+    pub duplicate_keys: Vec<DuplicateKey>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +33,13 @@ pub struct AuditSummary {
     pub missing_translations: usize,
     pub unused_keys: usize,
     pub placeholder_mismatches: usize,
+    #[serde(default)]
+    pub dynamic_keys: usize,
+    #[serde(default)]
+    pub duplicate_keys: usize,
+    /// Findings left out by `i18nlens-ignore` comments and `"@i18nlens-ignore"` markers.
+    #[serde(default)]
+    pub suppressed: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,12 +59,38 @@ pub struct UnusedKey {
     pub suggestion: Option<FixSuggestion>,
 }
 
+/// Call sites of a template key (`` t(`status.${s}`) ``) or a dynamic key (`t(labelKey)`),
+/// which can only be checked by prefix or not at all.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DynamicKeyUsage {
+    /// The literal prefix of a template key, or the expression of a dynamic key.
+    pub key: String,
+    pub kind: KeyKind,
+    /// Number of translation keys starting with the prefix; `0` for dynamic keys.
+    pub matched_keys: usize,
+    pub used_in: Vec<KeyUsage>,
+}
+
+/// A key that several locale files define for the same locale. Only the last definition loaded
+/// is used, so the others are silently shadowed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateKey {
+    pub key: String,
+    pub locale: String,
+    /// Every definition in load order.
+    pub defined_in: Vec<TranslationLocation>,
+    /// Whether the definitions disagree on the value.
+    pub conflicting: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlaceholderIssue {
     pub key: String,
     pub issue_type: PlaceholderIssueType,
     pub locale_values: HashMap<String, String>,
     pub expected_placeholders: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +99,10 @@ pub enum PlaceholderIssueType {
     Mismatch,
     Missing,
     Extra,
+    SyntaxError,
+    TypeMismatch,
+    MissingOther,
+    IncompletePlural,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,6 +126,14 @@ pub struct AuditResult {
     pub store: TranslationStore,
     pub scanned_files: Vec<ScannedFile>,
     pub used_keys: HashMap<String, Vec<KeyUsage>>,
+    /// Usages of template keys, by literal prefix.
+    pub template_keys: HashMap<String, Vec<KeyUsage>>,
+    /// Usages of dynamic keys, by expression.
+    pub dynamic_keys: HashMap<String, Vec<KeyUsage>>,
+    /// Static keys called with an i18next `context` option at least once.
+    pub context_keys: HashSet<String>,
+    /// Suppression comments of the scanned files that have any.
+    pub suppressions: HashMap<PathBuf, Suppressions>,
 }
 
 impl AuditResult {
@@ -88,12 +144,40 @@ impl AuditResult {
             store,
             scanned_files: Vec::new(),
             used_keys: HashMap::new(),
+            template_keys: HashMap::new(),
+            dynamic_keys: HashMap::new(),
+            context_keys: HashSet::new(),
+            suppressions: HashMap::new(),
         }
     }
 
     pub fn scan_codebase(&mut self) {
-        let scanner = CodeScanner::new(&self.config.function_patterns);
+        let scanner = CodeScanner::new(&self.config.function_patterns)
+            .with_namespaces(self.config.namespaces())
+            .with_ast_languages(self.config.ast_languages())
+            .with_include(&self.config.include)
+            .with_exclude(&self.config.exclude)
+            .with_cache(self.store.cache().cloned());
         self.scanned_files = scanner.scan_directory(&self.workspace_root);
+        if let Some(cache) = self.store.cache() {
+            if let Err(e) = cache.save() {
+                tracing::warn!("Failed to save index cache: {}", e);
+            }
+        }
+
+        let store = &self.store;
+        self.suppressions = self
+            .scanned_files
+            .par_iter()
+            .filter_map(|file| {
+                if file.path.extension().and_then(|e| e.to_str()) == Some("vue") {
+                    store.load_component_translations(&file.path, &file.content);
+                }
+
+                let suppressions = Suppressions::parse(&file.content);
+                (!suppressions.is_empty()).then(|| (file.path.clone(), suppressions))
+            })
+            .collect();
 
         // Aggregate key usages
         for file in &self.scanned_files {
@@ -104,10 +188,15 @@ impl AuditResult {
                     column: found.start_char,
                     code: found.code_snippet.clone(),
                 };
-                self.used_keys
-                    .entry(found.key.clone())
-                    .or_default()
-                    .push(usage);
+                if found.context && found.kind == KeyKind::Static {
+                    self.context_keys.insert(found.key.clone());
+                }
+                let usages = match found.kind {
+                    KeyKind::Static => &mut self.used_keys,
+                    KeyKind::Template => &mut self.template_keys,
+                    KeyKind::Dynamic => &mut self.dynamic_keys,
+                };
+                usages.entry(found.key.clone()).or_default().push(usage);
             }
         }
     }
@@ -116,17 +205,43 @@ impl AuditResult {
         let all_keys = self.store.get_all_keys();
         let all_locales = self.store.get_locales();
         let source_locale = &self.config.source_locale;
+        let mut suppressed = 0;
+
+        // Keys under an `"@i18nlens-ignore"` marker are left out of every check.
+        let checked_keys: Vec<String> = all_keys
+            .iter()
+            .filter(|key| !self.store.is_ignored_key(key))
+            .cloned()
+            .collect();
+        suppressed += all_keys.len() - checked_keys.len();
 
         // Find missing translations
         let mut missing = Vec::new();
-        for key in &all_keys {
-            let missing_locales = self.store.get_missing_locales(key);
+        for key in &checked_keys {
+            let mut missing_locales = self.store.get_missing_locales(key, Variants::Plural);
+            // `item_one` is not missing in `ja`: its plural rules only use `item_other`.
+            if let Some((base, category)) = split_plural_suffix(key) {
+                missing_locales.retain(|locale| {
+                    plural::plural_categories(locale)
+                        .map_or(true, |required| required.contains(&category))
+                        || self.store.get_translation(base, locale).is_none()
+                });
+            }
             if missing_locales.is_empty() {
                 continue;
             }
 
             // Only report if key is used in code or configured to check all
-            let usages = self.used_keys.get(key).cloned().unwrap_or_default();
+            let mut usages = self.used_keys.get(key).cloned().unwrap_or_default();
+            if !usages.is_empty() {
+                usages.retain(|usage| {
+                    !self.is_suppressed(usage, &["missing-translation", "incomplete-translation"])
+                });
+                if usages.is_empty() {
+                    suppressed += 1;
+                    continue;
+                }
+            }
 
             let source_value = self
                 .store
@@ -156,10 +271,32 @@ impl AuditResult {
             });
         }
 
+        // `t("item", { count })` uses `item_one`, `item_other`, …
+        let used_variants: HashSet<String> = self
+            .used_keys
+            .keys()
+            .flat_map(|key| {
+                let lookup = Variants::for_call(self.context_keys.contains(key));
+                self.store.get_key_variants(key, lookup)
+            })
+            .collect();
+
+        // Keys reachable through `` t(`status.${s}`) `` or an allowed dynamic prefix are used.
+        let used_by_prefix = |key: &str| {
+            self.config.is_allowed_dynamic_key(key)
+                || self
+                    .template_keys
+                    .keys()
+                    .any(|prefix| key.starts_with(prefix.as_str()))
+        };
+
         // Find unused keys
         let mut unused = Vec::new();
-        for key in &all_keys {
-            if !self.used_keys.contains_key(key) {
+        for key in &checked_keys {
+            if !self.used_keys.contains_key(key)
+                && !used_variants.contains(key)
+                && !used_by_prefix(key)
+            {
                 if let Some(location) = self.store.get_translation_location(key, source_locale) {
                     unused.push(UnusedKey {
                         key: key.clone(),
@@ -175,7 +312,39 @@ impl AuditResult {
         }
 
         // Find placeholder issues
-        let placeholder_issues = self.validate_placeholders(&all_keys, &all_locales);
+        let mut placeholder_issues = self.validate_placeholders(&checked_keys, &all_locales);
+        placeholder_issues.extend(self.validate_plural_suffixes(&checked_keys, &all_locales));
+
+        let mut dynamic_keys = self.dynamic_key_usages();
+        dynamic_keys.retain_mut(|item| {
+            let codes: &[&str] = match item.kind {
+                KeyKind::Template => &["missing-translation-prefix", "dynamic-translation-key"],
+                _ => &["dynamic-translation-key"],
+            };
+            item.used_in
+                .retain(|usage| !self.is_suppressed(usage, codes));
+            let keep = !item.used_in.is_empty();
+            suppressed += usize::from(!keep);
+            keep
+        });
+
+        let mut duplicate_keys = Vec::new();
+        for conflict in self.store.get_key_conflicts() {
+            if self.store.is_ignored_key(&conflict.key) {
+                suppressed += 1;
+                continue;
+            }
+            duplicate_keys.push(DuplicateKey {
+                conflicting: !conflict.has_same_value(),
+                defined_in: conflict
+                    .definitions
+                    .iter()
+                    .map(TranslationEntry::location)
+                    .collect(),
+                key: conflict.key,
+                locale: conflict.locale,
+            });
+        }
 
         AuditReport {
             summary: AuditSummary {
@@ -184,51 +353,251 @@ impl AuditResult {
                 missing_translations: missing.len(),
                 unused_keys: unused.len(),
                 placeholder_mismatches: placeholder_issues.len(),
+                dynamic_keys: dynamic_keys.len(),
+                duplicate_keys: duplicate_keys.len(),
+                suppressed,
             },
             missing,
             unused,
             placeholder_issues,
+            dynamic_keys,
+            duplicate_keys,
         }
     }
 
+    /// Whether a comment in the usage's file suppresses any of the diagnostic `codes` on its line.
+    fn is_suppressed(&self, usage: &KeyUsage, codes: &[&str]) -> bool {
+        self.suppressions
+            .get(&usage.file)
+            .is_some_and(|suppressions| {
+                codes
+                    .iter()
+                    .any(|code| suppressions.is_suppressed(usage.line, code))
+            })
+    }
+
+    /// Template and dynamic keys to report under `dynamicKeyPolicy`. Template keys are only
+    /// reported when no translation key starts with their prefix, unless the prefix is under
+    /// `allowedDynamicPrefixes`.
+    fn dynamic_key_usages(&self) -> Vec<DynamicKeyUsage> {
+        if self.config.dynamic_key_policy == DynamicKeyPolicy::Ignore {
+            return Vec::new();
+        }
+
+        let templates = self
+            .template_keys
+            .iter()
+            .filter(|(prefix, _)| !self.config.is_allowed_dynamic_key(prefix))
+            .map(|(prefix, usages)| DynamicKeyUsage {
+                key: prefix.clone(),
+                kind: KeyKind::Template,
+                matched_keys: self.store.get_keys_with_prefix(prefix).len(),
+                used_in: usages.clone(),
+            })
+            .filter(|usage| usage.matched_keys == 0);
+        let dynamic = self
+            .dynamic_keys
+            .iter()
+            .map(|(expression, usages)| DynamicKeyUsage {
+                key: expression.clone(),
+                kind: KeyKind::Dynamic,
+                matched_keys: 0,
+                used_in: usages.clone(),
+            });
+
+        let mut usages: Vec<DynamicKeyUsage> = templates.chain(dynamic).collect();
+        usages.sort_by(|a, b| (a.kind, &a.key).cmp(&(b.kind, &b.key)));
+        usages
+    }
+
     fn validate_placeholders(&self, keys: &[String], locales: &[String]) -> Vec<PlaceholderIssue> {
         let mut issues = Vec::new();
+        // Keep per-locale details in a stable order.
+        let mut locales = locales.to_vec();
+        locales.sort();
 
         for key in keys {
-            let mut locale_values = HashMap::new();
-            let mut all_placeholders: HashSet<String> = HashSet::new();
+            let mut shapes = Vec::new();
+            let mut syntax_errors = Vec::new();
 
-            for locale in locales {
+            for locale in &locales {
                 if let Some(value) = self.store.get_translation(key, locale) {
-                    let placeholders = extract_placeholders(&value);
-                    for p in &placeholders {
-                        all_placeholders.insert(p.clone());
+                    match analyze_message(&value, self.config.placeholder_syntax) {
+                        Ok(shape) => shapes.push((locale.clone(), value, shape)),
+                        Err(error) => syntax_errors.push((locale.clone(), value, error)),
                     }
-                    locale_values.insert(locale.clone(), value);
                 }
             }
 
-            if all_placeholders.is_empty() {
+            let expected: Vec<String> = shapes
+                .iter()
+                .flat_map(|(_, _, shape)| shape.placeholders.iter().cloned())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            if !syntax_errors.is_empty() {
+                let details = syntax_errors
+                    .iter()
+                    .map(|(locale, _, error)| format!("{}: {}", locale, error))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                issues.push(PlaceholderIssue {
+                    key: key.clone(),
+                    issue_type: PlaceholderIssueType::SyntaxError,
+                    locale_values: syntax_errors
+                        .into_iter()
+                        .map(|(locale, value, _)| (locale, value))
+                        .collect(),
+                    expected_placeholders: expected.clone(),
+                    details: Some(details),
+                });
+            }
+
+            if expected.is_empty() {
                 continue;
             }
 
             // Check for mismatches
-            let expected: Vec<String> = all_placeholders.iter().cloned().collect();
-            let mut mismatched_locales = HashMap::new();
-
-            for (locale, value) in &locale_values {
-                let placeholders = extract_placeholders(value);
-                if placeholders != expected {
-                    mismatched_locales.insert(locale.clone(), value.clone());
-                }
-            }
+            let mismatched_locales: HashMap<String, String> = shapes
+                .iter()
+                .filter(|(_, _, shape)| shape.placeholders != expected)
+                .map(|(locale, value, _)| (locale.clone(), value.clone()))
+                .collect();
 
             if !mismatched_locales.is_empty() {
                 issues.push(PlaceholderIssue {
                     key: key.clone(),
                     issue_type: PlaceholderIssueType::Mismatch,
                     locale_values: mismatched_locales,
-                    expected_placeholders: expected,
+                    expected_placeholders: expected.clone(),
+                    details: None,
+                });
+            }
+
+            if let Some(issue) = argument_type_mismatch(key, &shapes, &expected) {
+                issues.push(issue);
+            }
+
+            let missing_other: Vec<_> = shapes
+                .iter()
+                .filter(|(_, _, shape)| !shape.missing_other.is_empty())
+                .collect();
+            if !missing_other.is_empty() {
+                let details = missing_other
+                    .iter()
+                    .map(|(locale, _, shape)| {
+                        format!(
+                            "{}: {} has no 'other' branch",
+                            locale,
+                            shape.missing_other.join(", ")
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                issues.push(PlaceholderIssue {
+                    key: key.clone(),
+                    issue_type: PlaceholderIssueType::MissingOther,
+                    locale_values: missing_other
+                        .into_iter()
+                        .map(|(locale, value, _)| (locale.clone(), value.clone()))
+                        .collect(),
+                    expected_placeholders: expected.clone(),
+                    details: Some(details),
+                });
+            }
+
+            if let Some(issue) = incomplete_plural_branches(key, &shapes, &expected) {
+                issues.push(issue);
+            }
+        }
+
+        issues
+    }
+
+    /// Check i18next plural suffix groups (`item_one`, `item_other`, …) against the CLDR
+    /// categories each locale needs.
+    fn validate_plural_suffixes(
+        &self,
+        keys: &[String],
+        locales: &[String],
+    ) -> Vec<PlaceholderIssue> {
+        let mut groups: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for key in keys {
+            if let Some((base, category)) = split_plural_suffix(key) {
+                groups.entry(base).or_default().insert(category);
+            }
+        }
+
+        let mut locales = locales.to_vec();
+        locales.sort();
+
+        let mut issues = Vec::new();
+        for (base, categories) in groups {
+            // A lone `something_one` is more likely a regular key than a plural group.
+            if !categories.contains("other") && categories.len() < 2 {
+                continue;
+            }
+
+            let mut locale_values = HashMap::new();
+            let mut placeholders = BTreeSet::new();
+            let mut details = Vec::new();
+
+            for locale in &locales {
+                let Some(required) = plural::plural_categories(locale) else {
+                    continue;
+                };
+                let present: Vec<(&str, String)> = plural::CATEGORIES
+                    .iter()
+                    .filter_map(|category| {
+                        let value = self
+                            .store
+                            .get_translation(&format!("{}_{}", base, category), locale)?;
+                        Some((*category, value))
+                    })
+                    .collect();
+                if present.is_empty() {
+                    continue;
+                }
+
+                let missing: Vec<&str> = required
+                    .iter()
+                    .copied()
+                    .filter(|category| present.iter().all(|(found, _)| found != category))
+                    .collect();
+                if missing.is_empty() {
+                    continue;
+                }
+
+                for (_, value) in &present {
+                    placeholders.extend(extract_placeholders_with(
+                        value,
+                        self.config.placeholder_syntax,
+                    ));
+                }
+                let (_, value) = present
+                    .iter()
+                    .find(|(category, _)| *category == "other")
+                    .unwrap_or(&present[0]);
+                locale_values.insert(locale.clone(), value.clone());
+                details.push(format!(
+                    "{}: missing {}",
+                    locale,
+                    missing
+                        .iter()
+                        .map(|category| format!("{}_{}", base, category))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+
+            if !locale_values.is_empty() {
+                issues.push(PlaceholderIssue {
+                    key: base.to_string(),
+                    issue_type: PlaceholderIssueType::IncompletePlural,
+                    locale_values,
+                    expected_placeholders: placeholders.into_iter().collect(),
+                    details: Some(details.join("; ")),
                 });
             }
         }
@@ -254,38 +623,226 @@ impl AuditResult {
     }
 }
 
-fn extract_placeholders(value: &str) -> Vec<String> {
-    let mut placeholders = Vec::new();
+struct MessageShape {
+    /// Sorted, distinct placeholder names across all supported syntaxes.
+    placeholders: Vec<String>,
+    arguments: Vec<MessageArgument>,
+    missing_other: Vec<String>,
+    plurals: Vec<PluralArgument>,
+}
+
+/// Parse a translation value as ICU MessageFormat, also picking up `{{name}}` (i18next, Vue)
+/// and printf-style (`%s`, `%d`) placeholders, and Laravel `:name` ones for that syntax.
+fn analyze_message(value: &str, syntax: PlaceholderSyntax) -> Result<MessageShape, IcuSyntaxError> {
+    static DOUBLE_BRACE_REGEX: OnceLock<Regex> = OnceLock::new();
+    let double_brace_regex = DOUBLE_BRACE_REGEX.get_or_init(|| {
+        Regex::new(r"\{\{\s*([\w.-]+)\s*(?:,[^{}]*)?\}\}").expect("double brace regex")
+    });
+
+    // `{{name}}` is not valid ICU, so only strip it when the raw value does not parse; that
+    // keeps `other {{count} items}` intact.
+    let (parsed, interpolations) = match icu::parse(value) {
+        Ok(parsed) => (parsed, Vec::new()),
+        Err(error) => {
+            if !double_brace_regex.is_match(value) {
+                return Err(error);
+            }
+            let stripped = double_brace_regex.replace_all(value, "");
+            let names = double_brace_regex
+                .captures_iter(value)
+                .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
+                .collect();
+            (icu::parse(&stripped)?, names)
+        }
+    };
+
+    let mut placeholders: BTreeSet<String> = interpolations.into_iter().collect();
+    placeholders.extend(parsed.arguments.iter().map(|arg| arg.name.clone()));
 
-    // Match {{name}} pattern (Handlebars, Vue, etc.)
-    let double_brace_regex = regex::Regex::new(r"\{\{(\w+)\}\}").unwrap();
-    for cap in double_brace_regex.captures_iter(value) {
-        if let Some(m) = cap.get(1) {
-            placeholders.push(m.as_str().to_string());
+    // Match %s, %d patterns (printf-style)
+    for cap in printf_regex().captures_iter(value) {
+        if let Some(m) = cap.get(0) {
+            placeholders.insert(m.as_str().to_string());
         }
     }
+    if syntax == PlaceholderSyntax::Laravel {
+        placeholders.extend(colon_placeholders(value));
+    }
+
+    Ok(MessageShape {
+        placeholders: placeholders.into_iter().collect(),
+        arguments: parsed.arguments,
+        missing_other: parsed.missing_other,
+        plurals: parsed.plurals,
+    })
+}
+
+/// Placeholder names used by a translation value. Values that are not valid ICU messages fall
+/// back to a lenient scan so callers can still compare them.
+pub fn extract_placeholders(value: &str) -> Vec<String> {
+    extract_placeholders_with(value, PlaceholderSyntax::Icu)
+}
+
+/// Like [`extract_placeholders`], also reading the spellings of `syntax`.
+pub fn extract_placeholders_with(value: &str, syntax: PlaceholderSyntax) -> Vec<String> {
+    if let Ok(shape) = analyze_message(value, syntax) {
+        return shape.placeholders;
+    }
 
-    // Match {name} pattern (ICU, Flutter, etc.)
-    let single_brace_regex = regex::Regex::new(r"\{(\w+)\}").unwrap();
-    for cap in single_brace_regex.captures_iter(value) {
-        if let Some(m) = cap.get(1) {
-            let p = m.as_str().to_string();
-            if !placeholders.contains(&p) {
-                placeholders.push(p);
+    static LENIENT_REGEX: OnceLock<Regex> = OnceLock::new();
+    let lenient_regex = LENIENT_REGEX
+        .get_or_init(|| Regex::new(r"\{+\s*([\w.-]+)\s*[,}]").expect("lenient placeholder regex"));
+    let placeholders: BTreeSet<String> = lenient_regex
+        .captures_iter(value)
+        .filter_map(|cap| cap.get(1))
+        .chain(printf_regex().find_iter(value))
+        .map(|m| m.as_str().to_string())
+        .chain(
+            (syntax == PlaceholderSyntax::Laravel)
+                .then(|| colon_placeholders(value))
+                .into_iter()
+                .flatten(),
+        )
+        .collect();
+    placeholders.into_iter().collect()
+}
+
+/// Laravel `:name` placeholders, lowercased since `:Name` and `:NAME` only change the case of
+/// the same argument.
+fn colon_placeholders(value: &str) -> Vec<String> {
+    static COLON_REGEX: OnceLock<Regex> = OnceLock::new();
+    COLON_REGEX
+        .get_or_init(|| {
+            Regex::new(r"(?:^|[^\w:])(:[A-Za-z_]\w*)").expect("colon placeholder regex")
+        })
+        .captures_iter(value)
+        .filter_map(|cap| cap.get(1))
+        .map(|m| m.as_str().to_lowercase())
+        .collect()
+}
+
+/// printf-style `%s` and `%d` placeholders.
+fn printf_regex() -> &'static Regex {
+    static PRINTF_REGEX: OnceLock<Regex> = OnceLock::new();
+    PRINTF_REGEX.get_or_init(|| Regex::new(r"%(\w)").expect("printf placeholder regex"))
+}
+
+/// Report arguments whose ICU type differs between locales, e.g. `plural` in one locale and
+/// `select` in another. A plain `{count}` is accepted where other locales format it as a number.
+fn argument_type_mismatch(
+    key: &str,
+    shapes: &[(String, String, MessageShape)],
+    expected: &[String],
+) -> Option<PlaceholderIssue> {
+    let mut conflicts = Vec::new();
+    let mut locales = BTreeSet::new();
+
+    for name in expected {
+        let mut kinds: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (locale, _, shape) in shapes {
+            if let Some(argument) = shape.arguments.iter().find(|arg| &arg.name == name) {
+                kinds
+                    .entry(argument_category(&argument.kind))
+                    .or_default()
+                    .push(locale);
             }
         }
+
+        let compatible = kinds.len() <= 1
+            || (kinds.len() == 2 && kinds.contains_key("string") && kinds.contains_key("number"));
+        if compatible {
+            continue;
+        }
+
+        let described: Vec<String> = shapes
+            .iter()
+            .filter_map(|(locale, _, shape)| {
+                let argument = shape.arguments.iter().find(|arg| &arg.name == name)?;
+                locales.insert(locale.clone());
+                Some(format!("{} in {}", argument.kind, locale))
+            })
+            .collect();
+        conflicts.push(format!("{}: {}", name, described.join(", ")));
     }
 
-    // Match %s, %d patterns (printf-style)
-    let printf_regex = regex::Regex::new(r"%(\w)").unwrap();
-    for cap in printf_regex.captures_iter(value) {
-        if let Some(m) = cap.get(0) {
-            placeholders.push(m.as_str().to_string());
+    if conflicts.is_empty() {
+        return None;
+    }
+
+    Some(PlaceholderIssue {
+        key: key.to_string(),
+        issue_type: PlaceholderIssueType::TypeMismatch,
+        locale_values: shapes
+            .iter()
+            .filter(|(locale, _, _)| locales.contains(locale))
+            .map(|(locale, value, _)| (locale.clone(), value.clone()))
+            .collect(),
+        expected_placeholders: expected.to_vec(),
+        details: Some(conflicts.join("; ")),
+    })
+}
+
+/// Report ICU `plural` arguments that lack branches for categories the locale's CLDR rules use,
+/// such as `few`/`many` in Russian. Explicit `=N` branches do not count towards a category.
+fn incomplete_plural_branches(
+    key: &str,
+    shapes: &[(String, String, MessageShape)],
+    expected: &[String],
+) -> Option<PlaceholderIssue> {
+    let mut locale_values = HashMap::new();
+    let mut details = Vec::new();
+
+    for (locale, value, shape) in shapes {
+        let Some(required) = plural::plural_categories(locale) else {
+            continue;
+        };
+
+        let mut gaps = Vec::new();
+        for argument in &shape.plurals {
+            let missing: Vec<&str> = required
+                .iter()
+                .copied()
+                .filter(|category| !argument.selectors.iter().any(|s| s == category))
+                .collect();
+            if !missing.is_empty() {
+                gaps.push(format!("{} missing {}", argument.name, missing.join(", ")));
+            }
+        }
+
+        if !gaps.is_empty() {
+            locale_values.insert(locale.clone(), value.clone());
+            details.push(format!("{}: {}", locale, gaps.join(", ")));
         }
     }
 
-    placeholders.sort();
-    placeholders
+    if locale_values.is_empty() {
+        return None;
+    }
+
+    Some(PlaceholderIssue {
+        key: key.to_string(),
+        issue_type: PlaceholderIssueType::IncompletePlural,
+        locale_values,
+        expected_placeholders: expected.to_vec(),
+        details: Some(details.join("; ")),
+    })
+}
+
+/// Split an i18next cardinal plural key such as `item_few` into `("item", "few")`. Ordinal
+/// keys (`place_ordinal_one`) are left alone.
+fn split_plural_suffix(key: &str) -> Option<(&str, &str)> {
+    let (base, category) = key.rsplit_once('_')?;
+    if base.is_empty() || base.ends_with("_ordinal") || !plural::CATEGORIES.contains(&category) {
+        return None;
+    }
+    Some((base, category))
+}
+
+fn argument_category(kind: &str) -> &str {
+    match kind {
+        "plural" | "selectordinal" | "number" | "spellout" | "ordinal" | "duration" => "number",
+        other => other,
+    }
 }
 
 #[cfg(test)]
@@ -311,6 +868,17 @@ mod tests {
         assert_eq!(placeholders, vec!["%s", "count", "name"]);
     }
 
+    #[test]
+    fn extracts_laravel_colon_placeholders_for_laravel_syntax() {
+        let value = "Welcome, :Name! Visit https://example.com at 10:30 or :url";
+
+        assert!(extract_placeholders(value).is_empty());
+        assert_eq!(
+            extract_placeholders_with(value, PlaceholderSyntax::Laravel),
+            vec![":name", ":url"]
+        );
+    }
+
     #[test]
     fn reports_single_locale_placeholder_mismatch() {
         let workspace = temp_workspace("audit-placeholders");
@@ -325,7 +893,8 @@ mod tests {
             .expect("write vi translations");
 
         let config = I18nConfig::default();
-        let store = TranslationStore::new(workspace.clone());
+        let store = TranslationStore::new(workspace.clone())
+            .with_literal_key_paths(&config.literal_key_paths);
         store.scan_and_load(&config.locale_paths);
 
         let audit = AuditResult::new(workspace.clone(), config, store);
@@ -336,4 +905,198 @@ mod tests {
 
         fs::remove_dir_all(workspace).expect("cleanup temp workspace");
     }
+
+    #[test]
+    fn extracts_icu_arguments_without_branch_text() {
+        let placeholders = extract_placeholders(
+            "{count, plural, one {# item from {sender}} other {# items}} - it''s %d",
+        );
+
+        assert_eq!(placeholders, vec!["%d", "count", "sender"]);
+    }
+
+    #[test]
+    fn reports_icu_syntax_type_and_missing_other_issues() {
+        let workspace = temp_workspace("audit-icu");
+        let locales_dir = workspace.join("locales");
+        fs::create_dir_all(&locales_dir).expect("create locales dir");
+        fs::write(
+            locales_dir.join("en.json"),
+            r#"{
+                "broken": "Hello {name",
+                "gender": "{who, select, male {He} other {They}} replied",
+                "items": "{count, plural, one {# item} other {# items}}",
+                "plain": "{count} items"
+            }"#,
+        )
+        .expect("write en translations");
+        fs::write(
+            locales_dir.join("fr.json"),
+            r#"{
+                "broken": "Bonjour {name}",
+                "gender": "{who, plural, one {Il} other {Ils}} a répondu",
+                "items": "{count, plural, one {# article}}",
+                "plain": "{count, number} articles"
+            }"#,
+        )
+        .expect("write fr translations");
+
+        let config = I18nConfig::default();
+        let store = TranslationStore::new(workspace.clone())
+            .with_literal_key_paths(&config.literal_key_paths);
+        store.scan_and_load(&config.locale_paths);
+
+        let report = AuditResult::new(workspace.clone(), config, store).generate_report();
+        let issue = |key: &str| {
+            report
+                .placeholder_issues
+                .iter()
+                .find(|issue| issue.key == key)
+                .unwrap_or_else(|| panic!("expected issue for {key}"))
+        };
+
+        let broken = issue("broken");
+        assert!(matches!(
+            broken.issue_type,
+            PlaceholderIssueType::SyntaxError
+        ));
+        assert!(broken.locale_values.contains_key("en"));
+        assert!(broken.details.as_deref().unwrap().contains("Unclosed"));
+
+        let gender = issue("gender");
+        assert!(matches!(
+            gender.issue_type,
+            PlaceholderIssueType::TypeMismatch
+        ));
+        assert_eq!(
+            gender.details.as_deref(),
+            Some("who: select in en, plural in fr")
+        );
+
+        let items = issue("items");
+        assert!(matches!(
+            items.issue_type,
+            PlaceholderIssueType::MissingOther
+        ));
+        assert_eq!(items.locale_values.keys().collect::<Vec<_>>(), vec!["fr"]);
+
+        assert!(report
+            .placeholder_issues
+            .iter()
+            .all(|issue| issue.key != "plain"));
+
+        fs::remove_dir_all(workspace).expect("cleanup temp workspace");
+    }
+
+    #[test]
+    fn counts_i18next_variants_as_used_only_for_matching_calls() {
+        let workspace = temp_workspace("audit-variants");
+        fs::create_dir_all(workspace.join("locales")).expect("create locales dir");
+        fs::create_dir_all(workspace.join("src")).expect("create src dir");
+        fs::write(
+            workspace.join("locales/en.json"),
+            r#"{
+                "save": "Save",
+                "save_draft": "Save draft",
+                "item_one": "{{count}} item",
+                "item_other": "{{count}} items",
+                "friend_male": "A boyfriend",
+                "friend_female": "A girlfriend"
+            }"#,
+        )
+        .expect("write en translations");
+        fs::write(
+            workspace.join("src/App.tsx"),
+            r#"export const App = ({ n, g }) => [t("save"), t("item", { count: n }), t("friend", { context: g })];"#,
+        )
+        .expect("write source");
+
+        let config = I18nConfig::default();
+        let store = TranslationStore::new(workspace.clone());
+        store.scan_and_load(&config.locale_paths);
+        let mut result = AuditResult::new(workspace.clone(), config, store);
+        result.scan_codebase();
+
+        let report = result.generate_report();
+        let unused: Vec<&str> = report.unused.iter().map(|item| item.key.as_str()).collect();
+        assert_eq!(unused, vec!["save_draft"]);
+
+        fs::remove_dir_all(workspace).ok();
+    }
+
+    #[test]
+    fn reports_plural_forms_missing_for_cldr_locale_rules() {
+        let workspace = temp_workspace("audit-plurals");
+        let locales_dir = workspace.join("locales");
+        fs::create_dir_all(&locales_dir).expect("create locales dir");
+        fs::write(
+            locales_dir.join("en.json"),
+            r#"{
+                "apple_one": "{{count}} apple",
+                "apple_other": "{{count}} apples",
+                "done_one": "Done",
+                "files": "{count, plural, one {# file} other {# files}}"
+            }"#,
+        )
+        .expect("write en translations");
+        fs::write(
+            locales_dir.join("ru.json"),
+            r#"{
+                "apple_one": "{{count}} яблоко",
+                "apple_few": "{{count}} яблока",
+                "apple_other": "{{count}} яблок",
+                "done_one": "Готово",
+                "files": "{count, plural, one {# файл} few {# файла} many {# файлов} other {# файла}}"
+            }"#,
+        )
+        .expect("write ru translations");
+        fs::write(
+            locales_dir.join("ja.json"),
+            r#"{
+                "apple_other": "{{count}} 個のりんご",
+                "files": "{count, plural, other {# ファイル}}"
+            }"#,
+        )
+        .expect("write ja translations");
+        fs::write(
+            locales_dir.join("pl.json"),
+            r#"{
+                "files": "{count, plural, =0 {Brak plików} one {# plik} other {# plików}}"
+            }"#,
+        )
+        .expect("write pl translations");
+
+        let config = I18nConfig::default();
+        let store = TranslationStore::new(workspace.clone())
+            .with_literal_key_paths(&config.literal_key_paths);
+        store.scan_and_load(&config.locale_paths);
+
+        let report = AuditResult::new(workspace.clone(), config, store).generate_report();
+        let plural_issues: Vec<_> = report
+            .placeholder_issues
+            .iter()
+            .filter(|issue| matches!(issue.issue_type, PlaceholderIssueType::IncompletePlural))
+            .collect();
+        assert_eq!(plural_issues.len(), 2);
+
+        let apple = plural_issues
+            .iter()
+            .find(|issue| issue.key == "apple")
+            .expect("suffix group issue");
+        assert_eq!(apple.locale_values.keys().collect::<Vec<_>>(), vec!["ru"]);
+        assert_eq!(apple.details.as_deref(), Some("ru: missing apple_many"));
+        assert_eq!(apple.expected_placeholders, vec!["count"]);
+
+        let files = plural_issues
+            .iter()
+            .find(|issue| issue.key == "files")
+            .expect("icu plural issue");
+        assert_eq!(files.locale_values.keys().collect::<Vec<_>>(), vec!["pl"]);
+        assert_eq!(
+            files.details.as_deref(),
+            Some("pl: count missing few, many")
+        );
+
+        fs::remove_dir_all(workspace).expect("cleanup temp workspace");
+    }
 }
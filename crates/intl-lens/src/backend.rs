@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -8,10 +9,70 @@ use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
-use crate::config::{DisplayMode, I18nConfig};
+use crate::cache::IndexCache;
+use crate::config::{DisplayMode, DynamicKeyPolicy, I18nConfig};
 use crate::document::DocumentStore;
+use crate::i18n::key_finder::{FoundKey, KeyKind};
+use crate::i18n::locator::locate_keys;
+use crate::i18n::store::{plural_group_key, Variants};
+use crate::i18n::suppression::Suppressions;
 use crate::i18n::{KeyFinder, TranslationStore};
 
+/// Report template keys without matching translations and dynamic keys per `dynamicKeyPolicy`.
+fn dynamic_key_diagnostic(
+    found_key: &FoundKey,
+    store: &TranslationStore,
+    config: &I18nConfig,
+) -> Option<Diagnostic> {
+    let (severity, dynamic_severity) = match config.dynamic_key_policy {
+        DynamicKeyPolicy::Ignore => return None,
+        DynamicKeyPolicy::Warn => (DiagnosticSeverity::WARNING, DiagnosticSeverity::INFORMATION),
+        DynamicKeyPolicy::Error => (DiagnosticSeverity::ERROR, DiagnosticSeverity::ERROR),
+    };
+
+    let (severity, code, message) = match found_key.kind {
+        KeyKind::Static => return None,
+        KeyKind::Template => {
+            if config.is_allowed_dynamic_key(&found_key.key)
+                || !store.get_keys_with_prefix(&found_key.key).is_empty()
+            {
+                return None;
+            }
+            (
+                severity,
+                "missing-translation-prefix",
+                format!("No translation keys start with '{}'", found_key.key),
+            )
+        }
+        KeyKind::Dynamic => (
+            dynamic_severity,
+            "dynamic-translation-key",
+            format!(
+                "Translation key '{}' is computed at runtime and cannot be checked",
+                found_key.key
+            ),
+        ),
+    };
+
+    Some(Diagnostic {
+        range: Range {
+            start: Position {
+                line: found_key.line as u32,
+                character: found_key.start_char as u32,
+            },
+            end: Position {
+                line: found_key.line as u32,
+                character: found_key.end_char as u32,
+            },
+        },
+        severity: Some(severity),
+        code: Some(NumberOrString::String(code.to_string())),
+        source: Some("i18n".to_string()),
+        message,
+        ..Default::default()
+    })
+}
+
 fn truncate_string(s: &str, max_chars: usize) -> String {
     if s.chars().count() <= max_chars {
         return s.to_string();
@@ -58,10 +119,17 @@ impl I18nBackend {
         let config = I18nConfig::load_from_workspace(&root);
         tracing::info!("Config loaded, locale_paths: {:?}", config.locale_paths);
 
-        let key_finder = KeyFinder::new(&config.function_patterns);
+        let key_finder = KeyFinder::new(&config.function_patterns)
+            .with_namespaces(config.namespaces())
+            .with_ast_languages(config.ast_languages());
         *self.key_finder.write().await = key_finder;
 
-        let store = TranslationStore::new(root.clone());
+        let store = TranslationStore::new(root.clone())
+            .with_literal_key_paths(&config.literal_key_paths)
+            .with_namespaces(config.namespaces())
+            .with_exclude(&config.exclude)
+            .with_source_locale(&config.source_locale)
+            .with_cache(Some(IndexCache::open_or_in_memory(&root, &config)));
         store.scan_and_load(&config.locale_paths);
 
         let locales = store.get_locales();
@@ -146,6 +214,61 @@ impl I18nBackend {
                 scheme: None,
                 pattern: None,
             },
+            DocumentFilter {
+                language: Some("python".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("jinja".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("ruby".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("erb".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("go".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("elixir".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("heex".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("kotlin".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("java".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("swift".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("rust".to_string()),
+                scheme: None,
+                pattern: None,
+            },
             DocumentFilter {
                 language: Some("vue".to_string()),
                 scheme: None,
@@ -156,6 +279,16 @@ impl I18nBackend {
                 scheme: None,
                 pattern: None,
             },
+            DocumentFilter {
+                language: Some("astro".to_string()),
+                scheme: None,
+                pattern: None,
+            },
+            DocumentFilter {
+                language: Some("mdx".to_string()),
+                scheme: None,
+                pattern: None,
+            },
         ]);
 
         let register_options = InlayHintRegistrationOptions {
@@ -244,16 +377,31 @@ impl I18nBackend {
     }
 
     async fn diagnose_document(&self, uri: &Url, content: &str) {
-        let diagnostics = self.compute_diagnostics(content).await;
+        if let Some(component) = Self::component_path(uri) {
+            if let Some(store) = self.translation_store.read().await.as_ref() {
+                store.load_component_translations(&component, content);
+            }
+        }
+
+        let path = uri.to_file_path().ok();
+        let diagnostics = self.compute_diagnostics(path.as_deref(), content).await;
 
         self.client
             .publish_diagnostics(uri.clone(), diagnostics, None)
             .await;
     }
 
-    async fn compute_diagnostics(&self, content: &str) -> Vec<Diagnostic> {
+    /// Vue single-file components may carry their own `<i18n>` messages.
+    fn component_path(uri: &Url) -> Option<PathBuf> {
+        let path = uri.to_file_path().ok()?;
+        (path.extension().and_then(|e| e.to_str()) == Some("vue")).then_some(path)
+    }
+
+    /// `path` is the document's own path: it scopes Vue `<i18n>` messages and resolves lazy
+    /// lookups.
+    async fn compute_diagnostics(&self, path: Option<&Path>, content: &str) -> Vec<Diagnostic> {
         let key_finder = self.key_finder.read().await;
-        let found_keys = key_finder.find_keys(content);
+        let found_keys = key_finder.find_keys_in_file(content, path);
 
         let translation_store = self.translation_store.read().await;
 
@@ -262,10 +410,21 @@ impl I18nBackend {
         };
 
         let mut diagnostics = Vec::new();
-        let source_locale = self.config.read().await.source_locale.clone();
+        let config = self.config.read().await;
+        let source_locale = config.source_locale.clone();
 
         for found_key in found_keys {
-            if !store.key_exists(&found_key.key) {
+            if found_key.kind != KeyKind::Static {
+                diagnostics.extend(dynamic_key_diagnostic(&found_key, store, &config));
+                continue;
+            }
+
+            if store.is_ignored_key(&found_key.key) {
+                continue;
+            }
+
+            let lookup = Variants::for_call(found_key.context);
+            if !store.component_key_exists(path, &found_key.key, lookup) {
                 diagnostics.push(Diagnostic {
                     range: Range {
                         start: Position {
@@ -285,7 +444,9 @@ impl I18nBackend {
                 });
             } else {
                 // Check if the source locale value is a raw placeholder (_key_)
-                if let Some(value) = store.get_translation(&found_key.key, &source_locale) {
+                if let Some(value) =
+                    store.get_component_translation(path, &found_key.key, &source_locale)
+                {
                     if value.starts_with('_') && value.ends_with('_') && value.len() > 2 {
                         diagnostics.push(Diagnostic {
                             range: Range {
@@ -311,7 +472,8 @@ impl I18nBackend {
                     }
                 }
 
-                let missing_locales = store.get_missing_locales(&found_key.key);
+                let missing_locales =
+                    store.get_component_missing_locales(path, &found_key.key, lookup);
                 if !missing_locales.is_empty() {
                     diagnostics.push(Diagnostic {
                         range: Range {
@@ -338,16 +500,30 @@ impl I18nBackend {
             }
         }
 
+        let suppressions = Suppressions::parse(content);
+        diagnostics.retain(|diagnostic| {
+            let Some(NumberOrString::String(code)) = diagnostic.code.as_ref() else {
+                return true;
+            };
+            !suppressions.is_suppressed(diagnostic.range.start.line as usize, code)
+        });
+
         diagnostics
     }
 
-    async fn get_hover_content(&self, key: &str) -> Option<String> {
+    async fn get_hover_content(
+        &self,
+        component: Option<&Path>,
+        key: &str,
+        lookup: Variants,
+    ) -> Option<String> {
         let translation_store = self.translation_store.read().await;
         let config = self.config.read().await;
         let store = translation_store.as_ref()?;
 
-        let translations = store.get_all_translations(key);
-        if translations.is_empty() {
+        let translations = store.get_all_component_translations(component, key);
+        let variants = store.get_key_variants(key, lookup);
+        if translations.is_empty() && variants.is_empty() {
             return None;
         }
 
@@ -355,10 +531,15 @@ impl I18nBackend {
 
         let source_locale = &config.source_locale;
         let format_line = |locale: &str| -> Option<String> {
-            let entry = translations.get(locale)?;
+            let Some(entry) = translations.get(locale) else {
+                return Self::format_variant_lines(store, locale, key, &variants);
+            };
             let mut line = format!("**{}**: {}", locale, entry.value);
 
-            if let Some(location) = store.get_translation_location(key, locale) {
+            if let Some(location) = store
+                .get_translation_location(key, locale)
+                .filter(|location| location.file_path == entry.file_path)
+            {
                 if let Ok(uri) = Url::from_file_path(&location.file_path) {
                     let link = format!("{}#L{}", uri, location.line + 1);
                     line.push_str(&format!(" ([↗]({} \"Go to Definition\"))", link));
@@ -366,6 +547,9 @@ impl I18nBackend {
             }
 
             line.push_str("\n\n");
+            if let Some(variant_lines) = Self::format_variant_lines(store, locale, key, &variants) {
+                line.push_str(&variant_lines);
+            }
             Some(line)
         };
 
@@ -375,11 +559,12 @@ impl I18nBackend {
 
         content.push_str("---\n\n");
 
-        let mut other_locales: Vec<String> = translations
-            .keys()
-            .filter(|locale| *locale != source_locale)
-            .cloned()
-            .collect();
+        let mut other_locales: Vec<String> = if variants.is_empty() {
+            translations.keys().cloned().collect()
+        } else {
+            store.get_locales()
+        };
+        other_locales.retain(|locale| locale != source_locale);
         other_locales.sort();
 
         for locale in other_locales {
@@ -391,6 +576,27 @@ impl I18nBackend {
         Some(content)
     }
 
+    /// Hover lines for i18next suffix variants (`item_one`, `item_other`) grouped under `key`.
+    fn format_variant_lines(
+        store: &TranslationStore,
+        locale: &str,
+        key: &str,
+        variants: &[String],
+    ) -> Option<String> {
+        let lines: Vec<String> = variants
+            .iter()
+            .filter_map(|variant| {
+                let value = store.get_translation(variant, locale)?;
+                Some(format!("- `_{}`: {}", &variant[key.len() + 1..], value))
+            })
+            .collect();
+        if lines.is_empty() {
+            return None;
+        }
+
+        Some(format!("**{}**:\n{}\n\n", locale, lines.join("\n")))
+    }
+
     async fn get_completions(&self, prefix: &str) -> Vec<CompletionItem> {
         let translation_store = self.translation_store.read().await;
         let config = self.config.read().await;
@@ -402,20 +608,37 @@ impl I18nBackend {
         let all_keys = store.get_all_keys();
         let source_locale = &config.source_locale;
 
-        all_keys
+        // Offer `item` once instead of `item_one`, `item_other`, …
+        let mut grouped: Vec<String> = all_keys
+            .iter()
+            .map(|key| plural_group_key(key).unwrap_or(key).to_string())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        grouped.sort();
+
+        grouped
             .into_iter()
             .filter(|key| key.starts_with(prefix) || prefix.is_empty())
             .take(100)
             .map(|key| {
                 let translation = store.get_translation(&key, source_locale);
+                let variants = store.get_key_variants(&key, Variants::Plural);
+                let documentation = if variants.is_empty() {
+                    translation
+                        .as_ref()
+                        .map(|t| format!("**{}**: {}", source_locale, t))
+                } else {
+                    Self::format_variant_lines(store, source_locale, &key, &variants)
+                };
                 CompletionItem {
                     label: key.clone(),
                     kind: Some(CompletionItemKind::TEXT),
-                    detail: translation.clone(),
-                    documentation: translation.map(|t| {
+                    detail: translation,
+                    documentation: documentation.map(|value| {
                         Documentation::MarkupContent(MarkupContent {
                             kind: MarkupKind::Markdown,
-                            value: format!("**{}**: {}", source_locale, t),
+                            value,
                         })
                     }),
                     insert_text: Some(key.clone()),
@@ -494,8 +717,25 @@ impl I18nBackend {
         }
     }
 
-    fn translation_extensions() -> [&'static str; 5] {
-        [".json", ".yaml", ".yml", ".php", ".arb"]
+    fn translation_extensions() -> [&'static str; 16] {
+        [
+            ".json",
+            ".jsonc",
+            ".json5",
+            ".yaml",
+            ".yml",
+            ".toml",
+            ".php",
+            ".arb",
+            ".properties",
+            ".resx",
+            ".js",
+            ".mjs",
+            ".cjs",
+            ".ts",
+            ".mts",
+            ".cts",
+        ]
     }
 
     fn has_translation_extension(path: &Path) -> bool {
@@ -585,15 +825,53 @@ impl I18nBackend {
 
     async fn reload_translations(&self) {
         let workspace_root = { self.workspace_root.read().await.clone() };
-        let locale_paths = { self.config.read().await.locale_paths.clone() };
+        // Keep the loaded store's index, so only changed locale files are parsed again.
+        let current_cache = self
+            .translation_store
+            .read()
+            .await
+            .as_ref()
+            .and_then(|store| store.cache().cloned());
+        let (locale_paths, literal_key_paths, namespaces, exclude, source_locale, cache) = {
+            let config = self.config.read().await;
+            (
+                config.locale_paths.clone(),
+                config.literal_key_paths.clone(),
+                config.namespaces(),
+                config.exclude.clone(),
+                config.source_locale.clone(),
+                workspace_root.as_ref().map(|root| {
+                    current_cache
+                        .filter(|cache| cache.matches_config(&config))
+                        .unwrap_or_else(|| IndexCache::open_or_in_memory(root, &config))
+                }),
+            )
+        };
 
         let Some(root) = workspace_root.as_ref() else {
             return;
         };
 
-        let store = TranslationStore::new(root.clone());
+        let store = TranslationStore::new(root.clone())
+            .with_literal_key_paths(&literal_key_paths)
+            .with_namespaces(namespaces)
+            .with_exclude(&exclude)
+            .with_source_locale(&source_locale)
+            .with_cache(cache);
         store.scan_and_load(&locale_paths);
 
+        {
+            let docs = self.documents.read().await;
+            for uri in docs.uris() {
+                let Ok(url) = Url::parse(&uri) else {
+                    continue;
+                };
+                if let (Some(component), Some(doc)) = (Self::component_path(&url), docs.get(&uri)) {
+                    store.load_component_translations(&component, &doc.content);
+                }
+            }
+        }
+
         let locales = store.get_locales();
         let keys = store.get_all_keys();
 
@@ -662,16 +940,22 @@ impl I18nBackend {
         }
     }
 
-    async fn get_definition_locations(&self, key: &str) -> Vec<Location> {
+    async fn get_definition_locations(&self, key: &str, lookup: Variants) -> Vec<Location> {
         let translation_store = self.translation_store.read().await;
         let config = self.config.read().await;
         let Some(store) = translation_store.as_ref() else {
             return Vec::new();
         };
 
-        let translations = store.get_all_translations(key);
+        let mut translations = store.get_all_translations(key);
+        let mut key = key.to_string();
         if translations.is_empty() {
-            return Vec::new();
+            // Jump to the first i18next variant (`item_one`) of a suffix-only key.
+            let Some(variant) = store.get_key_variants(&key, lookup).into_iter().next() else {
+                return Vec::new();
+            };
+            translations = store.get_all_translations(&variant);
+            key = variant;
         }
 
         let source_locale = &config.source_locale;
@@ -690,18 +974,18 @@ impl I18nBackend {
 
         let mut locations = Vec::new();
         for locale in locales {
-            if let Some(location) = store.get_translation_location(key, &locale) {
+            if let Some(location) = store.get_translation_location(&key, &locale) {
                 if let Ok(uri) = Url::from_file_path(&location.file_path) {
                     locations.push(Location {
                         uri,
                         range: Range {
                             start: Position {
                                 line: location.line as u32,
-                                character: 0,
+                                character: location.start_char as u32,
                             },
                             end: Position {
                                 line: location.line as u32,
-                                character: 0,
+                                character: location.end_char as u32,
                             },
                         },
                     });
@@ -951,16 +1235,25 @@ impl LanguageServer for I18nBackend {
 
         let content = doc.content.to_string();
         let key_finder = self.key_finder.read().await;
+        let path = uri.to_file_path().ok();
 
-        let Some(found_key) = key_finder.find_key_at_position(
+        let Some(found_key) = key_finder.find_key_at_position_in_file(
             &content,
+            path.as_deref(),
             position.line as usize,
             position.character as usize,
         ) else {
             return Ok(None);
         };
 
-        let Some(hover_content) = self.get_hover_content(&found_key.key).await else {
+        let Some(hover_content) = self
+            .get_hover_content(
+                path.as_deref(),
+                &found_key.key,
+                Variants::for_call(found_key.context),
+            )
+            .await
+        else {
             return Ok(None);
         };
 
@@ -1018,16 +1311,20 @@ impl LanguageServer for I18nBackend {
 
         let content = doc.content.to_string();
         let key_finder = self.key_finder.read().await;
+        let path = uri.to_file_path().ok();
 
-        let Some(found_key) = key_finder.find_key_at_position(
+        let Some(found_key) = key_finder.find_key_at_position_in_file(
             &content,
+            path.as_deref(),
             position.line as usize,
             position.character as usize,
         ) else {
             return Ok(None);
         };
 
-        let locations = self.get_definition_locations(&found_key.key).await;
+        let locations = self
+            .get_definition_locations(&found_key.key, Variants::for_call(found_key.context))
+            .await;
         if locations.is_empty() {
             return Ok(None);
         }
@@ -1056,8 +1353,9 @@ impl LanguageServer for I18nBackend {
         };
 
         let content = doc.content.as_str();
+        let path = uri.to_file_path().ok();
         let key_finder = self.key_finder.read().await;
-        let found_keys = key_finder.find_keys(content);
+        let found_keys = key_finder.find_keys_in_file(content, path.as_deref());
 
         let translation_store = self.translation_store.read().await;
         let Some(store) = translation_store.as_ref() else {
@@ -1066,8 +1364,13 @@ impl LanguageServer for I18nBackend {
 
         let mut lenses = Vec::new();
 
-        for found_key in found_keys {
-            let Some(translation) = store.get_translation(&found_key.key, &source_locale) else {
+        for found_key in found_keys
+            .into_iter()
+            .filter(|found| found.kind == KeyKind::Static)
+        {
+            let Some(translation) =
+                store.get_component_translation(path.as_deref(), &found_key.key, &source_locale)
+            else {
                 continue;
             };
 
@@ -1252,8 +1555,9 @@ impl LanguageServer for I18nBackend {
         };
 
         let content = doc.content.as_str();
+        let path = uri.to_file_path().ok();
         let key_finder = self.key_finder.read().await;
-        let found_keys = key_finder.find_keys(content);
+        let found_keys = key_finder.find_keys_in_file(content, path.as_deref());
 
         let translation_store = self.translation_store.read().await;
         let Some(store) = translation_store.as_ref() else {
@@ -1272,7 +1576,10 @@ impl LanguageServer for I18nBackend {
             position_leq(range.start, end) && position_leq(start, range.end)
         };
 
-        for found_key in found_keys {
+        for found_key in found_keys
+            .into_iter()
+            .filter(|found| found.kind == KeyKind::Static)
+        {
             let key_start = Position {
                 line: found_key.line as u32,
                 character: found_key.start_char as u32,
@@ -1286,7 +1593,9 @@ impl LanguageServer for I18nBackend {
                 continue;
             }
 
-            if let Some(translation) = store.get_translation(&found_key.key, &source_locale) {
+            if let Some(translation) =
+                store.get_component_translation(path.as_deref(), &found_key.key, &source_locale)
+            {
                 let display_text = truncate_string(&translation, 30);
 
                 let mut hint_char = found_key.end_char;
@@ -1428,79 +1737,14 @@ impl I18nBackend {
         min_indent.unwrap_or_else(|| "  ".to_string())
     }
 
-    /// Walk a chain of key segments from the root to find the byte range
-    /// `(open_brace, close_brace)` of the innermost object.
-    /// E.g. for `["common", "buttons"]` it finds `"common": { "buttons": { ... } }`
-    /// and returns the range of the `buttons` object.
+    /// Find the byte offsets `(open_brace, close_brace)` of the object at a chain of key
+    /// segments, e.g. `["common", "buttons"]` for `"common": { "buttons": { ... } }`.
     fn find_nested_object_range(content: &str, key_chain: &[&str]) -> Option<(usize, usize)> {
-        let mut search_from = 0usize;
-        let mut result: Option<(usize, usize)> = None;
-
-        for key_name in key_chain {
-            let range = Self::find_key_object_range(content, search_from, key_name)?;
-            search_from = range.0 + 1; // search inside this object
-            result = Some(range);
-        }
-        result
-    }
-
-    /// Find the byte offsets `(open_brace, close_brace)` of the JSON object
-    /// that is the value of `"key_name"`, searching forward from `search_from`.
-    fn find_key_object_range(
-        content: &str,
-        search_from: usize,
-        key_name: &str,
-    ) -> Option<(usize, usize)> {
-        let needle = format!("\"{}\"", key_name);
-        let slice = &content[search_from..];
-        let key_rel = slice.find(&needle)?;
-        let key_abs = search_from + key_rel;
-        let after_key = &content[key_abs + needle.len()..];
-
-        // Expect `:` then `{` (with optional whitespace)
-        let mut found_colon = false;
-        let mut open_brace_abs = None;
-
-        for (i, ch) in after_key.char_indices() {
-            match ch {
-                ':' if !found_colon => found_colon = true,
-                '{' if found_colon => {
-                    open_brace_abs = Some(key_abs + needle.len() + i);
-                    break;
-                }
-                c if c.is_whitespace() => continue,
-                _ if !found_colon => return None,
-                _ => return None, // value is not an object
-            }
-        }
-
-        let open = open_brace_abs?;
-
-        // Track braces to find matching }, respecting strings
-        let mut depth = 1i32;
-        let mut in_string = false;
-        let mut escape = false;
-
-        for (i, ch) in content[open + 1..].char_indices() {
-            if escape {
-                escape = false;
-                continue;
-            }
-            match ch {
-                '\\' if in_string => escape = true,
-                '"' => in_string = !in_string,
-                '{' if !in_string => depth += 1,
-                '}' if !in_string => {
-                    depth -= 1;
-                    if depth == 0 {
-                        return Some((open, open + 1 + i));
-                    }
-                }
-                _ => {}
-            }
-        }
-
-        None
+        let located = locate_keys(content, "json");
+        let value = &located.get(&key_chain.join("."))?.value;
+        content[value.clone()]
+            .starts_with('{')
+            .then(|| (value.start, value.end - 1))
     }
 
     /// Insert `entry` text before the `}` at `brace_offset` in `content`.
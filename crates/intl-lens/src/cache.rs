@@ -0,0 +1,389 @@
+//! Index of parsed locale files and source key occurrences, so only files whose content
+//! changed are parsed again. It is kept in `.i18nlens/cache` when `cache` is enabled, and
+//! otherwise only in memory by the long-running MCP and language servers.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::config::I18nConfig;
+use crate::i18n::store::LoadedFile;
+use crate::scanner::CodeKeyOccurrence;
+
+const CACHE_DIR: &str = ".i18nlens/cache";
+const INDEX_FILE: &str = "index.json";
+/// A file modified less than this long before its stamp was recorded may have been written
+/// again within the file system's timestamp granularity without changing its stamp.
+const RACY_STAMP_NANOS: u64 = 2_000_000_000;
+
+pub struct IndexCache {
+    /// Where the index is saved; `None` for an index kept in memory.
+    path: Option<PathBuf>,
+    fingerprint: u64,
+    locale_files: DashMap<PathBuf, CacheEntry<Option<LoadedFile>>>,
+    source_files: DashMap<PathBuf, CacheEntry<Vec<CodeKeyOccurrence>>>,
+    dirty: AtomicBool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheIndex {
+    fingerprint: u64,
+    #[serde(default)]
+    locale_files: HashMap<PathBuf, CacheEntry<Option<LoadedFile>>>,
+    #[serde(default)]
+    source_files: HashMap<PathBuf, CacheEntry<Vec<CodeKeyOccurrence>>>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry<T> {
+    /// Modification time in nanoseconds since the Unix epoch.
+    modified: u64,
+    size: u64,
+    /// When `modified` and `size` were read, in nanoseconds since the Unix epoch.
+    #[serde(default)]
+    recorded: u64,
+    hash: u64,
+    value: T,
+}
+
+impl IndexCache {
+    /// Load the workspace's index, or start an empty one when it is missing, unreadable or
+    /// was written for different settings. `None` when `cache` is disabled in the config.
+    pub fn open(workspace: &Path, config: &I18nConfig) -> Option<Arc<Self>> {
+        if !config.cache {
+            return None;
+        }
+
+        let path = workspace.join(CACHE_DIR).join(INDEX_FILE);
+        let fingerprint = config_fingerprint(config);
+        let index = std::fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheIndex>(&bytes).ok())
+            .filter(|index| index.fingerprint == fingerprint);
+        let (locale_files, source_files) = match index {
+            Some(index) => (
+                index.locale_files.into_iter().collect(),
+                index.source_files.into_iter().collect(),
+            ),
+            None => (DashMap::new(), DashMap::new()),
+        };
+
+        Some(Arc::new(Self {
+            path: Some(path),
+            fingerprint,
+            locale_files,
+            source_files,
+            dirty: AtomicBool::new(false),
+        }))
+    }
+
+    /// An index that is never written to disk, for processes that load the workspace
+    /// repeatedly while `cache` is disabled.
+    pub fn in_memory(config: &I18nConfig) -> Arc<Self> {
+        Arc::new(Self {
+            path: None,
+            fingerprint: config_fingerprint(config),
+            locale_files: DashMap::new(),
+            source_files: DashMap::new(),
+            dirty: AtomicBool::new(false),
+        })
+    }
+
+    /// [`Self::open`], or [`Self::in_memory`] when `cache` is disabled.
+    pub fn open_or_in_memory(workspace: &Path, config: &I18nConfig) -> Arc<Self> {
+        Self::open(workspace, config).unwrap_or_else(|| Self::in_memory(config))
+    }
+
+    /// Whether the index was built with the same settings as `config`, and is on disk exactly
+    /// when `cache` is enabled.
+    pub fn matches_config(&self, config: &I18nConfig) -> bool {
+        config.cache == self.path.is_some() && self.fingerprint == config_fingerprint(config)
+    }
+
+    pub(crate) fn locale_file(
+        &self,
+        path: &Path,
+        parse: impl FnOnce() -> Option<LoadedFile>,
+    ) -> Option<LoadedFile> {
+        self.lookup(&self.locale_files, path, None, parse)
+    }
+
+    pub(crate) fn source_file(
+        &self,
+        path: &Path,
+        content: &str,
+        scan: impl FnOnce() -> Vec<CodeKeyOccurrence>,
+    ) -> Vec<CodeKeyOccurrence> {
+        self.lookup(&self.source_files, path, Some(content.as_bytes()), scan)
+    }
+
+    /// Write the index if anything changed, dropping entries of deleted files.
+    pub fn save(&self) -> anyhow::Result<()> {
+        if !self.dirty.swap(false, Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        self.locale_files.retain(|path, _| path.exists());
+        self.source_files.retain(|path, _| path.exists());
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let index = CacheIndex {
+            fingerprint: self.fingerprint,
+            locale_files: self
+                .locale_files
+                .iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect(),
+            source_files: self
+                .source_files
+                .iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect(),
+        };
+
+        let dir = path.parent().unwrap_or(Path::new("."));
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let gitignore = dir.join(".gitignore");
+        if !gitignore.exists() {
+            std::fs::write(&gitignore, "*\n")?;
+        }
+
+        // Write beside the index and rename, so a concurrent reader never sees half a file.
+        let temp = path.with_extension(format!("{}.tmp", std::process::id()));
+        std::fs::write(&temp, serde_json::to_vec(&index)?)
+            .with_context(|| format!("Failed to write {}", temp.display()))?;
+        std::fs::rename(&temp, path)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// The cached value for `path` if its content is unchanged; otherwise `compute` it and
+    /// remember the result. A given `content` is always compared by hash, so an unsaved
+    /// editor buffer never gets the value of the file on disk. Otherwise the file is only read
+    /// and hashed when its modification time or size changed, or when the stamp was recorded
+    /// too soon after the file was written to rule out a later same-size write.
+    fn lookup<T: Clone>(
+        &self,
+        entries: &DashMap<PathBuf, CacheEntry<T>>,
+        path: &Path,
+        content: Option<&[u8]>,
+        compute: impl FnOnce() -> T,
+    ) -> T {
+        let Some((modified, size)) = file_stamp(path) else {
+            return compute();
+        };
+        let recorded = now_nanos();
+        let hash = || match content {
+            Some(content) => Some(hash_bytes(content)),
+            None => std::fs::read(path).ok().map(|bytes| hash_bytes(&bytes)),
+        };
+
+        let mut known_hash = None;
+        if let Some(mut entry) = entries.get_mut(path) {
+            let racy = is_racy(entry.modified, entry.recorded);
+            let stamp_changed = entry.modified != modified || entry.size != size;
+            if content.is_none() && !stamp_changed && !racy {
+                return entry.value.clone();
+            }
+            known_hash = hash();
+            if known_hash == Some(entry.hash) {
+                if stamp_changed || racy {
+                    entry.modified = modified;
+                    entry.size = size;
+                    entry.recorded = recorded;
+                    self.dirty.store(true, Ordering::Relaxed);
+                }
+                return entry.value.clone();
+            }
+        }
+
+        let value = compute();
+        if let Some(hash) = known_hash.or_else(hash) {
+            entries.insert(
+                path.to_path_buf(),
+                CacheEntry {
+                    modified,
+                    size,
+                    recorded,
+                    hash,
+                    value: value.clone(),
+                },
+            );
+            self.dirty.store(true, Ordering::Relaxed);
+        }
+        value
+    }
+}
+
+/// Whether a stamp recorded at `recorded` can miss a write that happened after it.
+fn is_racy(modified: u64, recorded: u64) -> bool {
+    recorded < modified.saturating_add(RACY_STAMP_NANOS)
+}
+
+fn now_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_nanos() as u64)
+}
+
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((modified.as_nanos() as u64, metadata.len()))
+}
+
+/// Settings that change what parsing or scanning a file produces.
+fn config_fingerprint(config: &I18nConfig) -> u64 {
+    let ast_languages: BTreeSet<String> = config
+        .ast_languages()
+        .iter()
+        .map(|language| format!("{:?}", language))
+        .collect();
+    let settings = format!(
+        "{}\n{:?}\n{:?}\n{:?}\n{:?}\n{}",
+        env!("CARGO_PKG_VERSION"),
+        config.function_patterns,
+        ast_languages,
+        config.namespaces(),
+        config.literal_key_paths,
+        config.source_locale,
+    );
+    hash_bytes(settings.as_bytes())
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is stable across Rust releases.
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::i18n::key_finder::KeyKind;
+
+    fn test_workspace(name: &str) -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time before unix epoch")
+            .as_nanos();
+        std::env::temp_dir().join(format!("intl-lens-{name}-{unique}"))
+    }
+
+    fn occurrence(key: &str) -> CodeKeyOccurrence {
+        CodeKeyOccurrence {
+            key: key.to_string(),
+            line: 0,
+            start_char: 0,
+            end_char: key.len(),
+            code_snippet: String::new(),
+            kind: KeyKind::Static,
+            context: false,
+        }
+    }
+
+    #[test]
+    fn test_reuses_entries_until_content_or_config_changes() {
+        let root = test_workspace("index-cache");
+        fs::create_dir_all(&root).expect("create workspace");
+        let file = root.join("App.tsx");
+        fs::write(&file, "t('a')").expect("write source");
+        let config = I18nConfig {
+            cache: true,
+            ..I18nConfig::default()
+        };
+        assert!(IndexCache::open(&root, &I18nConfig::default()).is_none());
+
+        let cache = IndexCache::open(&root, &config).expect("cache enabled");
+        let keys = cache.source_file(&file, "t('a')", || vec![occurrence("a")]);
+        assert_eq!(keys[0].key, "a");
+        cache.save().expect("save cache");
+        assert!(root.join(".i18nlens/cache/index.json").exists());
+
+        let cache = IndexCache::open(&root, &config).expect("cache enabled");
+        let keys = cache.source_file(&file, "t('a')", || panic!("file should be cached"));
+        assert_eq!(keys[0].key, "a");
+
+        fs::write(&file, "t('bb')").expect("rewrite source");
+        let keys = cache.source_file(&file, "t('bb')", || vec![occurrence("bb")]);
+        assert_eq!(keys[0].key, "bb");
+        cache.save().expect("save cache");
+
+        let mut changed = config.clone();
+        changed
+            .function_patterns
+            .push("translate\\('([^']+)'\\)".into());
+        assert!(!cache.matches_config(&changed));
+        let cache = IndexCache::open(&root, &changed).expect("cache enabled");
+        let keys = cache.source_file(&file, "t('bb')", Vec::new);
+        assert!(keys.is_empty());
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn test_rechecks_recent_writes_and_editor_buffers() {
+        let root = test_workspace("index-cache-racy");
+        fs::create_dir_all(&root).expect("create workspace");
+        let file = root.join("en.json");
+        fs::write(&file, r#"{"a":"1"}"#).expect("write locale");
+        let config = I18nConfig {
+            cache: true,
+            ..I18nConfig::default()
+        };
+        let cache = IndexCache::open(&root, &config).expect("cache enabled");
+        let entries = DashMap::new();
+
+        let value = cache.lookup(&entries, &file, None, || "first".to_string());
+        assert_eq!(value, "first");
+        // A same-size write right after caching may keep the modification time.
+        fs::write(&file, r#"{"a":"2"}"#).expect("rewrite locale");
+        let value = cache.lookup(&entries, &file, None, || "second".to_string());
+        assert_eq!(value, "second");
+
+        let source = root.join("App.tsx");
+        fs::write(&source, "t('a')").expect("write source");
+        let keys = cache.source_file(&source, "t('a')", || vec![occurrence("a")]);
+        assert_eq!(keys[0].key, "a");
+        let keys = cache.source_file(&source, "t('b')", || vec![occurrence("b")]);
+        assert_eq!(keys[0].key, "b");
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn test_keeps_index_in_memory_when_cache_is_disabled() {
+        let root = test_workspace("index-cache-memory");
+        fs::create_dir_all(&root).expect("create workspace");
+        let file = root.join("App.tsx");
+        fs::write(&file, "t('a')").expect("write source");
+        let config = I18nConfig::default();
+
+        let cache = IndexCache::open_or_in_memory(&root, &config);
+        assert!(cache.matches_config(&config));
+        cache.source_file(&file, "t('a')", || vec![occurrence("a")]);
+        cache.save().expect("save cache");
+        let keys = cache.source_file(&file, "t('a')", || panic!("file should be cached"));
+        assert_eq!(keys[0].key, "a");
+        assert!(!root.join(".i18nlens").exists());
+
+        let enabled = I18nConfig {
+            cache: true,
+            ..config
+        };
+        assert!(!cache.matches_config(&enabled));
+
+        fs::remove_dir_all(root).ok();
+    }
+}
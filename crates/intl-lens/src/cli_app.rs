@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 
@@ -11,10 +11,15 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use walkdir::WalkDir;
 
-use crate::audit::{AuditReport, AuditResult, AuditSummary};
-use crate::config::I18nConfig;
-use crate::i18n::store::TranslationStore;
+use crate::audit::{AuditReport, AuditResult, AuditSummary, DuplicateKey, DynamicKeyUsage};
+use crate::cache::IndexCache;
+use crate::config::{DynamicKeyPolicy, I18nConfig};
+use crate::i18n::jsonc;
+use crate::i18n::key_finder::KeyKind;
+use crate::i18n::store::{uses_literal_keys, TranslationStore, Variants};
+use crate::i18n::suppression::Suppressions;
 use crate::scanner::CodeScanner;
+use crate::walker::WorkspaceWalker;
 
 #[derive(Parser)]
 #[command(name = "i18nlens")]
@@ -81,7 +86,7 @@ struct AuditArgs {
     #[arg(long)]
     suggest_fixes: bool,
 
-    /// Issue kinds that should fail the command: missing,unused,placeholder
+    /// Issue kinds that should fail the command: missing,unused,placeholder,dynamic,duplicate
     #[arg(long, value_parser = parse_fail_on, default_value = "missing,unused")]
     fail_on: FailOn,
 
@@ -116,7 +121,7 @@ struct CiArgs {
     #[arg(long)]
     suggest_fixes: bool,
 
-    /// Issue kinds that should fail the command: missing,unused,placeholder
+    /// Issue kinds that should fail the command: missing,unused,placeholder,dynamic,duplicate
     #[arg(long, value_parser = parse_fail_on, default_value = "missing,placeholder")]
     fail_on: FailOn,
 
@@ -156,6 +161,8 @@ struct FailOn {
     missing: bool,
     unused: bool,
     placeholder: bool,
+    dynamic: bool,
+    duplicate: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -164,6 +171,8 @@ enum IssueKind {
     Missing,
     Unused,
     Placeholder,
+    Dynamic,
+    Duplicate,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -290,6 +299,8 @@ fn parse_fail_on(value: &str) -> Result<FailOn, String> {
         missing: false,
         unused: false,
         placeholder: false,
+        dynamic: false,
+        duplicate: false,
     };
 
     for part in value
@@ -301,10 +312,12 @@ fn parse_fail_on(value: &str) -> Result<FailOn, String> {
             "missing" => fail_on.missing = true,
             "unused" => fail_on.unused = true,
             "placeholder" => fail_on.placeholder = true,
+            "dynamic" => fail_on.dynamic = true,
+            "duplicate" => fail_on.duplicate = true,
             "none" => {}
             other => {
                 return Err(format!(
-                    "unknown issue kind '{other}', expected missing, unused, or placeholder"
+                    "unknown issue kind '{other}', expected missing, unused, placeholder, dynamic, or duplicate"
                 ));
             }
         }
@@ -330,10 +343,16 @@ async fn run_audit(
     let config = I18nConfig::load_from_workspace(workspace);
 
     pb.set_message("Scanning translation files...");
-    let store = TranslationStore::new(workspace.to_path_buf());
+    let store = TranslationStore::new(workspace.to_path_buf())
+        .with_literal_key_paths(&config.literal_key_paths)
+        .with_namespaces(config.namespaces())
+        .with_exclude(&config.exclude)
+        .with_source_locale(&config.source_locale)
+        .with_cache(IndexCache::open(workspace, &config));
     store.scan_and_load(&config.locale_paths);
 
     pb.set_message("Scanning codebase...");
+    let dynamic_key_policy = config.dynamic_key_policy;
     let mut result = AuditResult::new(workspace.to_path_buf(), config, store);
     result.scan_codebase();
 
@@ -362,11 +381,9 @@ async fn run_audit(
         println!("{}", output_str);
     }
 
-    Ok(evaluate_exit_code(
-        &report,
-        &options.fail_on,
-        options.max_unused,
-    ))
+    let mut fail_on = options.fail_on.clone();
+    fail_on.dynamic |= dynamic_key_policy == DynamicKeyPolicy::Error;
+    Ok(evaluate_exit_code(&report, &fail_on, options.max_unused))
 }
 
 fn apply_audit_filters(
@@ -394,6 +411,12 @@ fn apply_audit_filters(
         report
             .placeholder_issues
             .retain(|item| !key_patterns.iter().any(|regex| regex.is_match(&item.key)));
+        report
+            .dynamic_keys
+            .retain(|item| !key_patterns.iter().any(|regex| regex.is_match(&item.key)));
+        report
+            .duplicate_keys
+            .retain(|item| !key_patterns.iter().any(|regex| regex.is_match(&item.key)));
     }
 
     let file_globs = compile_file_globs(&options.ignore_file)?;
@@ -407,6 +430,17 @@ fn apply_audit_filters(
         report
             .unused
             .retain(|item| !matches_glob(file_globs, workspace, &item.defined_in.file_path));
+        report.dynamic_keys.retain_mut(|item| {
+            item.used_in
+                .retain(|usage| !matches_glob(file_globs, workspace, &usage.file));
+            !item.used_in.is_empty()
+        });
+        // A key is only duplicated while at least two of its definitions remain.
+        report.duplicate_keys.retain_mut(|item| {
+            item.defined_in
+                .retain(|location| !matches_glob(file_globs, workspace, &location.file_path));
+            item.defined_in.len() > 1
+        });
     }
 
     if let Some(path) = options.baseline.as_ref() {
@@ -508,6 +542,27 @@ fn apply_baseline(report: &mut AuditReport, workspace: &Path, baseline: &Baselin
         });
         !item.locale_values.is_empty()
     });
+
+    report.dynamic_keys.retain_mut(|item| {
+        item.used_in.retain(|usage| {
+            !issues.contains(&IssueIdentity {
+                kind: IssueKind::Dynamic,
+                key: item.key.clone(),
+                locale: None,
+                file: Some(relative_path(workspace, &usage.file)),
+            })
+        });
+        !item.used_in.is_empty()
+    });
+
+    report.duplicate_keys.retain(|item| {
+        !issues.contains(&IssueIdentity {
+            kind: IssueKind::Duplicate,
+            key: item.key.clone(),
+            locale: Some(item.locale.clone()),
+            file: None,
+        })
+    });
 }
 
 fn issue_identities(report: &AuditReport, workspace: &Path) -> HashSet<IssueIdentity> {
@@ -544,6 +599,26 @@ fn issue_identities(report: &AuditReport, workspace: &Path) -> HashSet<IssueIden
         }
     }
 
+    for item in &report.dynamic_keys {
+        for usage in &item.used_in {
+            issues.insert(IssueIdentity {
+                kind: IssueKind::Dynamic,
+                key: item.key.clone(),
+                locale: None,
+                file: Some(relative_path(workspace, &usage.file)),
+            });
+        }
+    }
+
+    for item in &report.duplicate_keys {
+        issues.insert(IssueIdentity {
+            kind: IssueKind::Duplicate,
+            key: item.key.clone(),
+            locale: Some(item.locale.clone()),
+            file: None,
+        });
+    }
+
     issues
 }
 
@@ -561,6 +636,9 @@ fn recalculate_summary(report: &mut AuditReport) {
         missing_translations: report.missing.len(),
         unused_keys: report.unused.len(),
         placeholder_mismatches: report.placeholder_issues.len(),
+        dynamic_keys: report.dynamic_keys.len(),
+        duplicate_keys: report.duplicate_keys.len(),
+        suppressed: report.summary.suppressed,
     };
 }
 
@@ -577,6 +655,14 @@ fn evaluate_exit_code(report: &AuditReport, fail_on: &FailOn, max_unused: Option
         return 1;
     }
 
+    if fail_on.dynamic && report.summary.dynamic_keys > 0 {
+        return 1;
+    }
+
+    if fail_on.duplicate && report.summary.duplicate_keys > 0 {
+        return 1;
+    }
+
     0
 }
 
@@ -587,26 +673,59 @@ async fn run_check(
     output: Option<PathBuf>,
 ) -> anyhow::Result<i32> {
     let config = I18nConfig::load_from_workspace(workspace);
-    let scanner = CodeScanner::new(&config.function_patterns);
+    let store = TranslationStore::new(workspace.to_path_buf())
+        .with_literal_key_paths(&config.literal_key_paths)
+        .with_namespaces(config.namespaces())
+        .with_exclude(&config.exclude)
+        .with_source_locale(&config.source_locale)
+        .with_cache(IndexCache::open(workspace, &config));
+    store.scan_and_load(&config.locale_paths);
+
+    let scanner = CodeScanner::new(&config.function_patterns)
+        .with_namespaces(config.namespaces())
+        .with_ast_languages(config.ast_languages())
+        .with_cache(store.cache().cloned());
 
     let mut all_keys = Vec::new();
+    let mut suppressions = HashMap::new();
 
     for file in files {
         let content = std::fs::read_to_string(&file)?;
-        let occurrences = scanner.scan_content(&content);
+        if file.extension().and_then(|e| e.to_str()) == Some("vue") {
+            store.load_component_translations(&file, &content);
+        }
+        let occurrences = scanner.scan_file(&file, &content);
         for occ in occurrences {
             all_keys.push((file.clone(), occ));
         }
+        suppressions.insert(file, Suppressions::parse(&content));
+    }
+    if let Some(cache) = store.cache() {
+        if let Err(e) = cache.save() {
+            tracing::warn!("Failed to save index cache: {}", e);
+        }
     }
-
-    let store = TranslationStore::new(workspace.to_path_buf());
-    store.scan_and_load(&config.locale_paths);
 
     let mut missing = Vec::new();
     let mut found = Vec::new();
 
     for (file, occ) in all_keys {
-        if store.key_exists(&occ.key) {
+        let (exists, code) = match occ.kind {
+            KeyKind::Static => (
+                store.component_key_exists(Some(&file), &occ.key, Variants::for_call(occ.context))
+                    || store.is_ignored_key(&occ.key),
+                "missing-translation",
+            ),
+            KeyKind::Template => (
+                !store.get_keys_with_prefix(&occ.key).is_empty(),
+                "missing-translation-prefix",
+            ),
+            KeyKind::Dynamic => continue,
+        };
+        if !exists && suppressions[&file].is_suppressed(occ.line, code) {
+            continue;
+        }
+        if exists {
             found.push((file, occ));
         } else {
             missing.push((file, occ));
@@ -719,7 +838,12 @@ async fn run_fix(
     placeholder: Option<String>,
 ) -> anyhow::Result<i32> {
     let config = I18nConfig::load_from_workspace(workspace);
-    let store = TranslationStore::new(workspace.to_path_buf());
+    let store = TranslationStore::new(workspace.to_path_buf())
+        .with_literal_key_paths(&config.literal_key_paths)
+        .with_namespaces(config.namespaces())
+        .with_exclude(&config.exclude)
+        .with_source_locale(&config.source_locale)
+        .with_cache(IndexCache::open(workspace, &config));
     store.scan_and_load(&config.locale_paths);
 
     let mut result = AuditResult::new(workspace.to_path_buf(), config.clone(), store);
@@ -825,7 +949,13 @@ fn convert_translation_files(
     let mut converted = 0;
     let mut skipped = 0;
 
-    for file in collect_translation_files(workspace, &config.locale_paths) {
+    for file in collect_translation_files(workspace, config) {
+        // Literal keys such as "Log in." must not be split into nested objects.
+        if uses_literal_keys(workspace, &file, &config.literal_key_paths) {
+            skipped += 1;
+            continue;
+        }
+
         match convert_translation_file(&file, target)? {
             ConvertOutcome::Converted => converted += 1,
             ConvertOutcome::Skipped => skipped += 1,
@@ -842,6 +972,7 @@ fn convert_translation_file(
     match path.extension().and_then(|extension| extension.to_str()) {
         Some("json") => convert_json_translation_file(path, target),
         Some("yaml") | Some("yml") => convert_yaml_translation_file(path, target),
+        Some("toml") => convert_toml_translation_file(path, target),
         _ => Ok(ConvertOutcome::Skipped),
     }
 }
@@ -852,8 +983,9 @@ fn convert_json_translation_file(
 ) -> anyhow::Result<ConvertOutcome> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read locale file {}", path.display()))?;
-    let json: serde_json::Value = serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse JSON locale file {}", path.display()))?;
+    let Some(json) = parse_strict_json(path, &content)? else {
+        return Ok(ConvertOutcome::Skipped);
+    };
     let converted = match target {
         KeyConversionTarget::Nested => json_to_nested(json),
         KeyConversionTarget::Flat => json_to_flat(json),
@@ -883,9 +1015,18 @@ fn convert_yaml_translation_file(
         .with_context(|| format!("Failed to read locale file {}", path.display()))?;
     let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
         .with_context(|| format!("Failed to parse YAML locale file {}", path.display()))?;
-    let converted = match target {
-        KeyConversionTarget::Nested => yaml_to_nested(yaml),
-        KeyConversionTarget::Flat => yaml_to_flat(yaml),
+    let convert = |value| match target {
+        KeyConversionTarget::Nested => yaml_to_nested(value),
+        KeyConversionTarget::Flat => yaml_to_flat(value),
+    };
+    // Keep the Rails locale root in place and convert only the keys beneath it.
+    let converted = match yaml_locale_root(path, &yaml) {
+        Some(root) => convert(yaml[root.as_str()].clone()).map(|inner| {
+            let mut mapping = serde_yaml::Mapping::new();
+            mapping.insert(serde_yaml::Value::String(root), inner);
+            serde_yaml::Value::Mapping(mapping)
+        }),
+        None => convert(yaml),
     };
     let converted = converted.with_context(|| {
         format!(
@@ -903,11 +1044,39 @@ fn convert_yaml_translation_file(
     Ok(ConvertOutcome::Converted)
 }
 
+fn convert_toml_translation_file(
+    path: &Path,
+    target: KeyConversionTarget,
+) -> anyhow::Result<ConvertOutcome> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read locale file {}", path.display()))?;
+    let table: toml::Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse TOML locale file {}", path.display()))?;
+    let converted = match target {
+        KeyConversionTarget::Nested => toml_to_nested(table),
+        KeyConversionTarget::Flat => toml_to_flat(table),
+    };
+    let converted = converted.with_context(|| {
+        format!(
+            "Failed to convert TOML locale file {} without key conflicts",
+            path.display()
+        )
+    })?;
+
+    let output = toml::to_string(&converted)?;
+    if content == output {
+        return Ok(ConvertOutcome::Skipped);
+    }
+    std::fs::write(path, output)
+        .with_context(|| format!("Failed to write locale file {}", path.display()))?;
+    Ok(ConvertOutcome::Converted)
+}
+
 fn sort_translation_files(workspace: &Path, config: &I18nConfig) -> anyhow::Result<SortSummary> {
     let mut sorted = 0;
     let mut skipped = 0;
 
-    for file in collect_translation_files(workspace, &config.locale_paths) {
+    for file in collect_translation_files(workspace, config) {
         match sort_translation_file(&file)? {
             SortOutcome::Sorted => sorted += 1,
             SortOutcome::Skipped => skipped += 1,
@@ -917,17 +1086,18 @@ fn sort_translation_files(workspace: &Path, config: &I18nConfig) -> anyhow::Resu
     Ok(SortSummary { sorted, skipped })
 }
 
-fn collect_translation_files(workspace: &Path, locale_paths: &[String]) -> Vec<PathBuf> {
+fn collect_translation_files(workspace: &Path, config: &I18nConfig) -> Vec<PathBuf> {
+    let walker = WorkspaceWalker::new(workspace).with_exclude(&config.exclude);
     let mut files = Vec::new();
 
-    for locale_path in locale_paths {
+    for locale_path in &config.locale_paths {
         let trimmed = locale_path.trim_end_matches(['/', '\\']);
         if trimmed.is_empty() {
             continue;
         }
 
         if has_glob_meta(trimmed) {
-            collect_glob_translation_files(workspace, trimmed, &mut files);
+            collect_glob_translation_files(&walker, workspace, trimmed, &mut files);
             continue;
         }
 
@@ -944,18 +1114,19 @@ fn collect_translation_files(workspace: &Path, locale_paths: &[String]) -> Vec<P
     files
 }
 
-fn collect_glob_translation_files(workspace: &Path, pattern: &str, files: &mut Vec<PathBuf>) {
+fn collect_glob_translation_files(
+    walker: &WorkspaceWalker,
+    workspace: &Path,
+    pattern: &str,
+    files: &mut Vec<PathBuf>,
+) {
     let Ok(glob) = Glob::new(pattern) else {
         return;
     };
     let matcher = glob.compile_matcher();
 
-    for entry in WalkDir::new(workspace)
-        .into_iter()
-        .filter_entry(|entry| !is_ignored_workspace_dir(entry.path()))
-        .filter_map(|entry| entry.ok())
-    {
-        let path = entry.path();
+    for path in walker.walk(workspace) {
+        let path = path.as_path();
         let Ok(relative_path) = path.strip_prefix(workspace) else {
             continue;
         };
@@ -976,21 +1147,6 @@ fn has_glob_meta(path: &str) -> bool {
     path.contains('*') || path.contains('?') || path.contains('[')
 }
 
-fn is_ignored_workspace_dir(path: &Path) -> bool {
-    if !path.is_dir() {
-        return false;
-    }
-
-    path.file_name()
-        .and_then(|name| name.to_str())
-        .is_some_and(|name| {
-            matches!(
-                name,
-                "node_modules" | ".git" | "target" | "dist" | "build" | ".nuxt" | ".output"
-            )
-        })
-}
-
 fn collect_dir_translation_files(dir: &Path, files: &mut Vec<PathBuf>) {
     for entry in WalkDir::new(dir)
         .max_depth(3)
@@ -1007,7 +1163,18 @@ fn collect_dir_translation_files(dir: &Path, files: &mut Vec<PathBuf>) {
 fn is_translation_extension(path: &Path) -> bool {
     matches!(
         path.extension().and_then(|extension| extension.to_str()),
-        Some("json" | "yaml" | "yml" | "arb" | "php")
+        Some(
+            "json"
+                | "jsonc"
+                | "json5"
+                | "yaml"
+                | "yml"
+                | "toml"
+                | "arb"
+                | "php"
+                | "properties"
+                | "resx"
+        )
     )
 }
 
@@ -1015,6 +1182,7 @@ fn sort_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
     match path.extension().and_then(|extension| extension.to_str()) {
         Some("json") => sort_json_translation_file(path),
         Some("yaml") | Some("yml") => sort_yaml_translation_file(path),
+        Some("toml") => sort_toml_translation_file(path),
         Some("arb") => sort_arb_translation_file(path),
         _ => Ok(SortOutcome::Skipped),
     }
@@ -1023,8 +1191,9 @@ fn sort_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
 fn sort_json_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read locale file {}", path.display()))?;
-    let mut json: serde_json::Value = serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse JSON locale file {}", path.display()))?;
+    let Some(mut json) = parse_strict_json(path, &content)? else {
+        return Ok(SortOutcome::Skipped);
+    };
     sort_json_value(&mut json);
 
     let mut output = serde_json::to_string_pretty(&json)?;
@@ -1032,6 +1201,17 @@ fn sort_json_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
     write_if_changed(path, &content, output)
 }
 
+/// Parse a `.json` locale file for a full rewrite. Files that only parse as JSONC/JSON5 return
+/// `None`, since re-serializing them would drop their comments.
+fn parse_strict_json(path: &Path, content: &str) -> anyhow::Result<Option<serde_json::Value>> {
+    match serde_json::from_str(content) {
+        Ok(json) => Ok(Some(json)),
+        Err(_) if json5::from_str::<serde_json::Value>(content).is_ok() => Ok(None),
+        Err(error) => Err(error)
+            .with_context(|| format!("Failed to parse JSON locale file {}", path.display())),
+    }
+}
+
 fn sort_arb_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read locale file {}", path.display()))?;
@@ -1055,6 +1235,17 @@ fn sort_yaml_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
     write_if_changed(path, &content, output)
 }
 
+fn sort_toml_translation_file(path: &Path) -> anyhow::Result<SortOutcome> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read locale file {}", path.display()))?;
+    // `toml::Table` is ordered by key, so re-serializing sorts every table.
+    let table: toml::Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse TOML locale file {}", path.display()))?;
+
+    let output = toml::to_string(&table)?;
+    write_if_changed(path, &content, output)
+}
+
 fn write_if_changed(path: &Path, before: &str, after: String) -> anyhow::Result<SortOutcome> {
     if before == after {
         return Ok(SortOutcome::Skipped);
@@ -1268,6 +1459,68 @@ fn insert_yaml_value(
     Ok(())
 }
 
+fn toml_to_nested(table: toml::Table) -> anyhow::Result<toml::Table> {
+    let mut nested = toml::Table::new();
+    for (key, value) in flatten_toml_entries("", toml::Value::Table(table)) {
+        insert_toml_value(&mut nested, &key, value)?;
+    }
+    Ok(nested)
+}
+
+fn toml_to_flat(table: toml::Table) -> anyhow::Result<toml::Table> {
+    let entries = flatten_toml_entries("", toml::Value::Table(table));
+    let mut flat = toml::Table::new();
+    for (key, value) in entries {
+        if flat.contains_key(&key) {
+            return Err(anyhow!("conflicting key `{key}`"));
+        }
+        flat.insert(key, value);
+    }
+    Ok(flat)
+}
+
+fn flatten_toml_entries(prefix: &str, value: toml::Value) -> Vec<(String, toml::Value)> {
+    match value {
+        toml::Value::Table(map) => {
+            let mut entries = Vec::new();
+            for (key, child) in map {
+                let next_key = join_key(prefix, &key);
+                if child.is_table() {
+                    entries.extend(flatten_toml_entries(&next_key, child));
+                } else {
+                    entries.push((next_key, child));
+                }
+            }
+            entries
+        }
+        other => vec![(prefix.to_string(), other)],
+    }
+}
+
+fn insert_toml_value(table: &mut toml::Table, key: &str, value: toml::Value) -> anyhow::Result<()> {
+    let parts: Vec<&str> = key.split('.').filter(|part| !part.is_empty()).collect();
+    let Some((last, parents)) = parts.split_last() else {
+        return Err(anyhow!("empty key in TOML locale file"));
+    };
+
+    let mut current = table;
+    for part in parents {
+        let child = current
+            .entry((*part).to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        let Some(child) = child.as_table_mut() else {
+            return Err(anyhow!("conflicting key `{key}`"));
+        };
+        current = child;
+    }
+
+    if current.get(*last).is_some_and(toml::Value::is_table) && !value.is_table() {
+        return Err(anyhow!("conflicting key `{key}`"));
+    }
+    current.insert((*last).to_string(), value);
+    Ok(())
+}
+
 fn join_key(prefix: &str, key: &str) -> String {
     if prefix.is_empty() {
         key.to_string()
@@ -1285,15 +1538,29 @@ fn apply_missing_translations(
     let mut added = 0;
     let mut skipped = 0;
 
+    let namespaces = config.namespaces();
+
     for item in &report.missing {
         for locale in &item.missing_in {
-            let Some(file) = find_locale_file(workspace, config, locale) else {
+            let (file, key) = match &namespaces {
+                Some(namespaces) => {
+                    let (namespace, key) = namespaces.split(&item.key);
+                    let file = find_namespace_file(workspace, config, locale, namespace);
+                    (file, key)
+                }
+                None => (
+                    find_locale_file(workspace, config, locale),
+                    item.key.as_str(),
+                ),
+            };
+            let Some(file) = file else {
                 skipped += 1;
                 continue;
             };
 
             let value = placeholder.unwrap_or(&item.source_value);
-            if add_translation_to_file(&file, &item.key, value)? {
+            let literal = uses_literal_keys(workspace, &file, &config.literal_key_paths);
+            if add_translation_to_file(&file, key, value, literal)? {
                 added += 1;
             } else {
                 skipped += 1;
@@ -1304,9 +1571,16 @@ fn apply_missing_translations(
     Ok(MissingWriteSummary { added, skipped })
 }
 
-fn add_translation_to_file(path: &Path, key: &str, value: &str) -> anyhow::Result<bool> {
+fn add_translation_to_file(
+    path: &Path,
+    key: &str,
+    value: &str,
+    literal: bool,
+) -> anyhow::Result<bool> {
     match path.extension().and_then(|extension| extension.to_str()) {
-        Some("json") => add_json_translation(path, key, value).map(|_| true),
+        Some("json" | "jsonc" | "json5") => {
+            add_json_translation(path, key, value, literal).map(|_| true)
+        }
         Some("yaml") | Some("yml") => add_yaml_translation(path, key, value).map(|_| true),
         Some("arb") => add_arb_translation(path, key, value).map(|_| true),
         Some("php") => add_php_translation(path, key, value).map(|_| true),
@@ -1314,16 +1588,37 @@ fn add_translation_to_file(path: &Path, key: &str, value: &str) -> anyhow::Resul
     }
 }
 
-fn add_json_translation(path: &Path, key: &str, value: &str) -> anyhow::Result<()> {
+fn add_json_translation(path: &Path, key: &str, value: &str, literal: bool) -> anyhow::Result<()> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read locale file {}", path.display()))?;
-    let mut json: serde_json::Value = serde_json::from_str(&content)
-        .with_context(|| format!("Failed to parse JSON locale file {}", path.display()))?;
-
-    insert_json_key(&mut json, key, value);
+    let output = match serde_json::from_str::<serde_json::Value>(&content) {
+        Ok(mut json) if literal => {
+            if !json.is_object() {
+                json = serde_json::json!({});
+            }
+            json[key] = serde_json::Value::String(value.to_string());
+            let mut output = serde_json::to_string_pretty(&json)?;
+            output.push('\n');
+            output
+        }
+        Ok(mut json) => {
+            insert_json_key(&mut json, key, value);
+            let mut output = serde_json::to_string_pretty(&json)?;
+            output.push('\n');
+            output
+        }
+        // JSONC/JSON5: splice the key into the text so comments survive.
+        Err(_) => {
+            let parts: Vec<&str> = if literal {
+                vec![key]
+            } else {
+                key.split('.').collect()
+            };
+            jsonc::insert_key_path(&content, &parts, value)
+                .with_context(|| format!("Failed to parse JSON locale file {}", path.display()))?
+        }
+    };
 
-    let mut output = serde_json::to_string_pretty(&json)?;
-    output.push('\n');
     std::fs::write(path, output)
         .with_context(|| format!("Failed to write locale file {}", path.display()))?;
     Ok(())
@@ -1335,7 +1630,10 @@ fn add_yaml_translation(path: &Path, key: &str, value: &str) -> anyhow::Result<(
     let mut yaml: serde_yaml::Value = serde_yaml::from_str(&content)
         .with_context(|| format!("Failed to parse YAML locale file {}", path.display()))?;
 
-    insert_yaml_key(&mut yaml, key, value);
+    match yaml_locale_root(path, &yaml) {
+        Some(root) => insert_yaml_key(&mut yaml, &format!("{root}.{key}"), value),
+        None => insert_yaml_key(&mut yaml, key, value),
+    }
 
     let output = serde_yaml::to_string(&yaml)?;
     std::fs::write(path, output)
@@ -1407,6 +1705,17 @@ fn insert_json_key(json: &mut serde_json::Value, key: &str, value: &str) {
     }
 }
 
+/// Rails-style YAML nests every key under the locale the file is named after (`en.yml` → `en:`).
+fn yaml_locale_root(path: &Path, yaml: &serde_yaml::Value) -> Option<String> {
+    let mapping = yaml.as_mapping()?;
+    if mapping.len() != 1 {
+        return None;
+    }
+    let root = mapping.keys().next()?.as_str()?;
+    let stem = path.file_stem()?.to_str()?;
+    (stem == root || stem.ends_with(&format!(".{root}"))).then(|| root.to_string())
+}
+
 fn insert_yaml_key(yaml: &mut serde_yaml::Value, key: &str, value: &str) {
     if !yaml.is_mapping() {
         *yaml = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
@@ -1496,6 +1805,9 @@ fn format_fix_dry_run(workspace: &Path, config: &I18nConfig, report: &AuditRepor
                 "  expected placeholders: {}\n",
                 item.expected_placeholders.join(", ")
             ));
+            if let Some(details) = &item.details {
+                output.push_str(&format!("  details: {}\n", details));
+            }
             output.push_str(&format!(
                 "  mismatched locales: {}\n",
                 locales
@@ -1546,10 +1858,35 @@ fn detect_php_root_indent(content: &str) -> String {
         .unwrap_or_else(|| "    ".to_string())
 }
 
+/// The file holding `namespace` for `locale` (`locales/en/common.json`). The default namespace
+/// falls back to the locale's single file (`locales/en.json`).
+fn find_namespace_file(
+    workspace: &Path,
+    config: &I18nConfig,
+    locale: &str,
+    namespace: &str,
+) -> Option<PathBuf> {
+    for locale_path in &config.locale_paths {
+        let base = workspace.join(locale_path).join(locale);
+        for extension in ["json", "jsonc", "json5", "yaml", "yml"] {
+            let candidate = base.join(format!("{namespace}.{extension}"));
+            if candidate.exists() {
+                return Some(candidate);
+            }
+        }
+    }
+
+    if namespace == config.default_namespace {
+        return find_locale_file(workspace, config, locale);
+    }
+
+    None
+}
+
 fn find_locale_file(workspace: &Path, config: &I18nConfig, locale: &str) -> Option<PathBuf> {
     for locale_path in &config.locale_paths {
         let base = workspace.join(locale_path);
-        for extension in ["json", "yaml", "yml", "arb", "php"] {
+        for extension in ["json", "jsonc", "json5", "yaml", "yml", "arb", "php"] {
             let candidate = base.join(format!("{locale}.{extension}"));
             if candidate.exists() {
                 return Some(candidate);
@@ -1628,6 +1965,24 @@ fn format_terminal(report: &AuditReport, suggest_fixes: bool) -> String {
                 .bold()
         ));
     }
+    if report.summary.dynamic_keys > 0 {
+        output.push_str(&format!(
+            "  Dynamic Keys:      {}\n",
+            report.summary.dynamic_keys.to_string().yellow().bold()
+        ));
+    }
+    if report.summary.duplicate_keys > 0 {
+        output.push_str(&format!(
+            "  Duplicate Keys:    {}\n",
+            report.summary.duplicate_keys.to_string().yellow().bold()
+        ));
+    }
+    if report.summary.suppressed > 0 {
+        output.push_str(&format!(
+            "  Suppressed:        {}\n",
+            report.summary.suppressed.to_string().dimmed()
+        ));
+    }
     output.push('\n');
 
     if !report.missing.is_empty() {
@@ -1688,7 +2043,7 @@ fn format_terminal(report: &AuditReport, suggest_fixes: bool) -> String {
             output.push_str(&format!(
                 "    Defined in: {}:{}\n",
                 item.defined_in.file_path.display().to_string().dimmed(),
-                item.defined_in.line
+                item.defined_in.line + 1
             ));
             output.push('\n');
         }
@@ -1705,6 +2060,9 @@ fn format_terminal(report: &AuditReport, suggest_fixes: bool) -> String {
                 "    Expected placeholders: {}\n",
                 item.expected_placeholders.join(", ").cyan()
             ));
+            if let Some(details) = &item.details {
+                output.push_str(&format!("    Details: {}\n", details));
+            }
             output.push_str("    Mismatched locales:\n");
             for (locale, value) in &item.locale_values {
                 output.push_str(&format!("      {}: {}\n", locale.red(), value));
@@ -1713,7 +2071,48 @@ fn format_terminal(report: &AuditReport, suggest_fixes: bool) -> String {
         }
     }
 
-    if report.missing.is_empty() && report.unused.is_empty() && report.placeholder_issues.is_empty()
+    if !report.dynamic_keys.is_empty() {
+        output.push_str(&"Dynamic Keys\n".yellow().bold().underline().to_string());
+        for item in &report.dynamic_keys {
+            output.push_str(&format!("  {} {}\n", "•".yellow(), item.key.yellow()));
+            output.push_str(&format!("    {}\n", describe_dynamic_key(item)));
+            for usage in &item.used_in {
+                output.push_str(&format!(
+                    "      - {}:{}\n",
+                    usage.file.display().to_string().dimmed(),
+                    usage.line + 1
+                ));
+            }
+            output.push('\n');
+        }
+    }
+
+    if !report.duplicate_keys.is_empty() {
+        output.push_str(&"Duplicate Keys\n".yellow().bold().underline().to_string());
+        for item in &report.duplicate_keys {
+            output.push_str(&format!(
+                "  {} {} ({})\n",
+                "•".yellow(),
+                item.key.yellow(),
+                item.locale
+            ));
+            output.push_str(&format!("    {}\n", describe_duplicate_key(item)));
+            for location in &item.defined_in {
+                output.push_str(&format!(
+                    "      - {}:{}\n",
+                    location.file_path.display().to_string().dimmed(),
+                    location.line + 1
+                ));
+            }
+            output.push('\n');
+        }
+    }
+
+    if report.missing.is_empty()
+        && report.unused.is_empty()
+        && report.placeholder_issues.is_empty()
+        && report.dynamic_keys.is_empty()
+        && report.duplicate_keys.is_empty()
     {
         output.push_str(&format!("{}\n", "✓ All i18n checks passed!".green().bold()));
     }
@@ -1721,6 +2120,34 @@ fn format_terminal(report: &AuditReport, suggest_fixes: bool) -> String {
     output
 }
 
+/// Whether a dynamic key finding was checked by prefix or could not be checked at all.
+fn describe_dynamic_key(item: &DynamicKeyUsage) -> String {
+    match item.kind {
+        KeyKind::Dynamic => {
+            "Dynamic key, not checked. List the keys it may use under allowedDynamicPrefixes"
+                .to_string()
+        }
+        _ => "Template key, no translation keys start with this prefix".to_string(),
+    }
+}
+
+/// Which definition of a duplicated key wins, and whether the others disagree with it.
+fn describe_duplicate_key(item: &DuplicateKey) -> String {
+    let winner = item
+        .defined_in
+        .last()
+        .map(|location| location.file_path.display().to_string())
+        .unwrap_or_default();
+    if item.conflicting {
+        format!("Defined with different values, {} wins", winner)
+    } else {
+        format!(
+            "Defined more than once with the same value, {} wins",
+            winner
+        )
+    }
+}
+
 fn format_markdown(report: &AuditReport, suggest_fixes: bool) -> String {
     let mut md = String::new();
 
@@ -1755,6 +2182,21 @@ fn format_markdown(report: &AuditReport, suggest_fixes: bool) -> String {
             report.summary.placeholder_mismatches
         ));
     }
+    if report.summary.dynamic_keys > 0 {
+        md.push_str(&format!(
+            "| Dynamic Keys | **{}** ⚠️ |\n",
+            report.summary.dynamic_keys
+        ));
+    }
+    if report.summary.duplicate_keys > 0 {
+        md.push_str(&format!(
+            "| Duplicate Keys | **{}** ⚠️ |\n",
+            report.summary.duplicate_keys
+        ));
+    }
+    if report.summary.suppressed > 0 {
+        md.push_str(&format!("| Suppressed | {} |\n", report.summary.suppressed));
+    }
     md.push('\n');
 
     if !report.missing.is_empty() {
@@ -1804,7 +2246,7 @@ fn format_markdown(report: &AuditReport, suggest_fixes: bool) -> String {
                 "- `{}` - defined in `{}:{}`\n",
                 item.key,
                 item.defined_in.file_path.display(),
-                item.defined_in.line
+                item.defined_in.line + 1
             ));
         }
         md.push('\n');
@@ -1818,6 +2260,9 @@ fn format_markdown(report: &AuditReport, suggest_fixes: bool) -> String {
                 "- **Expected placeholders:** `{}`\n",
                 item.expected_placeholders.join("`, `")
             ));
+            if let Some(details) = &item.details {
+                md.push_str(&format!("- **Details:** {}\n", details));
+            }
             md.push_str("- **Mismatched locales:**\n");
             for (locale, value) in &item.locale_values {
                 md.push_str(&format!("  - `{}`: `{}`\n", locale, value));
@@ -1826,7 +2271,45 @@ fn format_markdown(report: &AuditReport, suggest_fixes: bool) -> String {
         }
     }
 
-    if report.missing.is_empty() && report.unused.is_empty() && report.placeholder_issues.is_empty()
+    if !report.dynamic_keys.is_empty() {
+        md.push_str("## Dynamic Keys\n\n");
+        for item in &report.dynamic_keys {
+            md.push_str(&format!("### `{}`\n\n", item.key));
+            md.push_str(&format!("- {}\n", describe_dynamic_key(item)));
+            md.push_str("- **Used in:**\n");
+            for usage in &item.used_in {
+                md.push_str(&format!(
+                    "  - `{}:{}`\n",
+                    usage.file.display(),
+                    usage.line + 1
+                ));
+            }
+            md.push('\n');
+        }
+    }
+
+    if !report.duplicate_keys.is_empty() {
+        md.push_str("## Duplicate Keys\n\n");
+        for item in &report.duplicate_keys {
+            md.push_str(&format!("### `{}` ({})\n\n", item.key, item.locale));
+            md.push_str(&format!("- {}\n", describe_duplicate_key(item)));
+            md.push_str("- **Defined in:**\n");
+            for location in &item.defined_in {
+                md.push_str(&format!(
+                    "  - `{}:{}`\n",
+                    location.file_path.display(),
+                    location.line + 1
+                ));
+            }
+            md.push('\n');
+        }
+    }
+
+    if report.missing.is_empty()
+        && report.unused.is_empty()
+        && report.placeholder_issues.is_empty()
+        && report.dynamic_keys.is_empty()
+        && report.duplicate_keys.is_empty()
     {
         md.push_str("## ✓ All i18n checks passed!\n");
     }
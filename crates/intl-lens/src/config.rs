@@ -1,9 +1,14 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::i18n::ast::SourceLanguage;
+use crate::i18n::key_finder::FunctionPattern;
+use crate::i18n::namespace::Namespaces;
+use crate::preset::Preset;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct I18nConfig {
@@ -16,14 +21,75 @@ pub struct I18nConfig {
     #[serde(default = "default_key_style")]
     pub key_style: KeyStyle,
 
+    /// Key locale files by i18next namespace: `locales/en/common.json` contributes
+    /// `common:button.save`, and code resolves `t("common:button.save")` or `t("button.save")`
+    /// after `useTranslation("common")`.
     #[serde(default)]
     pub namespace_enabled: bool,
 
+    /// Namespace for keys without an explicit namespace and for locale files that are not split
+    /// by namespace (`locales/en.json`).
+    #[serde(default = "default_namespace")]
+    pub default_namespace: String,
+
+    /// Separator between namespace and key, as in i18next's `nsSeparator`.
+    #[serde(default = "default_ns_separator")]
+    pub ns_separator: String,
+
+    /// Libraries whose call patterns, locale directories and placeholder syntax to use. Unset
+    /// detects them from `package.json`, `composer.json` and `pubspec.yaml`; `[]` turns
+    /// presets off. Preset patterns are always added to `functionPatterns`, whether those are
+    /// the defaults or configured.
+    #[serde(default)]
+    pub presets: Option<Vec<Preset>>,
+
     #[serde(default = "default_function_patterns")]
-    pub function_patterns: Vec<String>,
+    pub function_patterns: Vec<FunctionPattern>,
+
+    /// Key extractor per source language, e.g. `{"tsx": "ast", "vue": "ast"}`. Languages not
+    /// listed use `functionPatterns`; `ast` parses `javascript`, `typescript`, `tsx`, `vue`,
+    /// `svelte` and `php` files and finds calls to the functions `functionPatterns` match.
+    #[serde(default)]
+    pub key_extractors: HashMap<SourceLanguage, KeyExtractor>,
+
+    /// How template keys (`` t(`status.${s}`) ``) without matching translations and fully
+    /// dynamic keys (`t(labelKey)`) are reported.
+    #[serde(default)]
+    pub dynamic_key_policy: DynamicKeyPolicy,
+
+    /// Key prefixes that are looked up dynamically. Keys under these prefixes count as used, and
+    /// template keys under them are not reported.
+    #[serde(default)]
+    pub allowed_dynamic_prefixes: Vec<String>,
+
+    /// Placeholder spellings compared between locales. Set by the `laravel` preset.
+    #[serde(default)]
+    pub placeholder_syntax: PlaceholderSyntax,
 
     #[serde(default = "default_display_mode")]
     pub display_mode: DisplayMode,
+
+    /// Locale paths (directories, files or globs) whose JSON files are keyed by literal strings,
+    /// e.g. Laravel's `lang/vi.json` (`"Log in.": "Đăng nhập."`). Dots in those keys are not
+    /// treated as nesting. Laravel JSON files next to PHP locale directories are detected
+    /// automatically.
+    #[serde(default)]
+    pub literal_key_paths: Vec<String>,
+
+    /// Globs, relative to the workspace root, selecting the source files to scan for keys.
+    /// Empty scans every file with a supported extension.
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Globs, relative to the workspace root, of source and locale files to skip on top of
+    /// `.gitignore` and `.ignore`.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Keep parsed locale files and found keys in `.i18nlens/cache`, so runs only parse files
+    /// that changed. Off by default, as it writes into the workspace.
+    #[serde(default)]
+    pub cache: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -35,6 +101,33 @@ pub enum KeyStyle {
     Auto,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeyExtractor {
+    #[default]
+    Regex,
+    Ast,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DynamicKeyPolicy {
+    Ignore,
+    #[default]
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaceholderSyntax {
+    /// ICU `{name}`, i18next/Vue `{{name}}` and printf `%s`.
+    #[default]
+    Icu,
+    /// Laravel `:name`, in addition to the ICU spellings.
+    Laravel,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum DisplayMode {
@@ -50,13 +143,49 @@ impl Default for I18nConfig {
             source_locale: default_source_locale(),
             key_style: default_key_style(),
             namespace_enabled: false,
+            default_namespace: default_namespace(),
+            ns_separator: default_ns_separator(),
+            presets: None,
             function_patterns: default_function_patterns(),
+            key_extractors: HashMap::new(),
+            dynamic_key_policy: DynamicKeyPolicy::default(),
+            allowed_dynamic_prefixes: Vec::new(),
+            placeholder_syntax: PlaceholderSyntax::default(),
             display_mode: default_display_mode(),
+            literal_key_paths: Vec::new(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            cache: false,
         }
     }
 }
 
 impl I18nConfig {
+    /// Namespace settings for the store and key finder, or `None` when namespaces are disabled.
+    pub fn namespaces(&self) -> Option<Namespaces> {
+        self.namespace_enabled
+            .then(|| Namespaces::new(&self.default_namespace, &self.ns_separator))
+    }
+
+    /// Languages whose keys are extracted with the tree-sitter extractor. Languages without
+    /// a bundled grammar keep using `functionPatterns`.
+    pub fn ast_languages(&self) -> HashSet<SourceLanguage> {
+        self.key_extractors
+            .iter()
+            .filter(|(language, extractor)| {
+                **extractor == KeyExtractor::Ast && language.has_grammar()
+            })
+            .map(|(language, _)| *language)
+            .collect()
+    }
+
+    /// Whether `key` falls under one of `allowedDynamicPrefixes`.
+    pub fn is_allowed_dynamic_key(&self, key: &str) -> bool {
+        self.allowed_dynamic_prefixes
+            .iter()
+            .any(|prefix| !prefix.is_empty() && key.starts_with(prefix.as_str()))
+    }
+
     pub fn load_from_workspace(root: &Path) -> Self {
         let config_paths = [
             root.join(".i18nlens.json"),
@@ -71,18 +200,36 @@ impl I18nConfig {
                 let raw_config = serde_json::from_str::<Value>(&content).ok();
 
                 if let Ok(mut config) = serde_json::from_str::<I18nConfig>(&content) {
-                    let has_locale_paths = raw_config
-                        .as_ref()
-                        .and_then(|value| value.as_object())
-                        .is_some_and(|object| {
-                            object.contains_key("localePaths")
-                                || object.contains_key("locale_paths")
-                                || object.contains_key("localesPaths")
-                        });
-
+                    let has_key = |keys: &[&str]| {
+                        raw_config
+                            .as_ref()
+                            .and_then(|value| value.as_object())
+                            .is_some_and(|object| keys.iter().any(|key| object.contains_key(*key)))
+                    };
+                    let has_locale_paths =
+                        has_key(&["localePaths", "locale_paths", "localesPaths"]);
+
+                    config.apply_presets(
+                        root,
+                        has_key(&["placeholderSyntax", "placeholder_syntax"]),
+                        has_locale_paths,
+                    );
                     if !has_locale_paths {
                         config.add_detected_locale_paths(root);
                     }
+                    for (language, _) in
+                        config
+                            .key_extractors
+                            .iter()
+                            .filter(|(language, extractor)| {
+                                **extractor == KeyExtractor::Ast && !language.has_grammar()
+                            })
+                    {
+                        tracing::warn!(
+                            "AST key extraction is not supported for {:?} (no grammar is bundled); keyExtractors \"ast\" is ignored and functionPatterns are used",
+                            language
+                        );
+                    }
 
                     tracing::info!("Loaded config from {:?}", config_path);
                     return config;
@@ -92,10 +239,48 @@ impl I18nConfig {
 
         tracing::info!("Using default config");
         let mut config = Self::default();
+        config.apply_presets(root, false, false);
         config.add_detected_locale_paths(root);
         config
     }
 
+    /// Resolve `presets`, detecting them when unset, and merge in their patterns, placeholder
+    /// syntax and locale paths where those were not configured. Preset patterns are added to
+    /// the default or configured `functionPatterns`, never replacing them.
+    fn apply_presets(&mut self, root: &Path, has_placeholder_syntax: bool, has_locale_paths: bool) {
+        let presets = self.presets.take().unwrap_or_else(|| detect_presets(root));
+
+        if !presets.is_empty() {
+            for preset in &presets {
+                for pattern in preset.function_patterns() {
+                    if !self.function_patterns.contains(&pattern) {
+                        self.function_patterns.push(pattern);
+                    }
+                }
+            }
+
+            if !has_placeholder_syntax {
+                if let Some(syntax) = presets
+                    .iter()
+                    .map(|preset| preset.placeholder_syntax())
+                    .find(|syntax| *syntax != PlaceholderSyntax::Icu)
+                {
+                    self.placeholder_syntax = syntax;
+                }
+            }
+
+            if !has_locale_paths {
+                for path in presets.iter().flat_map(|preset| preset.locale_paths()) {
+                    if root.join(path).exists() && !self.locale_paths.iter().any(|p| p == path) {
+                        self.locale_paths.push(path.to_string());
+                    }
+                }
+            }
+        }
+
+        self.presets = Some(presets);
+    }
+
     fn add_detected_locale_paths(&mut self, root: &Path) {
         let detected_paths = detect_framework_locale_paths(root);
         if detected_paths.is_empty() {
@@ -126,53 +311,203 @@ fn default_source_locale() -> String {
     "en".to_string()
 }
 
+fn default_namespace() -> String {
+    "translation".to_string()
+}
+
+fn default_ns_separator() -> String {
+    ":".to_string()
+}
+
 fn default_key_style() -> KeyStyle {
     KeyStyle::Auto
 }
 
-fn default_function_patterns() -> Vec<String> {
+fn default_function_patterns() -> Vec<FunctionPattern> {
     vec![
         // JavaScript/TypeScript patterns
         // Match t() but not .post(), .get(), .put(), .delete(), etc.
-        r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"i18n\.t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"useTranslation\s*\(\s*\)\s*.*?t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$tc\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$te\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"useI18n\s*\(\s*\)\s*.*?\.t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"formatMessage\s*\(\s*\{\s*id:\s*["']([^"']+)["']"#.to_string(),
+        scoped(
+            r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB_AND_RUBY,
+        ),
+        scoped(
+            r#"\bi18n(?:ext|\.global)?\.t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"useTranslation\s*\(\s*\)\s*.*?t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(r#"\$t\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(r#"\$tc\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(r#"\$te\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(
+            r#"useI18n\s*\(\s*\)\s*.*?\.t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"formatMessage\s*\(\s*\{\s*id:\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"<Trans\s+i18nKey\s*=\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
         // Svelte patterns (svelte-i18n)
-        r#"\$_\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$format\s*\(\s*["']([^"']+)["']"#.to_string(),
+        scoped(r#"\$_\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(r#"\$format\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
         // Angular patterns
-        r#"translateService\.(?:instant|get|stream)\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"translocoService\.(?:translate|selectTranslate)\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"["']([^"']+)["']\s*\|\s*(?:translate|transloco)\b"#.to_string(),
+        scoped(
+            r#"translateService\.(?:instant|get|stream)\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"translocoService\.(?:translate|selectTranslate)\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"["']([^"']+)["']\s*\|\s*(?:translate|transloco)\b"#,
+            SourceLanguage::WEB,
+        ),
+        // HTML template patterns (jquery-i18next, Angular i18n ids)
+        scoped(
+            r#"\bdata-i18n\s*=\s*["'](?:\[[\w-]+\])?([^"';\[\]]+)"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"\bi18n(?:-[\w-]+)?\s*=\s*"[^"]*?@@([\w.-]+)""#,
+            SourceLanguage::WEB,
+        ),
         // PHP/Laravel patterns
-        r#"__\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"trans(?:_choice)?\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"Lang::(?:get|choice)\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"@lang\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"@choice\s*\(\s*["']([^"']+)["']"#.to_string(),
+        scoped(r#"__\s*\(\s*["']([^"']+)["']"#, SourceLanguage::PHP),
+        scoped(
+            r#"trans(?:_choice)?\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::PHP,
+        ),
+        scoped(
+            r#"Lang::(?:get|choice)\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::PHP,
+        ),
+        scoped(r#"@lang\s*\(\s*["']([^"']+)["']"#, SourceLanguage::PHP),
+        scoped(r#"@choice\s*\(\s*["']([^"']+)["']"#, SourceLanguage::PHP),
+        // Python/Jinja and Elixir gettext
+        scoped(
+            r#"(?:^|[^\w.$])_\(\s*["']([^"']+)["']"#,
+            SourceLanguage::GETTEXT,
+        ),
+        scoped(
+            r#"(?:^|\W)u?n?gettext(?:_lazy|_noop)?(?:\s*\(\s*|\s+)["']([^"']+)["']"#,
+            SourceLanguage::GETTEXT,
+        ),
+        scoped(
+            r#"(?:^|\W)(?:dn?|n?p)gettext(?:_lazy)?(?:\s*\(\s*|\s+)["'][^"']*["']\s*,\s*["']([^"']+)["']"#,
+            SourceLanguage::GETTEXT,
+        ),
+        // Ruby/Rails patterns
+        scoped(
+            r#"I18n\.(?:t|translate)\b(?:\s*\(\s*|\s+)["']([^"']+)["']"#,
+            SourceLanguage::RUBY,
+        ),
+        scoped(
+            r#"I18n\.(?:t|translate)\b(?:\s*\(\s*|\s+):([A-Za-z_][\w.]*)"#,
+            SourceLanguage::RUBY,
+        ),
+        scoped(
+            r#"(?m)(?:<%=?|^[ \t]*[=-])\s*t\s+["']([^"']+)["']"#,
+            SourceLanguage::RUBY,
+        ),
+        // Go patterns (go-i18n)
+        scoped(
+            r#"LocalizeConfig\s*\{[^}]*?\bMessageID:\s*"([^"]+)""#,
+            &[SourceLanguage::Go],
+        ),
+        scoped(
+            r#"i18n\.Message\s*\{[^}]*?\bID:\s*"([^"]+)""#,
+            &[SourceLanguage::Go],
+        ),
+        // Kotlin/Java patterns (Android and Compose resources)
+        scoped(
+            r#"\bR\.(?:string|plurals)\.(\w+)"#,
+            &[SourceLanguage::Kotlin, SourceLanguage::Java],
+        ),
+        scoped(
+            r#"\bRes\.(?:string|plurals)\.(\w+)"#,
+            &[SourceLanguage::Kotlin, SourceLanguage::Java],
+        ),
+        // Swift patterns
+        scoped(
+            r#"NSLocalizedString\s*\(\s*"([^"]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        scoped(
+            r#"String\s*\(\s*localized:\s*"([^"]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        scoped(
+            r#"LocalizedStringKey\s*\(\s*"([^"]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        // SwiftUI views take a `LocalizedStringKey` title; `Text("…")` in Dart or JSX is a plain string
+        scoped(
+            r#"(?:\b(?:Text|Button|Label|Toggle|Link|Picker|Section)|\.navigationTitle)\s*\(\s*"([^"\\\n]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        // Rust patterns (rust-i18n, i18n-embed-fl)
+        scoped(
+            r#"(?:^|[^\w.])t!\s*\(\s*"([^"]+)""#,
+            &[SourceLanguage::Rust],
+        ),
+        scoped(
+            r#"(?:^|[^\w.])fl!\s*\(\s*[^,()]+,\s*"([^"]+)""#,
+            &[SourceLanguage::Rust],
+        ),
         // Flutter/Dart patterns - easy_localization
-        r#"['"]([^'"]+)['"]\s*\.tr\("#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.tr\(\)"#.to_string(),
-        r#"(?:^|[^\w.])tr\(\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"context\.tr\(\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.plural\("#.to_string(),
+        scoped(r#"['"]([^'"]+)['"]\s*\.tr\("#, &[SourceLanguage::Dart]),
+        scoped(r#"['"]([^'"]+)['"]\s*\.tr\(\)"#, &[SourceLanguage::Dart]),
+        scoped(
+            r#"(?:^|[^\w.])tr\(\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"context\.tr\(\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(r#"['"]([^'"]+)['"]\s*\.plural\("#, &[SourceLanguage::Dart]),
         // Flutter/Dart patterns - flutter_i18n
-        r#"FlutterI18n\.translate\([^,]+,\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"FlutterI18n\.plural\([^,]+,\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"I18nText\(\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"I18nPlural\(\s*['"]([^'"]+)['"]"#.to_string(),
+        scoped(
+            r#"FlutterI18n\.translate\([^,]+,\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"FlutterI18n\.plural\([^,]+,\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(r#"I18nText\(\s*['"]([^'"]+)['"]"#, &[SourceLanguage::Dart]),
+        scoped(
+            r#"I18nPlural\(\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
         // Flutter/Dart patterns - GetX
-        r#"['"]([^'"]+)['"]\s*\.tr(?:\s|$|\)|,)"#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.trParams\("#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.trPlural\("#.to_string(),
+        scoped(
+            r#"['"]([^'"]+)['"]\s*\.tr(?:\s|$|\)|,)"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"['"]([^'"]+)['"]\s*\.trParams\("#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"['"]([^'"]+)['"]\s*\.trPlural\("#,
+            &[SourceLanguage::Dart],
+        ),
     ]
 }
 
+fn scoped(pattern: &str, languages: &[SourceLanguage]) -> FunctionPattern {
+    FunctionPattern::for_languages(pattern, languages)
+}
+
 fn default_display_mode() -> DisplayMode {
     DisplayMode::InlayHints
 }
@@ -227,6 +562,46 @@ fn detect_framework_locale_paths(root: &Path) -> Vec<String> {
         .collect()
 }
 
+/// Presets for the i18n libraries a project depends on.
+fn detect_presets(root: &Path) -> Vec<Preset> {
+    let sections = ["dependencies", "devDependencies"];
+    let package_json = read_json(&root.join("package.json"));
+    let depends_on = |dependencies: &[&str]| {
+        package_json.as_ref().is_some_and(|value| {
+            dependencies
+                .iter()
+                .any(|dependency| json_has_dependency(value, dependency, &sections))
+        })
+    };
+
+    let mut presets = Vec::new();
+    if depends_on(&["i18next", "react-i18next", "next-i18next"]) {
+        presets.push(Preset::I18next);
+    }
+    if depends_on(&["vue-i18n", "@intlify/vue-i18n", "@nuxtjs/i18n"]) {
+        presets.push(Preset::VueI18n);
+    }
+    if depends_on(&["next-intl"]) {
+        presets.push(Preset::NextIntl);
+    }
+    if depends_on(&["react-intl"]) {
+        presets.push(Preset::ReactIntl);
+    }
+    if depends_on(&["@ngx-translate/core"]) {
+        presets.push(Preset::AngularNgxTranslate);
+    }
+    if is_laravel_project(root) {
+        presets.push(Preset::Laravel);
+    }
+    if is_flutter_project(root)
+        && std::fs::read_to_string(root.join("pubspec.yaml"))
+            .is_ok_and(|pubspec| pubspec.contains("easy_localization"))
+    {
+        presets.push(Preset::FlutterEasyLocalization);
+    }
+    presets
+}
+
 fn detect_nuxt_layer_locale_paths(root: &Path) -> Vec<String> {
     let layers_dir = root.join("layers");
     let Ok(entries) = std::fs::read_dir(layers_dir) else {
@@ -390,6 +765,67 @@ mod tests {
         fs::remove_dir_all(root).ok();
     }
 
+    #[test]
+    fn detected_presets_extend_default_function_patterns() {
+        let root = test_workspace("preset-detection");
+        fs::create_dir_all(root.join("public/locales")).expect("create locales");
+        fs::write(
+            root.join("package.json"),
+            r#"{"dependencies":{"react-i18next":"15.0.0"}}"#,
+        )
+        .expect("write package json");
+
+        let config = I18nConfig::load_from_workspace(&root);
+
+        assert_eq!(config.presets, Some(vec![Preset::I18next]));
+        let defaults = default_function_patterns();
+        assert_eq!(config.function_patterns[..defaults.len()], defaults[..]);
+        assert!(Preset::I18next
+            .function_patterns()
+            .iter()
+            .all(|pattern| config.function_patterns.contains(pattern)));
+        assert!(config.locale_paths.contains(&"public/locales".to_string()));
+        assert_eq!(config.placeholder_syntax, PlaceholderSyntax::Icu);
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn configured_presets_extend_configured_function_patterns() {
+        let root = test_workspace("preset-config");
+        fs::create_dir_all(&root).expect("create workspace");
+        fs::write(
+            root.join("package.json"),
+            r#"{"dependencies":{"react-i18next":"15.0.0"}}"#,
+        )
+        .expect("write package json");
+        fs::write(
+            root.join(".i18nlens.json"),
+            r#"{"presets":["laravel"],"functionPatterns":["translate\\('([^']+)'\\)"]}"#,
+        )
+        .expect("write config");
+
+        let config = I18nConfig::load_from_workspace(&root);
+
+        assert_eq!(config.presets, Some(vec![Preset::Laravel]));
+        assert_eq!(
+            config.function_patterns[0],
+            FunctionPattern::new(r"translate\('([^']+)'\)")
+        );
+        assert_eq!(
+            config.function_patterns.len(),
+            1 + Preset::Laravel.function_patterns().len()
+        );
+        assert_eq!(config.placeholder_syntax, PlaceholderSyntax::Laravel);
+
+        fs::write(root.join(".i18nlens.json"), r#"{"presets":[]}"#).expect("write config");
+        let config = I18nConfig::load_from_workspace(&root);
+        assert_eq!(config.presets, Some(Vec::new()));
+        assert_eq!(config.function_patterns, default_function_patterns());
+
+        fs::remove_dir_all(root).ok();
+    }
+
     #[test]
     fn reads_locales_paths_alias() {
         let config = serde_json::from_str::<I18nConfig>(
@@ -488,6 +924,19 @@ mod tests {
         assert_eq!(config.display_mode, DisplayMode::CodeLens);
     }
 
+    #[test]
+    fn reads_key_extractors_per_language() {
+        let config = serde_json::from_str::<I18nConfig>(
+            r#"{"keyExtractors":{"tsx":"ast","vue":"ast","php":"regex","dart":"ast"}}"#,
+        )
+        .expect("parse config");
+
+        assert_eq!(
+            config.ast_languages(),
+            HashSet::from([SourceLanguage::Tsx, SourceLanguage::Vue])
+        );
+    }
+
     fn test_workspace(name: &str) -> std::path::PathBuf {
         let nonce = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -0,0 +1,856 @@
+//! Tree-sitter based key extraction. Translation calls are found by callee name and argument
+//! position, so `t("x")` inside a comment or a string literal is ignored and calls may span
+//! several lines.
+
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Language, Node, Parser};
+
+use super::key_finder::KeyKind;
+
+/// Languages of scanned source files, used to select key extractors and scope function
+/// patterns. Only JavaScript, TypeScript, TSX, Vue, Svelte and PHP have a bundled grammar;
+/// files in the other languages always use the regex patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceLanguage {
+    JavaScript,
+    TypeScript,
+    Tsx,
+    Vue,
+    Svelte,
+    Php,
+    Blade,
+    Dart,
+    Html,
+    Astro,
+    Mdx,
+    Python,
+    Jinja,
+    Ruby,
+    Erb,
+    Haml,
+    Slim,
+    Go,
+    Elixir,
+    /// EEx and HEEx templates.
+    Eex,
+    Kotlin,
+    Java,
+    Swift,
+    Rust,
+}
+
+impl SourceLanguage {
+    /// JavaScript, component and HTML template languages.
+    pub const WEB: &'static [Self] = &[
+        Self::JavaScript,
+        Self::TypeScript,
+        Self::Tsx,
+        Self::Vue,
+        Self::Svelte,
+        Self::Html,
+        Self::Astro,
+        Self::Mdx,
+    ];
+    /// Ruby and the Rails view templates.
+    pub const RUBY: &'static [Self] = &[Self::Ruby, Self::Erb, Self::Haml, Self::Slim];
+    /// The [`Self::WEB`] and [`Self::RUBY`] languages, which share `t("key")`.
+    pub const WEB_AND_RUBY: &'static [Self] = &[
+        Self::JavaScript,
+        Self::TypeScript,
+        Self::Tsx,
+        Self::Vue,
+        Self::Svelte,
+        Self::Html,
+        Self::Astro,
+        Self::Mdx,
+        Self::Ruby,
+        Self::Erb,
+        Self::Haml,
+        Self::Slim,
+    ];
+    pub const PHP: &'static [Self] = &[Self::Php, Self::Blade];
+    /// Languages using gettext calls: Python, Jinja (also in `.html` templates) and Elixir.
+    pub const GETTEXT: &'static [Self] = &[
+        Self::Python,
+        Self::Jinja,
+        Self::Html,
+        Self::Elixir,
+        Self::Eex,
+    ];
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        // Blade templates are HTML with `{{ }}` echoes, not PHP.
+        if file_name.ends_with(".blade.php") {
+            return Some(Self::Blade);
+        }
+
+        match path.extension()?.to_str()? {
+            "js" | "jsx" | "mjs" | "cjs" => Some(Self::JavaScript),
+            "ts" | "mts" | "cts" => Some(Self::TypeScript),
+            "tsx" => Some(Self::Tsx),
+            "vue" => Some(Self::Vue),
+            "svelte" => Some(Self::Svelte),
+            "php" => Some(Self::Php),
+            "dart" => Some(Self::Dart),
+            "html" | "htm" => Some(Self::Html),
+            "astro" => Some(Self::Astro),
+            "mdx" => Some(Self::Mdx),
+            "py" => Some(Self::Python),
+            "jinja" | "jinja2" | "j2" => Some(Self::Jinja),
+            "rb" => Some(Self::Ruby),
+            "erb" => Some(Self::Erb),
+            "haml" => Some(Self::Haml),
+            "slim" => Some(Self::Slim),
+            "go" => Some(Self::Go),
+            "ex" | "exs" => Some(Self::Elixir),
+            "eex" | "heex" | "leex" => Some(Self::Eex),
+            "kt" | "kts" => Some(Self::Kotlin),
+            "java" => Some(Self::Java),
+            "swift" => Some(Self::Swift),
+            "rs" => Some(Self::Rust),
+            _ => None,
+        }
+    }
+
+    /// Whether a grammar is bundled for the language, so its files can use the `ast` key
+    /// extractor. Dart has none yet, so its `.tr()` calls are only found by the regex patterns.
+    pub fn has_grammar(self) -> bool {
+        matches!(
+            self,
+            Self::JavaScript | Self::TypeScript | Self::Tsx | Self::Vue | Self::Svelte | Self::Php
+        )
+    }
+}
+
+/// A key argument and the byte range of its text (without quotes) in the source. Template keys
+/// hold their literal prefix and dynamic keys the expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedKey {
+    pub key: String,
+    pub range: Range<usize>,
+    pub kind: KeyKind,
+}
+
+/// A translation call, derived from a function pattern by
+/// [`FunctionPattern::callees`](super::key_finder::FunctionPattern::callees).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Callee {
+    /// `t`, `i18n.t`, `Lang::get`, …
+    pub name: String,
+    /// Whether only calls of exactly `name` match. Otherwise member calls ending in it match
+    /// too, such as `this.i18n.t` for `i18n.t`.
+    pub exact: bool,
+    /// Whether the key is the `id` of a message descriptor `{ id: "key" }` rather than the
+    /// first argument.
+    pub descriptor: bool,
+}
+
+impl Callee {
+    fn matches(&self, path: &str) -> bool {
+        path == self.name
+            || !self.exact
+                && path
+                    .strip_suffix(self.name.as_str())
+                    .is_some_and(|object| object.ends_with('.'))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grammar {
+    JavaScript,
+    TypeScript,
+    Tsx,
+    Php,
+}
+
+impl Grammar {
+    fn language(self) -> Language {
+        match self {
+            Self::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Self::Php => tree_sitter_php::LANGUAGE_PHP.into(),
+        }
+    }
+}
+
+/// Extract the keys of calls to `callees` from `content`. Returns `None` when no grammar is
+/// bundled for `language`, so the caller can fall back to the regex patterns.
+pub fn extract_keys(
+    content: &str,
+    language: SourceLanguage,
+    callees: &[Callee],
+) -> Option<Vec<ExtractedKey>> {
+    let mut keys = Vec::new();
+    for (range, grammar) in grammar_regions(content, language)? {
+        extract_region(content, range, grammar, callees, &mut keys)?;
+    }
+
+    keys.sort_by_key(|key| key.range.start);
+    keys.dedup_by(|a, b| a.range.start == b.range.start);
+    Some(keys)
+}
+
+/// Byte ranges of the functions, arrow functions and methods in `content`, or `None` when no
+/// JavaScript grammar is bundled for `language`.
+pub fn function_ranges(content: &str, language: SourceLanguage) -> Option<Vec<Range<usize>>> {
+    let mut functions = Vec::new();
+    for (range, grammar) in grammar_regions(content, language)? {
+        if grammar == Grammar::Php {
+            return None;
+        }
+
+        let mut parser = Parser::new();
+        parser.set_language(&grammar.language()).ok()?;
+        let tree = parser.parse(&content[range.clone()], None)?;
+        let mut stack = vec![tree.root_node()];
+        while let Some(node) = stack.pop() {
+            if matches!(
+                node.kind(),
+                "function_declaration"
+                    | "function_expression"
+                    | "function"
+                    | "generator_function_declaration"
+                    | "generator_function"
+                    | "arrow_function"
+                    | "method_definition"
+            ) {
+                functions.push(node.start_byte() + range.start..node.end_byte() + range.start);
+            }
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+        }
+    }
+    Some(functions)
+}
+
+/// The parts of `content` to parse, with the grammar for each.
+fn grammar_regions(
+    content: &str,
+    language: SourceLanguage,
+) -> Option<Vec<(Range<usize>, Grammar)>> {
+    Some(match language {
+        SourceLanguage::JavaScript => vec![(0..content.len(), Grammar::JavaScript)],
+        SourceLanguage::TypeScript => vec![(0..content.len(), Grammar::TypeScript)],
+        SourceLanguage::Tsx => vec![(0..content.len(), Grammar::Tsx)],
+        SourceLanguage::Php => vec![(0..content.len(), Grammar::Php)],
+        SourceLanguage::Vue | SourceLanguage::Svelte => component_regions(content, language),
+        _ => return None,
+    })
+}
+
+fn extract_region(
+    content: &str,
+    range: Range<usize>,
+    grammar: Grammar,
+    callees: &[Callee],
+    keys: &mut Vec<ExtractedKey>,
+) -> Option<()> {
+    let mut parser = Parser::new();
+    parser.set_language(&grammar.language()).ok()?;
+    let source = &content[range.clone()];
+    let tree = parser.parse(source, None)?;
+
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        let found = match grammar {
+            Grammar::Php => php_call_key(node, source, callees),
+            _ => js_call_key(node, source, callees),
+        };
+        if let Some(mut found) = found {
+            found.range = found.range.start + range.start..found.range.end + range.start;
+            keys.push(found);
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    Some(())
+}
+
+fn js_call_key(node: Node, source: &str, callees: &[Callee]) -> Option<ExtractedKey> {
+    match node.kind() {
+        "call_expression" => {
+            let callee = js_callee_path(node.child_by_field_name("function")?, source)?;
+            let callee = callee.strip_prefix("this.").unwrap_or(&callee);
+            let argument = first_argument(node.child_by_field_name("arguments")?)?;
+            let called = |descriptor: bool| {
+                callees
+                    .iter()
+                    .any(|c| c.descriptor == descriptor && c.matches(callee))
+            };
+
+            if called(false) {
+                Some(js_key_argument(argument, source))
+            } else if called(true) && argument.kind() == "object" {
+                let mut cursor = argument.walk();
+                let id = argument
+                    .named_children(&mut cursor)
+                    .filter(|pair| pair.kind() == "pair")
+                    .find(|pair| {
+                        pair.child_by_field_name("key")
+                            .is_some_and(|key| text(key, source).trim_matches(['"', '\'']) == "id")
+                    })?;
+                Some(js_key_argument(id.child_by_field_name("value")?, source))
+            } else {
+                None
+            }
+        }
+        "jsx_opening_element" | "jsx_self_closing_element" => {
+            if text(node.child_by_field_name("name")?, source) != "Trans" {
+                return None;
+            }
+            let mut cursor = node.walk();
+            let attribute =
+                node.children_by_field_name("attribute", &mut cursor)
+                    .find(|attribute| {
+                        attribute.kind() == "jsx_attribute"
+                            && attribute
+                                .named_child(0)
+                                .is_some_and(|name| text(name, source) == "i18nKey")
+                    })?;
+            let value = attribute.named_child(1)?;
+            match value.kind() {
+                "jsx_expression" => Some(js_key_argument(value.named_child(0)?, source)),
+                _ => js_string_key(value, source),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `t`, `i18n.t`, `this.$t`, … for identifiers and member chains; `None` for other callees.
+fn js_callee_path(node: Node, source: &str) -> Option<String> {
+    match node.kind() {
+        "identifier" | "this" => Some(text(node, source).to_string()),
+        "member_expression" => {
+            let object = js_callee_path(node.child_by_field_name("object")?, source)?;
+            let property = node.child_by_field_name("property")?;
+            Some(format!("{}.{}", object, text(property, source)))
+        }
+        _ => None,
+    }
+}
+
+/// A static string key: a quoted string, a template literal without substitutions, or a
+/// concatenation of those such as `"checkout." + "title"`.
+fn js_string_key(node: Node, source: &str) -> Option<ExtractedKey> {
+    match node.kind() {
+        "string" => quoted_key(node, source),
+        "template_string" => {
+            let mut cursor = node.walk();
+            let has_substitution = node
+                .named_children(&mut cursor)
+                .any(|child| child.kind() == "template_substitution");
+            (!has_substitution)
+                .then(|| quoted_key(node, source))
+                .flatten()
+        }
+        "parenthesized_expression" => js_string_key(node.named_child(0)?, source),
+        "binary_expression" => concatenated_key(node, source, "+", js_string_key),
+        _ => None,
+    }
+}
+
+fn js_key_argument(node: Node, source: &str) -> ExtractedKey {
+    js_string_key(node, source)
+        .or_else(|| js_template_prefix(node, source))
+        .unwrap_or_else(|| dynamic_key(node, source))
+}
+
+/// The literal prefix of `` `status.${s}` `` or `"status." + s`.
+fn js_template_prefix(node: Node, source: &str) -> Option<ExtractedKey> {
+    let prefix = match node.kind() {
+        "template_string" => interpolation_prefix(node, source, &["string_fragment"]),
+        "parenthesized_expression" => js_template_prefix(node.named_child(0)?, source),
+        "binary_expression" if node.child_by_field_name("operator")?.kind() == "+" => {
+            let left = node.child_by_field_name("left")?;
+            js_string_key(left, source).or_else(|| js_template_prefix(left, source))
+        }
+        _ => None,
+    }?;
+    Some(ExtractedKey {
+        kind: KeyKind::Template,
+        ..prefix
+    })
+}
+
+fn php_call_key(node: Node, source: &str, callees: &[Callee]) -> Option<ExtractedKey> {
+    let callee = match node.kind() {
+        "function_call_expression" => {
+            let function = text(node.child_by_field_name("function")?, source);
+            function.trim_start_matches('\\').to_string()
+        }
+        "scoped_call_expression" => format!(
+            "{}::{}",
+            text(node.child_by_field_name("scope")?, source).trim_start_matches('\\'),
+            text(node.child_by_field_name("name")?, source)
+        ),
+        _ => return None,
+    };
+    if !callees.iter().any(|c| !c.descriptor && c.matches(&callee)) {
+        return None;
+    }
+
+    let argument = first_argument(node.child_by_field_name("arguments")?)?;
+    if argument.child_by_field_name("name").is_some() {
+        return None;
+    }
+    let value = argument.named_child(argument.named_child_count().checked_sub(1)?)?;
+    Some(
+        php_string_key(value, source)
+            .or_else(|| php_template_prefix(value, source))
+            .unwrap_or_else(|| dynamic_key(value, source)),
+    )
+}
+
+fn php_string_key(node: Node, source: &str) -> Option<ExtractedKey> {
+    match node.kind() {
+        "string" => quoted_key(node, source),
+        "encapsed_string" => {
+            let mut cursor = node.walk();
+            let interpolated = node
+                .named_children(&mut cursor)
+                .any(|child| !matches!(child.kind(), "string_content" | "escape_sequence"));
+            (!interpolated).then(|| quoted_key(node, source)).flatten()
+        }
+        "parenthesized_expression" => php_string_key(node.named_child(0)?, source),
+        "binary_expression" => concatenated_key(node, source, ".", php_string_key),
+        _ => None,
+    }
+}
+
+/// The literal prefix of `"users.{$id}"` or `'users.' . $id`.
+fn php_template_prefix(node: Node, source: &str) -> Option<ExtractedKey> {
+    let prefix = match node.kind() {
+        "encapsed_string" => interpolation_prefix(node, source, &["string_content"]),
+        "parenthesized_expression" => php_template_prefix(node.named_child(0)?, source),
+        "binary_expression" if node.child_by_field_name("operator")?.kind() == "." => {
+            let left = node.child_by_field_name("left")?;
+            php_string_key(left, source).or_else(|| php_template_prefix(left, source))
+        }
+        _ => None,
+    }?;
+    Some(ExtractedKey {
+        kind: KeyKind::Template,
+        ..prefix
+    })
+}
+
+/// The text of an interpolated string up to its first interpolation.
+fn interpolation_prefix(node: Node, source: &str, literal_kinds: &[&str]) -> Option<ExtractedKey> {
+    let mut cursor = node.walk();
+    let interpolation = node.children(&mut cursor).skip(1).find(|child| {
+        !literal_kinds.contains(&child.kind()) && child.kind() != "escape_sequence"
+    })?;
+    let range = node.start_byte() + 1..interpolation.start_byte();
+    (!range.is_empty()).then(|| ExtractedKey {
+        key: source[range.clone()].to_string(),
+        range,
+        kind: KeyKind::Static,
+    })
+}
+
+/// A key computed at runtime, such as `t(labelKey)`; the range covers its first line.
+fn dynamic_key(node: Node, source: &str) -> ExtractedKey {
+    let expression = text(node, source);
+    let first_line = expression.lines().next().unwrap_or_default().trim_end();
+    ExtractedKey {
+        key: expression.split_whitespace().collect::<Vec<_>>().join(" "),
+        range: node.start_byte()..node.start_byte() + first_line.len(),
+        kind: KeyKind::Dynamic,
+    }
+}
+
+fn first_argument(arguments: Node) -> Option<Node> {
+    let mut cursor = arguments.walk();
+    let first = arguments
+        .named_children(&mut cursor)
+        .find(|argument| argument.kind() != "comment");
+    first
+}
+
+fn quoted_key(node: Node, source: &str) -> Option<ExtractedKey> {
+    let range = node.start_byte() + 1..node.end_byte().checked_sub(1)?;
+    let key = source.get(range.clone())?;
+    (!key.is_empty()).then(|| ExtractedKey {
+        key: key.to_string(),
+        range,
+        kind: KeyKind::Static,
+    })
+}
+
+/// Join the parts of `left <operator> right`. The range covers the parts on the first line, so
+/// a key split across lines is still reported on a single line.
+fn concatenated_key(
+    node: Node,
+    source: &str,
+    operator: &str,
+    part: fn(Node, &str) -> Option<ExtractedKey>,
+) -> Option<ExtractedKey> {
+    if node.child_by_field_name("operator")?.kind() != operator {
+        return None;
+    }
+    let left = part(node.child_by_field_name("left")?, source)?;
+    let right = part(node.child_by_field_name("right")?, source)?;
+
+    let end = if source[left.range.start..right.range.end].contains('\n') {
+        left.range.end
+    } else {
+        right.range.end
+    };
+    Some(ExtractedKey {
+        key: left.key + &right.key,
+        range: left.range.start..end,
+        kind: KeyKind::Static,
+    })
+}
+
+fn text<'a>(node: Node, source: &'a str) -> &'a str {
+    &source[node.byte_range()]
+}
+
+/// Split a Vue or Svelte component into `<script>` blocks and template expressions. Styles,
+/// `<i18n>` blocks and HTML comments are skipped.
+fn component_regions(content: &str, language: SourceLanguage) -> Vec<(Range<usize>, Grammar)> {
+    static BLOCK_REGEX: OnceLock<Regex> = OnceLock::new();
+    let block_regex = BLOCK_REGEX.get_or_init(|| {
+        Regex::new(
+            r"(?is)<script\b([^>]*)>(.*?)</script\s*>|<style\b[^>]*>.*?</style\s*>|<i18n\b[^>]*>.*?</i18n\s*>|<!--.*?-->",
+        )
+        .expect("component block regex")
+    });
+    static LANG_REGEX: OnceLock<Regex> = OnceLock::new();
+    let lang_regex = LANG_REGEX
+        .get_or_init(|| Regex::new(r#"\blang\s*=\s*["']?(\w+)"#).expect("script lang regex"));
+
+    let mut regions = Vec::new();
+    let mut template_start = 0;
+    for cap in block_regex.captures_iter(content) {
+        let whole = cap.get(0).expect("whole match");
+        regions.extend(template_expressions(
+            content,
+            template_start..whole.start(),
+            language,
+        ));
+        template_start = whole.end();
+
+        if let (Some(attributes), Some(script)) = (cap.get(1), cap.get(2)) {
+            let grammar = match lang_regex
+                .captures(attributes.as_str())
+                .and_then(|lang| lang.get(1))
+                .map(|lang| lang.as_str())
+            {
+                Some("ts") => Grammar::TypeScript,
+                Some("tsx") => Grammar::Tsx,
+                _ => Grammar::JavaScript,
+            };
+            regions.push((script.range(), grammar));
+        }
+    }
+    regions.extend(template_expressions(
+        content,
+        template_start..content.len(),
+        language,
+    ));
+
+    regions
+}
+
+/// Expressions in template markup: Vue `{{ … }}` interpolations and `:prop`, `v-*` and `@event`
+/// attribute values; Svelte `{…}` tags.
+fn template_expressions(
+    content: &str,
+    range: Range<usize>,
+    language: SourceLanguage,
+) -> Vec<(Range<usize>, Grammar)> {
+    let markup = &content[range.clone()];
+    let offset = |inner: Range<usize>| inner.start + range.start..inner.end + range.start;
+
+    if language == SourceLanguage::Svelte {
+        return svelte_tags(markup)
+            .into_iter()
+            .map(|inner| (offset(inner), Grammar::JavaScript))
+            .collect();
+    }
+
+    static EXPRESSION_REGEX: OnceLock<Regex> = OnceLock::new();
+    let expression_regex = EXPRESSION_REGEX.get_or_init(|| {
+        Regex::new(r#"(?s)\{\{(.*?)\}\}|\s(?::|@|#|v-)[^\s=>]*\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+            .expect("vue template expression regex")
+    });
+    expression_regex
+        .captures_iter(markup)
+        .filter_map(|cap| cap.get(1).or_else(|| cap.get(2)).or_else(|| cap.get(3)))
+        .map(|inner| (offset(inner.range()), Grammar::JavaScript))
+        .collect()
+}
+
+/// The inner ranges of top-level `{…}` tags, without block keywords such as `#if` or `@html`.
+fn svelte_tags(markup: &str) -> Vec<Range<usize>> {
+    let mut tags = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (index, ch) in markup.char_indices() {
+        if depth > 0 {
+            if let Some(open) = quote {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == open {
+                    quote = None;
+                }
+                continue;
+            }
+            if matches!(ch, '"' | '\'' | '`') {
+                quote = Some(ch);
+                continue;
+            }
+        }
+
+        match ch {
+            '{' => {
+                if depth == 0 {
+                    start = index + 1;
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    tags.push(start..index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tags.into_iter()
+        .map(|tag| {
+            let inner = &markup[tag.clone()];
+            let keyword = inner
+                .strip_prefix(['#', ':', '/', '@'])
+                .map(|rest| {
+                    let word = rest
+                        .find(|c: char| !c.is_alphanumeric())
+                        .unwrap_or(rest.len());
+                    1 + word
+                })
+                .unwrap_or(0);
+            tag.start + keyword..tag.end
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::I18nConfig;
+    use crate::i18n::key_finder::FunctionPattern;
+
+    fn default_callees() -> Vec<Callee> {
+        I18nConfig::default()
+            .function_patterns
+            .iter()
+            .flat_map(FunctionPattern::callees)
+            .collect()
+    }
+
+    fn keys(content: &str, language: SourceLanguage) -> Vec<String> {
+        extract_keys(content, language, &default_callees())
+            .unwrap()
+            .into_iter()
+            .filter(|found| found.kind == KeyKind::Static)
+            .map(|found| found.key)
+            .collect()
+    }
+
+    fn classified(content: &str, language: SourceLanguage) -> Vec<(KeyKind, String)> {
+        extract_keys(content, language, &default_callees())
+            .unwrap()
+            .into_iter()
+            .map(|found| (found.kind, found.key))
+            .collect()
+    }
+
+    #[test]
+    fn test_ignores_calls_in_comments_and_strings() {
+        let content = r#"
+// t("commented.out")
+/* $t("block.comment") */
+const label = "t('inside.string')";
+const title = t("real.key");
+api.post("/api/products");
+"#;
+        assert_eq!(keys(content, SourceLanguage::TypeScript), vec!["real.key"]);
+    }
+
+    #[test]
+    fn test_finds_multiline_calls_and_split_keys() {
+        let content = "const a = t(\n  \"multi.line\",\n  { count }\n);\nconst b = i18n.t(\"checkout.\" +\n  \"title\");\nconst c = this.$t(`template.key`);\nconst d = t(`dynamic.${name}`);\n";
+        let found: Vec<ExtractedKey> =
+            extract_keys(content, SourceLanguage::JavaScript, &default_callees())
+                .unwrap()
+                .into_iter()
+                .filter(|found| found.kind == KeyKind::Static)
+                .collect();
+        let names: Vec<&str> = found.iter().map(|found| found.key.as_str()).collect();
+        assert_eq!(names, vec!["multi.line", "checkout.title", "template.key"]);
+        assert_eq!(&content[found[1].range.clone()], "checkout.");
+    }
+
+    #[test]
+    fn test_classifies_template_and_dynamic_keys() {
+        let content = r#"
+t(`status.${order.status}`);
+t("status." + order.status);
+t("order." + "status." + kind);
+t(`${scope}.title`);
+t(labels[kind]);
+<Trans i18nKey={`banner.${variant}`} />;
+"#;
+        assert_eq!(
+            classified(content, SourceLanguage::Tsx),
+            vec![
+                (KeyKind::Template, "status.".to_string()),
+                (KeyKind::Template, "status.".to_string()),
+                (KeyKind::Template, "order.status.".to_string()),
+                (KeyKind::Dynamic, "`${scope}.title`".to_string()),
+                (KeyKind::Dynamic, "labels[kind]".to_string()),
+                (KeyKind::Template, "banner.".to_string()),
+            ]
+        );
+
+        let php = r#"<?php
+echo __("users.{$id}.name");
+echo trans('roles.' . $role);
+echo __($message);
+"#;
+        assert_eq!(
+            classified(php, SourceLanguage::Php),
+            vec![
+                (KeyKind::Template, "users.".to_string()),
+                (KeyKind::Template, "roles.".to_string()),
+                (KeyKind::Dynamic, "$message".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_finds_tsx_trans_and_format_message() {
+        let content = r#"
+export function Page(): JSX.Element {
+  const intl = useIntl();
+  return (
+    <div title={intl.formatMessage({ id: "page.title" })}>
+      {/* <Trans i18nKey="commented.out" /> */}
+      <Trans i18nKey="page.body" />
+      <Trans i18nKey={"page.footer"}>Footer</Trans>
+    </div>
+  );
+}
+"#;
+        assert_eq!(
+            keys(content, SourceLanguage::Tsx),
+            vec!["page.title", "page.body", "page.footer"]
+        );
+    }
+
+    #[test]
+    fn test_finds_vue_and_svelte_template_keys() {
+        let vue = r#"<template>
+  <!-- {{ $t('commented.out') }} -->
+  <h1>{{ $t('page.title') }}</h1>
+  <input :placeholder="$t('form.email')" />
+</template>
+<script setup lang="ts">
+const { t } = useI18n();
+const label: string = t('form.submit');
+</script>
+<i18n>{"en": {"local": "t('not.a.call')"}}</i18n>
+"#;
+        assert_eq!(
+            keys(vue, SourceLanguage::Vue),
+            vec!["page.title", "form.email", "form.submit"]
+        );
+
+        let svelte = r#"<script>
+  const heading = $_('page.heading');
+</script>
+{#if $_('flags.enabled') === 'yes'}
+  <p title={$t('page.tooltip')}>{$_("page.body")}</p>
+{/if}
+"#;
+        assert_eq!(
+            keys(svelte, SourceLanguage::Svelte),
+            vec!["page.heading", "flags.enabled", "page.tooltip", "page.body"]
+        );
+    }
+
+    #[test]
+    fn test_finds_php_translation_calls() {
+        let content = r#"<?php
+// __('commented.out')
+$label = "__('inside.string')";
+echo __('messages.welcome');
+echo trans_choice("cart.items", $count);
+echo \Lang::get('auth.failed');
+echo __("users.{$id}");
+"#;
+        assert_eq!(
+            keys(content, SourceLanguage::Php),
+            vec!["messages.welcome", "cart.items", "auth.failed"]
+        );
+    }
+
+    #[test]
+    fn test_detects_language_from_path() {
+        assert_eq!(
+            SourceLanguage::from_path(Path::new("src/App.tsx")),
+            Some(SourceLanguage::Tsx)
+        );
+        assert_eq!(
+            SourceLanguage::from_path(Path::new("resources/views/home.blade.php")),
+            Some(SourceLanguage::Blade)
+        );
+        let callees = default_callees();
+        assert_eq!(
+            extract_keys("{{ __('x') }}", SourceLanguage::Blade, &callees),
+            None
+        );
+        assert_eq!(
+            extract_keys("'x'.tr()", SourceLanguage::Dart, &callees),
+            None
+        );
+        // No Dart grammar is bundled; Dart AST extraction is an open roadmap item.
+        assert!(!SourceLanguage::Dart.has_grammar());
+    }
+
+    #[test]
+    fn test_matches_callees_of_configured_patterns() {
+        let callees = FunctionPattern::new(r#"\btranslate\s*\(\s*["']([^"']+)["']"#).callees();
+        let content = r#"
+translate("custom.key");
+this.labels.translate("member.key");
+t("default.key");
+"#;
+        let found: Vec<String> = extract_keys(content, SourceLanguage::TypeScript, &callees)
+            .unwrap()
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(found, vec!["custom.key", "member.key"]);
+    }
+}
@@ -0,0 +1,388 @@
+//! A parser for ICU MessageFormat strings such as
+//! `{count, plural, one {# item} other {# items}}`.
+//!
+//! Only the message structure is parsed: argument names and types, plural/select branches and
+//! apostrophe quoting. Number/date styles and skeletons are skipped over.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageArgument {
+    pub name: String,
+    /// `string` for `{name}`, otherwise the argument type (`number`, `plural`, `select`, …).
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedMessage {
+    /// Distinct arguments in order of first appearance, including ones nested in branches.
+    pub arguments: Vec<MessageArgument>,
+    /// `plural`/`select`/`selectordinal` arguments that lack the required `other` branch.
+    pub missing_other: Vec<String>,
+    /// Every `plural` argument with its branch selectors (`one`, `=0`, …), including nested ones.
+    pub plurals: Vec<PluralArgument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluralArgument {
+    pub name: String,
+    pub selectors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcuSyntaxError {
+    pub message: String,
+    /// Character offset into the message.
+    pub offset: usize,
+}
+
+impl fmt::Display for IcuSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for IcuSyntaxError {}
+
+pub fn parse(message: &str) -> Result<ParsedMessage, IcuSyntaxError> {
+    let mut parser = IcuParser {
+        chars: message.chars().collect(),
+        pos: 0,
+        result: ParsedMessage::default(),
+    };
+
+    parser.parse_message(false)?;
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("Unmatched '}'"));
+    }
+
+    Ok(parser.result)
+}
+
+struct IcuParser {
+    chars: Vec<char>,
+    pos: usize,
+    result: ParsedMessage,
+}
+
+impl IcuParser {
+    /// Parse message text up to an unmatched `}` or the end of input.
+    fn parse_message(&mut self, in_plural: bool) -> Result<(), IcuSyntaxError> {
+        while let Some(ch) = self.peek() {
+            match ch {
+                '{' => self.parse_argument()?,
+                '}' => return Ok(()),
+                '\'' => self.skip_apostrophe(in_plural)?,
+                _ => self.pos += 1,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// An apostrophe quotes literal text only when followed by a syntax character;
+    /// `''` is always a literal apostrophe.
+    fn skip_apostrophe(&mut self, in_plural: bool) -> Result<(), IcuSyntaxError> {
+        let start = self.pos;
+        self.pos += 1;
+
+        match self.peek() {
+            Some('\'') => self.pos += 1,
+            Some('{' | '}' | '|') => self.skip_quoted(start)?,
+            Some('#') if in_plural => self.skip_quoted(start)?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn skip_quoted(&mut self, start: usize) -> Result<(), IcuSyntaxError> {
+        while let Some(ch) = self.next() {
+            if ch == '\'' {
+                if self.peek() == Some('\'') {
+                    self.pos += 1;
+                } else {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(IcuSyntaxError {
+            message: "Unterminated quoted literal".to_string(),
+            offset: start,
+        })
+    }
+
+    fn parse_argument(&mut self) -> Result<(), IcuSyntaxError> {
+        let start = self.pos;
+        self.pos += 1;
+        self.skip_whitespace();
+
+        let name = self.read_identifier();
+        if name.is_empty() {
+            return Err(self.error("Expected an argument name"));
+        }
+        self.skip_whitespace();
+
+        match self.next() {
+            Some('}') => {
+                self.record_argument(&name, "string");
+                Ok(())
+            }
+            Some(',') => {
+                self.skip_whitespace();
+                let kind = self.read_identifier();
+                if kind.is_empty() {
+                    return Err(self.error(&format!("Expected a type for argument '{}'", name)));
+                }
+                self.record_argument(&name, &kind);
+                self.skip_whitespace();
+
+                match kind.as_str() {
+                    "plural" => {
+                        let selectors = self.parse_options(&name, true)?;
+                        self.result.plurals.push(PluralArgument { name, selectors });
+                        Ok(())
+                    }
+                    "selectordinal" => self.parse_options(&name, true).map(|_| ()),
+                    "select" => self.parse_options(&name, false).map(|_| ()),
+                    _ => self.parse_style(start),
+                }
+            }
+            Some(_) => {
+                self.pos -= 1;
+                Err(self.error(&format!("Unexpected character in argument '{}'", name)))
+            }
+            None => Err(IcuSyntaxError {
+                message: format!("Unclosed argument '{}'", name),
+                offset: start,
+            }),
+        }
+    }
+
+    /// Skip `, style}` of a simple argument such as `{price, number, ::currency/EUR}`.
+    fn parse_style(&mut self, start: usize) -> Result<(), IcuSyntaxError> {
+        match self.next() {
+            Some('}') => return Ok(()),
+            Some(',') => {}
+            _ => {
+                self.pos = self.pos.saturating_sub(1);
+                return Err(self.error("Expected ',' or '}' after argument type"));
+            }
+        }
+
+        let mut depth = 0usize;
+        while let Some(ch) = self.next() {
+            match ch {
+                '\'' => {
+                    self.pos -= 1;
+                    self.skip_apostrophe(false)?;
+                }
+                '{' => depth += 1,
+                '}' if depth == 0 => return Ok(()),
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        Err(IcuSyntaxError {
+            message: "Unclosed argument style".to_string(),
+            offset: start,
+        })
+    }
+
+    fn parse_options(
+        &mut self,
+        name: &str,
+        is_plural: bool,
+    ) -> Result<Vec<String>, IcuSyntaxError> {
+        if self.next() != Some(',') {
+            self.pos = self.pos.saturating_sub(1);
+            return Err(self.error(&format!("Expected ',' before options of '{}'", name)));
+        }
+
+        let mut selectors = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                None => return Err(self.error(&format!("Unclosed argument '{}'", name))),
+                _ => {}
+            }
+
+            let selector_start = self.pos;
+            let selector = if self.peek() == Some('=') {
+                self.pos += 1;
+                format!("={}", self.read_identifier())
+            } else {
+                self.read_identifier()
+            };
+
+            if selector.is_empty() || selector == "=" {
+                return Err(self.error(&format!("Expected a selector in '{}'", name)));
+            }
+
+            if is_plural && selector.starts_with("offset:") {
+                continue;
+            }
+            if is_plural && selector == "offset" && self.peek() == Some(':') {
+                self.pos += 1;
+                self.skip_whitespace();
+                self.read_identifier();
+                continue;
+            }
+
+            self.skip_whitespace();
+            if self.next() != Some('{') {
+                return Err(IcuSyntaxError {
+                    message: format!("Expected '{{' after selector '{}' in '{}'", selector, name),
+                    offset: selector_start,
+                });
+            }
+
+            self.parse_message(is_plural)?;
+            if self.next() != Some('}') {
+                return Err(IcuSyntaxError {
+                    message: format!("Unclosed branch '{}' in '{}'", selector, name),
+                    offset: selector_start,
+                });
+            }
+
+            selectors.push(selector);
+        }
+
+        if selectors.is_empty() {
+            return Err(self.error(&format!("Argument '{}' has no options", name)));
+        }
+        if !selectors.iter().any(|selector| selector == "other")
+            && !self.result.missing_other.iter().any(|n| n == name)
+        {
+            self.result.missing_other.push(name.to_string());
+        }
+
+        Ok(selectors)
+    }
+
+    fn record_argument(&mut self, name: &str, kind: &str) {
+        match self
+            .result
+            .arguments
+            .iter_mut()
+            .find(|argument| argument.name == name)
+        {
+            // `{count}` inside a plural branch refers to the plural argument itself.
+            Some(existing) => {
+                if existing.kind == "string" {
+                    existing.kind = kind.to_string();
+                }
+            }
+            None => self.result.arguments.push(MessageArgument {
+                name: name.to_string(),
+                kind: kind.to_string(),
+            }),
+        }
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let mut identifier = String::new();
+        while let Some(ch) = self.peek() {
+            if ch.is_alphanumeric() || matches!(ch, '_' | '-' | '.' | '$' | ':' | '+') {
+                identifier.push(ch);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        identifier
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += 1;
+        Some(ch)
+    }
+
+    fn error(&self, message: &str) -> IcuSyntaxError {
+        IcuSyntaxError {
+            message: message.to_string(),
+            offset: self.pos,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(message: &ParsedMessage) -> Vec<(&str, &str)> {
+        message
+            .arguments
+            .iter()
+            .map(|argument| (argument.name.as_str(), argument.kind.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_plural_with_nested_arguments() {
+        let parsed = parse(
+            "{count, plural, offset:1 =0 {No messages from {sender}} one {# message} other {{count} messages}}",
+        )
+        .unwrap();
+        assert_eq!(
+            names(&parsed),
+            vec![("count", "plural"), ("sender", "string")]
+        );
+        assert!(parsed.missing_other.is_empty());
+    }
+
+    #[test]
+    fn parses_select_selectordinal_and_styles() {
+        let parsed = parse(
+            "{gender, select, female {She} male {He} other {They}} finished {place, selectordinal, one {#st} other {#th}} on {day, date, short} for {price, number, ::currency/EUR}",
+        )
+        .unwrap();
+        assert_eq!(
+            names(&parsed),
+            vec![
+                ("gender", "select"),
+                ("place", "selectordinal"),
+                ("day", "date"),
+                ("price", "number")
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_escaped_apostrophes() {
+        let parsed = parse("It''s '{literal}' and it's {name}").unwrap();
+        assert_eq!(names(&parsed), vec![("name", "string")]);
+
+        let parsed = parse("{n, plural, other {'#' is # items}}").unwrap();
+        assert_eq!(names(&parsed), vec![("n", "plural")]);
+    }
+
+    #[test]
+    fn reports_missing_other_and_syntax_errors() {
+        let parsed = parse("{count, plural, one {# item}}").unwrap();
+        assert_eq!(parsed.missing_other, vec!["count".to_string()]);
+
+        assert!(parse("Hello {name").is_err());
+        assert!(parse("Hello name}").is_err());
+        assert!(parse("{count, plural, one # item}").is_err());
+        assert!(parse("{}").is_err());
+    }
+}
@@ -0,0 +1,397 @@
+//! Text-based edits for JSONC/JSON5 locale files.
+//!
+//! Re-serializing these files through `serde_json` would drop comments and trailing commas, so
+//! keys are spliced into the original text instead.
+
+use anyhow::{bail, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    Punct(u8),
+    String,
+    Literal,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug)]
+struct Member {
+    key: String,
+    value_start: usize,
+    value_end: usize,
+    object: Option<ObjectSpan>,
+}
+
+#[derive(Debug)]
+struct ObjectSpan {
+    open: usize,
+    close: usize,
+    members: Vec<Member>,
+    trailing_comma: Option<usize>,
+}
+
+/// Insert (or overwrite) a dotted `key` with a string `value`, creating intermediate objects as
+/// needed. Comments, trailing commas and the existing layout are left untouched.
+pub fn insert_key(content: &str, key: &str, value: &str) -> Result<String> {
+    let parts: Vec<&str> = key.split('.').collect();
+    insert_key_path(content, &parts, value)
+}
+
+/// Like [`insert_key`], with the key already split into path segments. Pass a single segment
+/// to write a literal key that contains dots.
+pub fn insert_key_path(content: &str, parts: &[&str], value: &str) -> Result<String> {
+    let key = parts.join(".");
+    let tokens = tokenize(content)?;
+    let mut pos = 0;
+    let root = match tokens.first() {
+        Some(token) if token.kind == TokenKind::Punct(b'{') => {
+            parse_object(content, &tokens, &mut pos)?
+        }
+        _ => bail!("Locale file root is not an object"),
+    };
+
+    let indent = detect_indent_unit(content);
+    let mut object = &root;
+    let mut depth = 1;
+
+    for (index, part) in parts.iter().enumerate() {
+        let is_leaf = index == parts.len() - 1;
+        let Some(member) = object.members.iter().find(|member| member.key == *part) else {
+            let entry = build_entry(&parts[index..], value, &indent, depth);
+            return Ok(append_member(content, object, &entry, &indent, depth));
+        };
+
+        match &member.object {
+            Some(child) if !is_leaf => {
+                object = child;
+                depth += 1;
+            }
+            _ => {
+                // Overwrite the existing value, replacing scalars that sit in the way of a
+                // nested key with an object, as the serde-based writers do.
+                let replacement = if is_leaf {
+                    quote(value)
+                } else {
+                    let entry = build_entry(&parts[index + 1..], value, &indent, depth + 1);
+                    format!("{{\n{}\n{}}}", entry, indent.repeat(depth))
+                };
+                let mut output = String::with_capacity(content.len() + replacement.len());
+                output.push_str(&content[..member.value_start]);
+                output.push_str(&replacement);
+                output.push_str(&content[member.value_end..]);
+                return Ok(output);
+            }
+        }
+    }
+
+    bail!("Key '{}' resolves to an object", key)
+}
+
+fn append_member(
+    content: &str,
+    object: &ObjectSpan,
+    entry: &str,
+    indent: &str,
+    depth: usize,
+) -> String {
+    let mut output = String::with_capacity(content.len() + entry.len() + 8);
+    let trailing = if object.trailing_comma.is_some() {
+        ","
+    } else {
+        ""
+    };
+
+    // Insert on a fresh line before the closing brace when it sits on its own line, so comments
+    // after the last member stay attached to it.
+    let line_start = content[..object.close].rfind('\n').map_or(0, |i| i + 1);
+    let own_line = line_start > object.open && content[line_start..object.close].trim().is_empty();
+
+    let comma_at = match object.members.last() {
+        Some(last) if object.trailing_comma.is_none() => Some(last.value_end),
+        _ => None,
+    };
+
+    let mut cursor = 0;
+    if let Some(comma_at) = comma_at {
+        output.push_str(&content[..comma_at]);
+        output.push(',');
+        cursor = comma_at;
+    }
+
+    if own_line {
+        output.push_str(&content[cursor..line_start]);
+        output.push_str(entry);
+        output.push_str(trailing);
+        output.push('\n');
+        output.push_str(&content[line_start..]);
+    } else {
+        output.push_str(content[cursor..object.close].trim_end());
+        output.push('\n');
+        output.push_str(entry);
+        output.push_str(trailing);
+        output.push('\n');
+        output.push_str(&indent.repeat(depth - 1));
+        output.push_str(&content[object.close..]);
+    }
+
+    output
+}
+
+fn build_entry(parts: &[&str], value: &str, indent: &str, depth: usize) -> String {
+    let mut lines = Vec::new();
+    for (i, part) in parts.iter().enumerate() {
+        let level = indent.repeat(depth + i);
+        if i == parts.len() - 1 {
+            lines.push(format!("{}{}: {}", level, quote(part), quote(value)));
+        } else {
+            lines.push(format!("{}{}: {{", level, quote(part)));
+        }
+    }
+    for i in (0..parts.len().saturating_sub(1)).rev() {
+        lines.push(format!("{}}}", indent.repeat(depth + i)));
+    }
+    lines.join("\n")
+}
+
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+fn parse_object(content: &str, tokens: &[Token], pos: &mut usize) -> Result<ObjectSpan> {
+    let open = tokens[*pos].start;
+    *pos += 1;
+    let mut members = Vec::new();
+    let mut trailing_comma = None;
+
+    loop {
+        let Some(token) = tokens.get(*pos).copied() else {
+            bail!("Unterminated object");
+        };
+
+        match token.kind {
+            TokenKind::Punct(b'}') => {
+                *pos += 1;
+                return Ok(ObjectSpan {
+                    open,
+                    close: token.start,
+                    members,
+                    trailing_comma,
+                });
+            }
+            TokenKind::String | TokenKind::Literal => {
+                let key = token_text(content, &token);
+                *pos += 1;
+                if tokens.get(*pos).map(|t| t.kind) != Some(TokenKind::Punct(b':')) {
+                    bail!("Expected ':' after key '{}'", key);
+                }
+                *pos += 1;
+
+                let Some(value) = tokens.get(*pos).copied() else {
+                    bail!("Missing value for key '{}'", key);
+                };
+                let (object, value_end) = match value.kind {
+                    TokenKind::Punct(b'{') => {
+                        let object = parse_object(content, tokens, pos)?;
+                        let end = object.close + 1;
+                        (Some(object), end)
+                    }
+                    TokenKind::Punct(b'[') => (None, skip_array(tokens, pos)?),
+                    TokenKind::String | TokenKind::Literal => {
+                        *pos += 1;
+                        (None, value.end)
+                    }
+                    TokenKind::Punct(_) => bail!("Unexpected token in value of '{}'", key),
+                };
+
+                members.push(Member {
+                    key,
+                    value_start: value.start,
+                    value_end,
+                    object,
+                });
+
+                trailing_comma = None;
+                if let Some(next) = tokens.get(*pos) {
+                    if next.kind == TokenKind::Punct(b',') {
+                        trailing_comma = Some(next.start);
+                        *pos += 1;
+                    }
+                }
+            }
+            _ => bail!("Unexpected token at byte {}", token.start),
+        }
+    }
+}
+
+fn skip_array(tokens: &[Token], pos: &mut usize) -> Result<usize> {
+    let mut depth = 0usize;
+    while let Some(token) = tokens.get(*pos) {
+        *pos += 1;
+        match token.kind {
+            TokenKind::Punct(b'[' | b'{') => depth += 1,
+            TokenKind::Punct(b']' | b'}') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(token.end);
+                }
+            }
+            _ => {}
+        }
+    }
+    bail!("Unterminated array")
+}
+
+fn token_text(content: &str, token: &Token) -> String {
+    let raw = &content[token.start..token.end];
+    if token.kind != TokenKind::String {
+        return raw.to_string();
+    }
+
+    if raw.starts_with('"') {
+        if let Ok(value) = serde_json::from_str::<String>(raw) {
+            return value;
+        }
+    }
+    json5::from_str::<String>(raw).unwrap_or_else(|_| raw[1..raw.len() - 1].to_string())
+}
+
+fn tokenize(content: &str) -> Result<Vec<Token>> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b if b.is_ascii_whitespace() => i += 1,
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let Some(end) = content[i + 2..].find("*/") else {
+                    bail!("Unterminated block comment");
+                };
+                i += end + 4;
+            }
+            quote @ (b'"' | b'\'') => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                if i >= bytes.len() {
+                    bail!("Unterminated string");
+                }
+                i += 1;
+                tokens.push(Token {
+                    kind: TokenKind::String,
+                    start,
+                    end: i,
+                });
+            }
+            punct @ (b'{' | b'}' | b'[' | b']' | b':' | b',') => {
+                tokens.push(Token {
+                    kind: TokenKind::Punct(punct),
+                    start: i,
+                    end: i + 1,
+                });
+                i += 1;
+            }
+            _ => {
+                let start = i;
+                while i < bytes.len()
+                    && !bytes[i].is_ascii_whitespace()
+                    && !matches!(bytes[i], b'{' | b'}' | b'[' | b']' | b':' | b',' | b'/')
+                {
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Literal,
+                    start,
+                    end: i,
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn detect_indent_unit(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .filter(|leading| !leading.is_empty())
+        .min_by_key(|leading| leading.len())
+        .map(|leading| {
+            if leading.starts_with('\t') {
+                "\t".to_string()
+            } else {
+                leading.to_string()
+            }
+        })
+        .unwrap_or_else(|| "  ".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_nested_key_and_keeps_comments() {
+        let content = r#"{
+  // Shared buttons
+  "common": {
+    "save": "Save" // primary action
+  },
+  /* legacy */
+  "title": "Home"
+}
+"#;
+        let output = insert_key(content, "common.cancel", "Cancel").unwrap();
+        assert_eq!(
+            output,
+            r#"{
+  // Shared buttons
+  "common": {
+    "save": "Save", // primary action
+    "cancel": "Cancel"
+  },
+  /* legacy */
+  "title": "Home"
+}
+"#
+        );
+        assert!(json5::from_str::<serde_json::Value>(&output).is_ok());
+    }
+
+    #[test]
+    fn keeps_trailing_comma_style_and_creates_parents() {
+        let content = "{\n  title: 'Home',\n}\n";
+        let output = insert_key(content, "nav.about", "About \"us\"").unwrap();
+        assert_eq!(
+            output,
+            "{\n  title: 'Home',\n  \"nav\": {\n    \"about\": \"About \\\"us\\\"\"\n  },\n}\n"
+        );
+    }
+
+    #[test]
+    fn overwrites_existing_value_in_place() {
+        let content = "{\n  // greeting\n  \"hello\": \"Hi\", // keep\n}\n";
+        let output = insert_key(content, "hello", "Hello").unwrap();
+        assert_eq!(
+            output,
+            "{\n  // greeting\n  \"hello\": \"Hello\", // keep\n}\n"
+        );
+    }
+}
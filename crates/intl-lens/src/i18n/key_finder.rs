@@ -1,4 +1,14 @@
-use regex::Regex;
+use std::collections::HashSet;
+use std::path::{Component, Path};
+use std::sync::OnceLock;
+
+use globset::GlobSet;
+use regex::{Regex, RegexSet};
+use serde::{Deserialize, Serialize};
+
+use super::ast::{self, Callee, SourceLanguage};
+use super::namespace::Namespaces;
+use crate::walker::build_glob_set;
 
 #[derive(Debug, Clone)]
 pub struct FoundKey {
@@ -7,61 +17,477 @@ pub struct FoundKey {
     pub line: usize,
     pub start_char: usize,
     pub end_char: usize,
+    pub kind: KeyKind,
+    /// Whether the call passes an i18next `context` option, so `t("friend", { context })`
+    /// also resolves `friend_male`.
+    pub context: bool,
+}
+
+/// How a call site names its key.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyKind {
+    /// A literal key: `t("status.active")`.
+    #[default]
+    Static,
+    /// A key built from a literal prefix: `` t(`status.${s}`) `` or `t("status." + s)`. The
+    /// found key is the prefix.
+    Template,
+    /// A key computed at runtime: `t(labelKey)`. The found key is the expression.
+    Dynamic,
+}
+
+/// An entry of `functionPatterns`: a regex whose first group is the key. Written as a plain
+/// string it applies to every file; as `{"pattern", "languages", "files"}` it applies to files
+/// in those languages or matching those globs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawFunctionPattern", into = "RawFunctionPattern")]
+pub struct FunctionPattern {
+    pub pattern: String,
+    pub languages: Vec<SourceLanguage>,
+    pub files: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawFunctionPattern {
+    Pattern(String),
+    Scoped {
+        pattern: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        languages: Vec<SourceLanguage>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        files: Vec<String>,
+    },
+}
+
+impl FunctionPattern {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            languages: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    pub fn for_languages(pattern: impl Into<String>, languages: &[SourceLanguage]) -> Self {
+        Self {
+            languages: languages.to_vec(),
+            ..Self::new(pattern)
+        }
+    }
+
+    pub fn is_scoped(&self) -> bool {
+        !self.languages.is_empty() || !self.files.is_empty()
+    }
+
+    /// The calls this pattern matches, for the `ast` key extractor: `trans` and `trans_choice`
+    /// for `trans(?:_choice)?\s*\(\s*["']([^"']+)["']`. Only patterns for a call whose first
+    /// argument is the quoted key or a message descriptor `{ id: "key" }` yield callees.
+    pub fn callees(&self) -> Vec<Callee> {
+        let Some((head, arguments)) = self.pattern.split_once(r"\(") else {
+            return Vec::new();
+        };
+        let arguments = arguments.strip_prefix(r"\s*").unwrap_or(arguments);
+        let descriptor = arguments.starts_with(r"\{\s*id:");
+        if !descriptor
+            && !arguments
+                .trim_start_matches('[')
+                .starts_with(['"', '\'', '`'])
+        {
+            return Vec::new();
+        }
+
+        let head = head.strip_suffix(r"\s*").unwrap_or(head);
+        let head = head.strip_suffix(r"\b").unwrap_or(head);
+        // `(?:^|[^\w.])t` does not match member calls such as `api.t(`.
+        let (exact, head) = match head.strip_prefix("(?:^|") {
+            Some(rest) => match rest.split_once(')') {
+                Some((boundary, name)) => (boundary.contains('.'), name),
+                None => return Vec::new(),
+            },
+            None => (false, head.strip_prefix(r"\b").unwrap_or(head)),
+        };
+
+        callee_names(head)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| Callee {
+                name,
+                exact,
+                descriptor,
+            })
+            .collect()
+    }
+}
+
+impl From<RawFunctionPattern> for FunctionPattern {
+    fn from(raw: RawFunctionPattern) -> Self {
+        match raw {
+            RawFunctionPattern::Pattern(pattern) => Self::new(pattern),
+            RawFunctionPattern::Scoped {
+                pattern,
+                languages,
+                files,
+            } => Self {
+                pattern,
+                languages,
+                files,
+            },
+        }
+    }
+}
+
+impl From<FunctionPattern> for RawFunctionPattern {
+    fn from(pattern: FunctionPattern) -> Self {
+        if pattern.is_scoped() {
+            Self::Scoped {
+                pattern: pattern.pattern,
+                languages: pattern.languages,
+                files: pattern.files,
+            }
+        } else {
+            Self::Pattern(pattern.pattern)
+        }
+    }
+}
+
+impl From<&str> for FunctionPattern {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for FunctionPattern {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+/// The names a callee regex such as `i18n\.(?:global\.)?t` matches, or `None` when it uses
+/// more than literals, escaped punctuation and non-nested `(?:a|b)` groups.
+fn callee_names(regex: &str) -> Option<Vec<String>> {
+    let mut names = vec![String::new()];
+    let mut rest = regex;
+    while !rest.is_empty() {
+        let alternatives = if let Some(group) = rest.strip_prefix("(?:") {
+            let (inner, after) = group.split_once(')')?;
+            let mut alternatives = inner
+                .split('|')
+                .map(unescape_name)
+                .collect::<Option<Vec<_>>>()?;
+            rest = match after.strip_prefix('?') {
+                Some(after) => {
+                    alternatives.push(String::new());
+                    after
+                }
+                None => after,
+            };
+            alternatives
+        } else {
+            let len = if rest.starts_with('\\') { 2 } else { 1 };
+            let end = rest.char_indices().nth(len).map_or(rest.len(), |(i, _)| i);
+            let literal = unescape_name(&rest[..end])?;
+            rest = &rest[end..];
+            vec![literal]
+        };
+        names = names
+            .iter()
+            .flat_map(|name| alternatives.iter().map(move |alt| format!("{name}{alt}")))
+            .collect();
+    }
+    Some(names)
+}
+
+/// `i18n\.t` as `i18n.t`; `None` for regex syntax other than escaped `.` and `$`.
+fn unescape_name(regex: &str) -> Option<String> {
+    let mut name = String::new();
+    let mut chars = regex.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                c @ ('.' | '$') => name.push(c),
+                _ => return None,
+            },
+            c if c.is_alphanumeric() || matches!(c, '_' | ':') => name.push(c),
+            _ => return None,
+        }
+    }
+    Some(name)
+}
+
+/// The files a scoped [`FunctionPattern`] applies to.
+struct PatternScope {
+    languages: Vec<SourceLanguage>,
+    files: Option<GlobSet>,
+}
+
+impl PatternScope {
+    /// Files in a language without an id match every language-scoped pattern.
+    fn applies_to(&self, file: &Path, language: Option<SourceLanguage>) -> bool {
+        self.files
+            .as_ref()
+            .is_some_and(|files| files.is_match(file))
+            || match language {
+                Some(language) => self.languages.contains(&language),
+                None => !self.languages.is_empty(),
+            }
+    }
 }
 
 pub struct KeyFinder {
     patterns: Vec<Regex>,
+    /// Where each of `patterns` applies; `None` for every file.
+    scopes: Vec<Option<PatternScope>>,
+    /// The calls each of `patterns` matches, for the `ast` extractor.
+    callees: Vec<Vec<Callee>>,
+    /// All `patterns` in one automaton, so a file is searched once to learn which patterns
+    /// match before any captures are run. `None` when the set could not be built, for example
+    /// because it exceeds the size limit; every pattern is then run on its own.
+    pattern_set: Option<RegexSet>,
+    namespaces: Option<Namespaces>,
+    ast_languages: HashSet<SourceLanguage>,
 }
 
 impl KeyFinder {
-    pub fn new(patterns: &[String]) -> Self {
-        let compiled_patterns: Vec<Regex> =
-            patterns.iter().filter_map(|p| Regex::new(p).ok()).collect();
+    pub fn new(patterns: &[FunctionPattern]) -> Self {
+        let mut compiled_patterns = Vec::new();
+        let mut scopes = Vec::new();
+        let mut callees = Vec::new();
+        for p in patterns {
+            let Ok(regex) = Regex::new(&p.pattern) else {
+                continue;
+            };
+            compiled_patterns.push(regex);
+            scopes.push(p.is_scoped().then(|| PatternScope {
+                languages: p.languages.clone(),
+                files: build_glob_set(&p.files),
+            }));
+            callees.push(p.callees());
+        }
+        let pattern_set = RegexSet::new(compiled_patterns.iter().map(Regex::as_str))
+            .map_err(|e| {
+                tracing::warn!(
+                    "Failed to combine function patterns, running them one by one: {}",
+                    e
+                )
+            })
+            .ok();
 
         Self {
             patterns: compiled_patterns,
+            scopes,
+            callees,
+            pattern_set,
+            namespaces: None,
+            ast_languages: HashSet::new(),
         }
     }
 
+    /// Extract keys with the tree-sitter extractor instead of the regex patterns for files in
+    /// these languages. Only applies to [`Self::find_keys_in_file`] with a known file path.
+    pub fn with_ast_languages(mut self, languages: HashSet<SourceLanguage>) -> Self {
+        self.ast_languages = languages;
+        self
+    }
+
+    /// Qualify found keys with their i18next namespace: an explicit `ns:key`, the namespace of
+    /// the closest preceding `useTranslation("ns")`, or the default namespace.
+    pub fn with_namespaces(mut self, namespaces: Option<Namespaces>) -> Self {
+        self.namespaces = namespaces;
+        self
+    }
+
+    /// Find keys with every pattern, regardless of its scope.
     pub fn find_keys(&self, content: &str) -> Vec<FoundKey> {
+        self.find_keys_with(content, true, |_| true)
+    }
+
+    /// Find keys with the patterns whose scope covers `file`.
+    fn find_keys_for_file(&self, content: &str, file: &Path) -> Vec<FoundKey> {
+        let language = SourceLanguage::from_path(file);
+        let computed = language.is_some_and(|language| COMPUTED_KEY_LANGUAGES.contains(&language));
+        self.find_keys_with(content, computed, |index| {
+            self.scopes[index]
+                .as_ref()
+                .map_or(true, |scope| scope.applies_to(file, language))
+        })
+    }
+
+    /// Find keys with the patterns `applies` selects, and template and dynamic keys when
+    /// `computed` is set.
+    fn find_keys_with(
+        &self,
+        content: &str,
+        computed: bool,
+        applies: impl Fn(usize) -> bool,
+    ) -> Vec<FoundKey> {
         let mut found_keys = Vec::new();
 
-        for pattern in &self.patterns {
-            for cap in pattern.captures_iter(content) {
+        let matching: Vec<usize> = match &self.pattern_set {
+            Some(pattern_set) => pattern_set.matches(content).into_iter().collect(),
+            None => (0..self.patterns.len()).collect(),
+        };
+        for index in matching.into_iter().filter(|index| applies(*index)) {
+            for cap in self.patterns[index].captures_iter(content) {
                 if let Some(key_match) = cap.get(1) {
-                    let key = key_match.as_str().to_string();
-                    let start_offset = key_match.start();
-                    let end_offset = key_match.end();
-
-                    let (line, start_char, end_char) =
-                        Self::offset_to_position(content, start_offset, end_offset);
-
-                    found_keys.push(FoundKey {
-                        key,
-                        start_offset,
-                        line,
-                        start_char,
-                        end_char,
-                    });
+                    found_keys.push(Self::found_key(
+                        content,
+                        key_match.as_str().to_string(),
+                        key_match.start(),
+                        key_match.end(),
+                        KeyKind::Static,
+                    ));
                 }
             }
         }
+        if computed {
+            found_keys.extend(find_computed_keys(content).into_iter().map(|found| {
+                Self::found_key(
+                    content,
+                    found.key,
+                    found.range.start,
+                    found.range.end,
+                    found.kind,
+                )
+            }));
+        }
 
-        found_keys.sort_by_key(|k| k.start_offset);
+        // A pattern matching the prefix of `t("status." + s)` yields a static key at the same
+        // offset as the template key; keep the template key.
+        found_keys.sort_by_key(|k| (k.start_offset, k.kind == KeyKind::Static));
         found_keys.dedup_by(|a, b| a.start_offset == b.start_offset);
         found_keys
     }
 
+    /// Like [`Self::find_keys`], but only uses the patterns scoped to the language of `file`
+    /// and resolves Rails lazy lookups (`t(".title")`) against its view path.
+    pub fn find_keys_in_file(&self, content: &str, file: Option<&Path>) -> Vec<FoundKey> {
+        let mut keys = self
+            .find_keys_with_ast(content, file)
+            .unwrap_or_else(|| match file {
+                Some(file) => self.find_keys_for_file(content, file),
+                None => self.find_keys(content),
+            });
+        if let Some(file) = file {
+            for found in &mut keys {
+                if let Some(resolved) = resolve_lazy_key(&found.key, file) {
+                    found.key = resolved;
+                }
+            }
+        }
+
+        let mut scopes = find_translator_scopes(content);
+        if !scopes.is_empty() {
+            limit_scopes_to_functions(&mut scopes, content, file);
+        }
+        for found in keys
+            .iter_mut()
+            .filter(|found| found.kind != KeyKind::Dynamic)
+        {
+            let scope = scopes
+                .iter()
+                .rev()
+                .find(|scope| scope.offset < found.start_offset && found.start_offset < scope.end);
+            let namespaced = self
+                .namespaces
+                .as_ref()
+                .is_some_and(|namespaces| namespaces.explicit_namespace(&found.key).is_some());
+
+            if let Some(prefix) = scope.and_then(|scope| scope.key_prefix.as_deref()) {
+                if !namespaced {
+                    found.key = format!("{}.{}", prefix, found.key);
+                }
+            }
+            if let Some(namespaces) = &self.namespaces {
+                let namespace = scope.and_then(|scope| scope.namespace.as_deref());
+                found.key = namespaces.resolve(&found.key, namespace);
+            }
+        }
+
+        keys
+    }
+
+    fn find_keys_with_ast(&self, content: &str, file: Option<&Path>) -> Option<Vec<FoundKey>> {
+        let file = file?;
+        let language = SourceLanguage::from_path(file)
+            .filter(|language| self.ast_languages.contains(language))?;
+        let callees: Vec<Callee> = self
+            .callees
+            .iter()
+            .zip(&self.scopes)
+            .filter(|(_, scope)| {
+                scope
+                    .as_ref()
+                    .map_or(true, |scope| scope.applies_to(file, Some(language)))
+            })
+            .flat_map(|(callees, _)| callees.iter().cloned())
+            .collect();
+        let keys = ast::extract_keys(content, language, &callees)?;
+
+        Some(
+            keys.into_iter()
+                .map(|found| {
+                    Self::found_key(
+                        content,
+                        found.key,
+                        found.range.start,
+                        found.range.end,
+                        found.kind,
+                    )
+                })
+                .collect(),
+        )
+    }
+
     pub fn find_key_at_position(
         &self,
         content: &str,
         line: usize,
         character: usize,
     ) -> Option<FoundKey> {
-        let keys = self.find_keys(content);
+        self.find_key_at_position_in_file(content, None, line, character)
+    }
+
+    pub fn find_key_at_position_in_file(
+        &self,
+        content: &str,
+        file: Option<&Path>,
+        line: usize,
+        character: usize,
+    ) -> Option<FoundKey> {
+        let keys = self.find_keys_in_file(content, file);
 
-        keys.into_iter()
-            .find(|k| k.line == line && character >= k.start_char && character <= k.end_char)
+        keys.into_iter().find(|k| {
+            k.kind == KeyKind::Static
+                && k.line == line
+                && character >= k.start_char
+                && character <= k.end_char
+        })
+    }
+
+    fn found_key(
+        content: &str,
+        key: String,
+        start_offset: usize,
+        end_offset: usize,
+        kind: KeyKind,
+    ) -> FoundKey {
+        let (line, start_char, end_char) =
+            Self::offset_to_position(content, start_offset, end_offset);
+
+        FoundKey {
+            key,
+            start_offset,
+            line,
+            start_char,
+            end_char,
+            kind,
+            context: passes_context(content, end_offset),
+        }
     }
 
     fn offset_to_position(
@@ -89,45 +515,506 @@ impl KeyFinder {
     }
 }
 
+/// A translator created by `useTranslation`, `useTranslations`, `getFixedT`, … that applies to
+/// the `t()` calls after it, up to the end of the enclosing function.
+#[derive(Debug, Clone, PartialEq)]
+struct TranslatorScope {
+    offset: usize,
+    /// The end of the function the translator is created in, or of the file.
+    end: usize,
+    namespace: Option<String>,
+    key_prefix: Option<String>,
+}
+
+/// Find translator scopes:
+/// - `useTranslation("ns", { keyPrefix: "checkout" })` and `withTranslation(["ns", …])`
+/// - `getFixedT(lng, "ns", "checkout")`
+/// - next-intl's `useTranslations("checkout")` / `getTranslations({ namespace: "checkout" })`,
+///   whose namespace is a key prefix
+/// - vue-i18n's `useI18n({ useScope: "checkout" })`, whose scope is a key prefix unless it is
+///   `"local"` or `"global"`
+///
+/// A call without arguments, such as `useTranslation()`, resets the scope.
+fn find_translator_scopes(content: &str) -> Vec<TranslatorScope> {
+    static CALL_REGEX: OnceLock<Regex> = OnceLock::new();
+    let call_regex = CALL_REGEX.get_or_init(|| {
+        Regex::new(
+            r"\b(useTranslation|withTranslation|useTranslations|getTranslations|getFixedT|useI18n)\s*\(",
+        )
+        .expect("translator scope regex")
+    });
+
+    call_regex
+        .captures_iter(content)
+        .filter_map(|cap| {
+            let whole = cap.get(0)?;
+            let args = call_arguments(&content[whole.end()..])?;
+            let arg = |index: usize| args.get(index).copied().unwrap_or("");
+
+            let (namespace, key_prefix) = match cap.get(1)?.as_str() {
+                "useTranslations" | "getTranslations" => (
+                    None,
+                    string_literal(arg(0)).or_else(|| object_string_property(arg(0), "namespace")),
+                ),
+                "getFixedT" => (namespace_argument(arg(1)), string_literal(arg(2))),
+                "useI18n" => (
+                    None,
+                    object_string_property(arg(0), "useScope")
+                        .filter(|scope| scope != "local" && scope != "global"),
+                ),
+                _ => (
+                    namespace_argument(arg(0)),
+                    object_string_property(arg(1), "keyPrefix"),
+                ),
+            };
+
+            Some(TranslatorScope {
+                offset: whole.start(),
+                end: content.len(),
+                namespace,
+                key_prefix,
+            })
+        })
+        .collect()
+}
+
+/// Languages whose `t(…)`, `__(…)` and `trans(…)` calls [`find_computed_keys`] understands. In
+/// others, such as Dart or Rust, the same names are ordinary functions.
+const COMPUTED_KEY_LANGUAGES: &[SourceLanguage] = &[
+    SourceLanguage::JavaScript,
+    SourceLanguage::TypeScript,
+    SourceLanguage::Tsx,
+    SourceLanguage::Vue,
+    SourceLanguage::Svelte,
+    SourceLanguage::Html,
+    SourceLanguage::Astro,
+    SourceLanguage::Mdx,
+    SourceLanguage::Php,
+    SourceLanguage::Blade,
+];
+
+/// End each scope with the innermost function it is created in, so a translator of one component
+/// does not apply to the next. Scopes keep running to the end of the file when the language has
+/// no bundled grammar.
+fn limit_scopes_to_functions(scopes: &mut [TranslatorScope], content: &str, file: Option<&Path>) {
+    let Some(functions) = file
+        .and_then(SourceLanguage::from_path)
+        .and_then(|language| ast::function_ranges(content, language))
+    else {
+        return;
+    };
+
+    for scope in scopes {
+        if let Some(function) = functions
+            .iter()
+            .filter(|function| function.contains(&scope.offset))
+            .min_by_key(|function| function.len())
+        {
+            scope.end = function.end;
+        }
+    }
+}
+
+/// Find translation calls whose key is not a plain string literal: template literals, string
+/// concatenations and other expressions. Template literals without substitutions are static.
+/// Declarations such as `function t(key)` or `t(key: string)` are skipped.
+fn find_computed_keys(content: &str) -> Vec<ast::ExtractedKey> {
+    static CALL_REGEX: OnceLock<Regex> = OnceLock::new();
+    let call_regex = CALL_REGEX.get_or_init(|| {
+        Regex::new(
+            r"(?:^|[^\w.$'])(?:this\.)?(?:t|\$t|\$tc|\$te|\$_|\$format|i18n\.t|i18next\.t|__|trans|trans_choice|Lang::get|Lang::choice)\(",
+        )
+        .expect("translation call regex")
+    });
+
+    call_regex
+        .find_iter(content)
+        .filter(|call| {
+            let before = content[..call.start()].trim_end();
+            !(before.ends_with("function") || before.ends_with("fn"))
+        })
+        .filter_map(|call| {
+            let args = call_arguments(&content[call.end()..])?;
+            let arg = *args.first()?;
+            if is_typed_parameter(arg) {
+                return None;
+            }
+            let offset = arg.as_ptr() as usize - content.as_ptr() as usize;
+            let (kind, range) = classify_key_argument(arg)?;
+            let key = match kind {
+                KeyKind::Dynamic => arg.split_whitespace().collect::<Vec<_>>().join(" "),
+                _ => arg[range.clone()].to_string(),
+            };
+            Some(ast::ExtractedKey {
+                key,
+                range: range.start + offset..range.end + offset,
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Whether a call argument is a typed parameter of a declaration rather than an expression:
+/// `key: string` in TypeScript or `string $key` in PHP.
+fn is_typed_parameter(arg: &str) -> bool {
+    static PARAMETER_REGEX: OnceLock<Regex> = OnceLock::new();
+    let parameter_regex = PARAMETER_REGEX.get_or_init(|| {
+        Regex::new(r"^(?:[A-Za-z_$][\w$]*\??\s*:\s*[A-Za-z_{\[(]|\??[A-Za-z_\\][\w\\|]*\s+&?\$\w+)")
+            .expect("typed parameter regex")
+    });
+    parameter_regex.is_match(arg)
+}
+
+/// Classify a key argument and return the range of its key text within `arg`: the inside of a
+/// template literal without substitutions, the literal prefix of a template key, or the first
+/// line of a dynamic expression. Plain string literals are left to the regex patterns.
+fn classify_key_argument(arg: &str) -> Option<(KeyKind, std::ops::Range<usize>)> {
+    let first = arg.chars().next()?;
+    let dynamic = || {
+        let first_line = arg.lines().next().unwrap_or_default().trim_end();
+        Some((KeyKind::Dynamic, 0..first_line.len()))
+    };
+
+    if first == '`' {
+        let inner = arg.strip_prefix('`')?.strip_suffix('`')?;
+        return match inner.find("${") {
+            Some(0) => dynamic(),
+            Some(end) => Some((KeyKind::Template, 1..1 + end)),
+            None if inner.is_empty() => None,
+            None => Some((KeyKind::Static, 1..1 + inner.len())),
+        };
+    }
+
+    if first == '"' || first == '\'' {
+        let end = literal_end(arg)?;
+        let rest = arg[end..].trim_start();
+        let operator = rest.strip_prefix(['+', '.'])?;
+        // `"checkout." + "title"` is static, and not picked up by the patterns either.
+        let mut remainder = operator.trim_start();
+        while remainder.starts_with(['"', '\'']) {
+            let end = literal_end(remainder)?;
+            remainder = remainder[end..].trim_start();
+            match remainder.strip_prefix(['+', '.']) {
+                Some(next) => remainder = next.trim_start(),
+                None if remainder.is_empty() => return None,
+                None => break,
+            }
+        }
+        return (end > 2).then_some((KeyKind::Template, 1..end - 1));
+    }
+
+    dynamic()
+}
+
+/// The byte length of the string literal at the start of `source`, including both quotes.
+fn literal_end(source: &str) -> Option<usize> {
+    let quote = source.chars().next()?;
+    let mut escaped = false;
+    for (index, ch) in source.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else if ch == quote {
+            return Some(index + 1);
+        }
+    }
+    None
+}
+
+/// Split the arguments of a call at top-level commas; `source` starts right after `(`.
+fn call_arguments(source: &str) -> Option<Vec<&str>> {
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (index, ch) in source.char_indices() {
+        if let Some(open) = quote {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == open {
+                quote = None;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' | '\'' | '`' => quote = Some(ch),
+            '(' | '[' | '{' => depth += 1,
+            ')' if depth == 0 => {
+                let last = source[start..index].trim();
+                if !last.is_empty() || !args.is_empty() {
+                    args.push(last);
+                }
+                return Some(args);
+            }
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                args.push(source[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Whether the call whose key ends at `key_end` passes an object with a `context` property as
+/// a later argument: `t("friend", { context: gender })` or `t("friend", { count, context })`.
+fn passes_context(content: &str, key_end: usize) -> bool {
+    static CONTEXT_REGEX: OnceLock<Regex> = OnceLock::new();
+    let context_regex = CONTEXT_REGEX
+        .get_or_init(|| Regex::new(r"[{,]\s*context\s*[:,}]").expect("context option regex"));
+
+    let Some(rest) = content.get(key_end..) else {
+        return false;
+    };
+    let rest = rest.strip_prefix(['"', '\'', '`']).unwrap_or(rest);
+    let Some(rest) = rest.trim_start().strip_prefix(',') else {
+        return false;
+    };
+    call_arguments(rest).is_some_and(|args| {
+        args.iter()
+            .any(|arg| arg.starts_with('{') && context_regex.is_match(arg))
+    })
+}
+
+fn string_literal(arg: &str) -> Option<String> {
+    let arg = arg.trim();
+    let quote = arg
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let inner = arg.strip_prefix(quote)?.strip_suffix(quote)?;
+    (!inner.is_empty() && !inner.contains(quote) && !inner.contains("${"))
+        .then(|| inner.to_string())
+}
+
+/// `"ns"` or the first entry of `["ns", "other"]`.
+fn namespace_argument(arg: &str) -> Option<String> {
+    match arg.trim().strip_prefix('[') {
+        Some(rest) => string_literal(rest.split([',', ']']).next()?),
+        None => string_literal(arg),
+    }
+}
+
+fn object_string_property(arg: &str, name: &str) -> Option<String> {
+    let regex = Regex::new(&format!(r#"\b{}\s*:\s*(["'`][^"'`]*["'`])"#, name)).ok()?;
+    string_literal(regex.captures(arg)?.get(1)?.as_str())
+}
+
+/// Resolve a Rails lazy lookup such as `t(".title")` used in
+/// `app/views/users/_form.html.erb` to `users.form.title`.
+pub fn resolve_lazy_key(key: &str, file: &Path) -> Option<String> {
+    let suffix = key.strip_prefix('.')?;
+    if suffix.is_empty() {
+        return None;
+    }
+
+    let components: Vec<&str> = file
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect();
+    let views = components
+        .windows(2)
+        .rposition(|pair| pair == ["app", "views"])?;
+    let (file_name, dirs) = components[views + 2..].split_last()?;
+
+    let template = file_name.split('.').next()?;
+    let template = template.strip_prefix('_').unwrap_or(template);
+    if template.is_empty() {
+        return None;
+    }
+
+    let mut segments: Vec<&str> = dirs.to_vec();
+    segments.push(template);
+    segments.push(suffix);
+    Some(segments.join("."))
+}
+
 impl Default for KeyFinder {
     fn default() -> Self {
         Self::new(&default_patterns())
     }
 }
 
-fn default_patterns() -> Vec<String> {
+fn default_patterns() -> Vec<FunctionPattern> {
     vec![
         // JavaScript/TypeScript patterns
         // Match t() but not .post(), .get(), .put(), .delete(), etc.
-        r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"i18n\.t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$tc\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$te\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"useI18n\s*\(\s*\)\s*.*?\.t\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"formatMessage\s*\(\s*\{\s*id:\s*["']([^"']+)["']"#.to_string(),
-        r#"<Trans\s+i18nKey\s*=\s*["']([^"']+)["']"#.to_string(),
+        scoped(
+            r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB_AND_RUBY,
+        ),
+        scoped(
+            r#"\bi18n(?:ext|\.global)?\.t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(r#"\$t\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(r#"\$tc\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(r#"\$te\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(
+            r#"useI18n\s*\(\s*\)\s*.*?\.t\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"formatMessage\s*\(\s*\{\s*id:\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"<Trans\s+i18nKey\s*=\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
         // Svelte patterns (svelte-i18n)
-        r#"\$_\s*\(\s*["']([^"']+)["']"#.to_string(),
-        r#"\$format\s*\(\s*["']([^"']+)["']"#.to_string(),
+        scoped(r#"\$_\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        scoped(r#"\$format\s*\(\s*["']([^"']+)["']"#, SourceLanguage::WEB),
+        // Angular patterns
+        scoped(
+            r#"translateService\.(?:instant|get|stream)\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"translocoService\.(?:translate|selectTranslate)\s*\(\s*["']([^"']+)["']"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"["']([^"']+)["']\s*\|\s*(?:translate|transloco)\b"#,
+            SourceLanguage::WEB,
+        ),
+        // HTML template patterns (jquery-i18next, Angular i18n ids)
+        scoped(
+            r#"\bdata-i18n\s*=\s*["'](?:\[[\w-]+\])?([^"';\[\]]+)"#,
+            SourceLanguage::WEB,
+        ),
+        scoped(
+            r#"\bi18n(?:-[\w-]+)?\s*=\s*"[^"]*?@@([\w.-]+)""#,
+            SourceLanguage::WEB,
+        ),
+        // Python/Jinja and Elixir gettext
+        scoped(
+            r#"(?:^|[^\w.$])_\(\s*["']([^"']+)["']"#,
+            SourceLanguage::GETTEXT,
+        ),
+        scoped(
+            r#"(?:^|\W)u?n?gettext(?:_lazy|_noop)?(?:\s*\(\s*|\s+)["']([^"']+)["']"#,
+            SourceLanguage::GETTEXT,
+        ),
+        scoped(
+            r#"(?:^|\W)(?:dn?|n?p)gettext(?:_lazy)?(?:\s*\(\s*|\s+)["'][^"']*["']\s*,\s*["']([^"']+)["']"#,
+            SourceLanguage::GETTEXT,
+        ),
+        // Ruby/Rails patterns
+        scoped(
+            r#"I18n\.(?:t|translate)\b(?:\s*\(\s*|\s+)["']([^"']+)["']"#,
+            SourceLanguage::RUBY,
+        ),
+        scoped(
+            r#"I18n\.(?:t|translate)\b(?:\s*\(\s*|\s+):([A-Za-z_][\w.]*)"#,
+            SourceLanguage::RUBY,
+        ),
+        scoped(
+            r#"(?m)(?:<%=?|^[ \t]*[=-])\s*t\s+["']([^"']+)["']"#,
+            SourceLanguage::RUBY,
+        ),
+        // Go patterns (go-i18n)
+        scoped(
+            r#"LocalizeConfig\s*\{[^}]*?\bMessageID:\s*"([^"]+)""#,
+            &[SourceLanguage::Go],
+        ),
+        scoped(
+            r#"i18n\.Message\s*\{[^}]*?\bID:\s*"([^"]+)""#,
+            &[SourceLanguage::Go],
+        ),
+        // Kotlin/Java patterns (Android and Compose resources)
+        scoped(
+            r#"\bR\.(?:string|plurals)\.(\w+)"#,
+            &[SourceLanguage::Kotlin, SourceLanguage::Java],
+        ),
+        scoped(
+            r#"\bRes\.(?:string|plurals)\.(\w+)"#,
+            &[SourceLanguage::Kotlin, SourceLanguage::Java],
+        ),
+        // Swift patterns
+        scoped(
+            r#"NSLocalizedString\s*\(\s*"([^"]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        scoped(
+            r#"String\s*\(\s*localized:\s*"([^"]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        scoped(
+            r#"LocalizedStringKey\s*\(\s*"([^"]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        // SwiftUI views take a `LocalizedStringKey` title; `Text("…")` in Dart or JSX is a plain string
+        scoped(
+            r#"(?:\b(?:Text|Button|Label|Toggle|Link|Picker|Section)|\.navigationTitle)\s*\(\s*"([^"\\\n]+)""#,
+            &[SourceLanguage::Swift],
+        ),
+        // Rust patterns (rust-i18n, i18n-embed-fl)
+        scoped(
+            r#"(?:^|[^\w.])t!\s*\(\s*"([^"]+)""#,
+            &[SourceLanguage::Rust],
+        ),
+        scoped(
+            r#"(?:^|[^\w.])fl!\s*\(\s*[^,()]+,\s*"([^"]+)""#,
+            &[SourceLanguage::Rust],
+        ),
         // Flutter/Dart patterns - easy_localization
-        r#"['"]([^'"]+)['"]\s*\.tr\("#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.tr\(\)"#.to_string(),
-        r#"(?:^|[^\w.])tr\(\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"context\.tr\(\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.plural\("#.to_string(),
+        scoped(r#"['"]([^'"]+)['"]\s*\.tr\("#, &[SourceLanguage::Dart]),
+        scoped(r#"['"]([^'"]+)['"]\s*\.tr\(\)"#, &[SourceLanguage::Dart]),
+        scoped(
+            r#"(?:^|[^\w.])tr\(\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"context\.tr\(\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(r#"['"]([^'"]+)['"]\s*\.plural\("#, &[SourceLanguage::Dart]),
         // Flutter/Dart patterns - flutter_i18n
-        r#"FlutterI18n\.translate\([^,]+,\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"FlutterI18n\.plural\([^,]+,\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"I18nText\(\s*['"]([^'"]+)['"]"#.to_string(),
-        r#"I18nPlural\(\s*['"]([^'"]+)['"]"#.to_string(),
+        scoped(
+            r#"FlutterI18n\.translate\([^,]+,\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"FlutterI18n\.plural\([^,]+,\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(r#"I18nText\(\s*['"]([^'"]+)['"]"#, &[SourceLanguage::Dart]),
+        scoped(
+            r#"I18nPlural\(\s*['"]([^'"]+)['"]"#,
+            &[SourceLanguage::Dart],
+        ),
         // Flutter/Dart patterns - GetX
-        r#"['"]([^'"]+)['"]\s*\.tr(?:\s|$|\)|,)"#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.trParams\("#.to_string(),
-        r#"['"]([^'"]+)['"]\s*\.trPlural\("#.to_string(),
+        scoped(
+            r#"['"]([^'"]+)['"]\s*\.tr(?:\s|$|\)|,)"#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"['"]([^'"]+)['"]\s*\.trParams\("#,
+            &[SourceLanguage::Dart],
+        ),
+        scoped(
+            r#"['"]([^'"]+)['"]\s*\.trPlural\("#,
+            &[SourceLanguage::Dart],
+        ),
     ]
 }
 
+fn scoped(pattern: &str, languages: &[SourceLanguage]) -> FunctionPattern {
+    FunctionPattern::for_languages(pattern, languages)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,4 +1267,506 @@ mod tests {
         assert_eq!(keys.len(), 1);
         assert_eq!(keys[0].key, "welcome.message");
     }
+
+    #[test]
+    fn test_find_python_and_jinja_gettext() {
+        let finder = KeyFinder::default();
+        let content = r#"
+title = _("Welcome back")
+label = gettext_lazy('Sign out')
+count = ngettext("One file", "%(n)s files", n)
+menu = pgettext("menu", "Open")
+<h1>{{ _('Dashboard') }}</h1>
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec!["Welcome back", "Sign out", "One file", "Open", "Dashboard"]
+        );
+    }
+
+    #[test]
+    fn test_find_elixir_gettext() {
+        let finder = KeyFinder::default();
+        let content = r#"
+gettext("Hello")
+dgettext("errors", "is invalid")
+ngettext "One message", "%{count} messages", count
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(keys, vec!["Hello", "is invalid", "One message"]);
+    }
+
+    #[test]
+    fn test_find_rails_i18n_calls() {
+        let finder = KeyFinder::default();
+        let content = r#"
+flash[:notice] = I18n.t("users.created")
+subject = I18n.translate :welcome_subject
+<%= t "shared.footer" %>
+= t ".title"
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "users.created",
+                "welcome_subject",
+                "shared.footer",
+                ".title"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_go_i18n_message_ids() {
+        let finder = KeyFinder::default();
+        let content = r#"
+msg := localizer.MustLocalize(&i18n.LocalizeConfig{
+    MessageID:    "PersonCats",
+    TemplateData: map[string]interface{}{"Name": name},
+})
+other := &i18n.Message{ID: "Emails", Other: "{{.Count}} emails"}
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(keys, vec!["PersonCats", "Emails"]);
+    }
+
+    #[test]
+    fn test_find_android_string_resources() {
+        let finder = KeyFinder::default();
+        let content = r#"
+val title = context.getString(R.string.app_title)
+Text(text = stringResource(R.string.welcome_message))
+Text(pluralStringResource(Res.plurals.item_count, count, count))
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(keys, vec!["app_title", "welcome_message", "item_count"]);
+    }
+
+    #[test]
+    fn test_find_swift_localized_strings_and_swiftui_views() {
+        let finder = KeyFinder::default();
+        let content = r#"
+let title = NSLocalizedString("settings.title", comment: "")
+let body = String(localized: "settings.body")
+Text("settings.header")
+Button("settings.save") { save() }
+Text("Hello \(name)")
+"#;
+        let swift = Path::new("/app/Sources/SettingsView.swift");
+        let keys: Vec<String> = finder
+            .find_keys_in_file(content, Some(swift))
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "settings.title",
+                "settings.body",
+                "settings.header",
+                "settings.save"
+            ]
+        );
+
+        let dart = Path::new("/app/lib/home.dart");
+        assert!(finder
+            .find_keys_in_file(r#"Text("Hello")"#, Some(dart))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_find_rust_i18n_macros() {
+        let finder = KeyFinder::default();
+        let content = r#"
+let greeting = t!("hello.world", name = "Ferris");
+let label = fl!(LANGUAGE_LOADER, "settings-title");
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(keys, vec!["hello.world", "settings-title"]);
+    }
+
+    #[test]
+    fn test_find_html_and_angular_template_keys() {
+        let finder = KeyFinder::default();
+        let content = r#"
+<h1 data-i18n="home.title"></h1>
+<img data-i18n="[alt]home.logo" src="logo.png">
+<p i18n="site header|Welcome message@@home.welcome">Welcome</p>
+<button i18n-title="@@home.save.tooltip" title="Save">{{ 'home.save' | translate }}</button>
+<p i18n>No id</p>
+"#;
+        let keys: Vec<String> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|k| k.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "home.title",
+                "home.logo",
+                "home.welcome",
+                "home.save.tooltip",
+                "home.save"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scopes_default_patterns_by_file_language() {
+        let finder = KeyFinder::new(&crate::config::I18nConfig::default().function_patterns);
+        let keys_in = |content: &str, file: &str| -> Vec<String> {
+            finder
+                .find_keys_in_file(content, Some(Path::new(file)))
+                .into_iter()
+                .map(|k| k.key)
+                .collect()
+        };
+
+        let js = r#"const label = "Save".tr(); const text = __("raw.text"); t("app.title");"#;
+        assert_eq!(keys_in(js, "/app/src/App.ts"), vec!["app.title"]);
+
+        let dart = r#"Text('home.title'.tr()); t('not.a.key'); tr('home.body');"#;
+        assert_eq!(
+            keys_in(dart, "/app/lib/home.dart"),
+            vec!["home.title", "home.body"]
+        );
+
+        let blade = r#"<h1>{{ __('messages.welcome') }}</h1> @lang('auth.failed')"#;
+        assert_eq!(
+            keys_in(blade, "/app/resources/views/home.blade.php"),
+            vec!["messages.welcome", "auth.failed"]
+        );
+    }
+
+    #[test]
+    fn test_scoped_function_patterns_from_config() {
+        let patterns: Vec<FunctionPattern> = serde_json::from_str(
+            r#"[
+                "translate\\('([^']+)'\\)",
+                {"pattern": "tr\\('([^']+)'\\)", "languages": ["dart"]},
+                {"pattern": "lang\\('([^']+)'\\)", "files": ["**/*.blade.php"]}
+            ]"#,
+        )
+        .expect("parse patterns");
+        assert!(!patterns[0].is_scoped());
+        assert_eq!(patterns[1].languages, vec![SourceLanguage::Dart]);
+        assert_eq!(patterns[2].files, vec!["**/*.blade.php"]);
+
+        let finder = KeyFinder::new(&patterns);
+        let content = "translate('a') tr('b') lang('c')";
+        let keys_in = |file: &str| -> Vec<String> {
+            finder
+                .find_keys_in_file(content, Some(Path::new(file)))
+                .into_iter()
+                .map(|k| k.key)
+                .collect()
+        };
+        assert_eq!(keys_in("/app/src/main.ts"), vec!["a"]);
+        assert_eq!(keys_in("/app/lib/main.dart"), vec!["a", "b"]);
+        assert_eq!(
+            keys_in("/app/resources/views/home.blade.php"),
+            vec!["a", "c"]
+        );
+        assert_eq!(finder.find_keys(content).len(), 3);
+    }
+
+    #[test]
+    fn test_resolves_rails_lazy_lookups_in_views() {
+        let finder = KeyFinder::default();
+        let content = r#"<h1><%= t(".title") %></h1> <p><%= t("shared.footer") %></p>"#;
+        let view = Path::new("/app/root/app/views/admin/users/_form.html.erb");
+        let keys = finder.find_keys_in_file(content, Some(view));
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].key, "admin.users.form.title");
+        assert_eq!(keys[1].key, "shared.footer");
+
+        let outside_views = Path::new("/app/root/lib/report.rb");
+        assert_eq!(resolve_lazy_key(".title", outside_views), None);
+    }
+
+    #[test]
+    fn test_qualifies_keys_with_namespace_scopes() {
+        let finder =
+            KeyFinder::default().with_namespaces(Some(Namespaces::new("translation", ":")));
+        let content = r#"t("intro");
+const { t } = useTranslation(["home", "common"]);
+t("title"); t("common:save");"#;
+        let keys: Vec<String> = finder
+            .find_keys_in_file(content, None)
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(keys, vec!["translation:intro", "home:title", "common:save"]);
+    }
+
+    #[test]
+    fn test_classifies_template_and_dynamic_keys() {
+        let finder = KeyFinder::default();
+        let content = r#"
+t(`status.${order.status}`);
+t("status." + order.status);
+t(`plain.key`);
+$t(labelKey);
+t("checkout.title");
+function t(key) {}
+"#;
+        let keys: Vec<(KeyKind, String)> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|found| (found.kind, found.key))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                (KeyKind::Template, "status.".to_string()),
+                (KeyKind::Template, "status.".to_string()),
+                (KeyKind::Static, "plain.key".to_string()),
+                (KeyKind::Dynamic, "labelKey".to_string()),
+                (KeyKind::Static, "checkout.title".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runs_patterns_one_by_one_when_the_set_is_too_large() {
+        // Each of these compiles, but together they exceed the regex size limit.
+        let mut patterns = vec![FunctionPattern::new(
+            r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#,
+        )];
+        patterns.extend(
+            (0..4).map(|i| FunctionPattern::new(format!(r#"x{i}\(\s*["'](\w{{100}})["']"#))),
+        );
+        let finder = KeyFinder::new(&patterns);
+
+        assert!(finder.pattern_set.is_none());
+        let keys: Vec<String> = finder
+            .find_keys(r#"const title = t("home.title");"#)
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(keys, vec!["home.title"]);
+    }
+
+    #[test]
+    fn test_derives_callees_from_patterns() {
+        let names = |pattern: &str| -> Vec<(String, bool, bool)> {
+            FunctionPattern::new(pattern)
+                .callees()
+                .into_iter()
+                .map(|callee| (callee.name, callee.exact, callee.descriptor))
+                .collect()
+        };
+
+        assert_eq!(
+            names(r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#),
+            vec![("t".to_string(), true, false)]
+        );
+        assert_eq!(
+            names(r#"trans(?:_choice)?\s*\(\s*["']([^"']+)["']"#),
+            vec![
+                ("trans_choice".to_string(), false, false),
+                ("trans".to_string(), false, false)
+            ]
+        );
+        assert_eq!(
+            names(r#"\bi18n(?:ext|\.global)?\.t\s*\(\s*["']([^"']+)["']"#)
+                .into_iter()
+                .map(|(name, ..)| name)
+                .collect::<Vec<_>>(),
+            vec!["i18next.t", "i18n.global.t", "i18n.t"]
+        );
+        assert_eq!(
+            names(r#"formatMessage\s*\(\s*\{\s*id:\s*["']([^"']+)["']"#),
+            vec![("formatMessage".to_string(), false, true)]
+        );
+        // The key is not the first argument, or the callee is not a plain name.
+        assert!(names(r#"useI18n\s*\(\s*\)\s*.*?\.t\s*\(\s*["']([^"']+)["']"#).is_empty());
+        assert!(names(r#"["']([^"']+)["']\s*\.tr\("#).is_empty());
+        assert!(names(r#"\w+\.t\s*\(\s*["']([^"']+)["']"#).is_empty());
+    }
+
+    #[test]
+    fn test_detects_i18next_context_option() {
+        let finder = KeyFinder::default();
+        let content = r#"
+t("friend", { context: gender });
+t("friend", { count, context });
+t("save");
+t("item", { count: 2, defaultValue: "context" });
+"#;
+        let keys: Vec<(String, bool)> = finder
+            .find_keys(content)
+            .into_iter()
+            .map(|found| (found.key, found.context))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("friend".to_string(), true),
+                ("friend".to_string(), true),
+                ("save".to_string(), false),
+                ("item".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_finds_computed_keys_only_in_web_and_php_sources() {
+        let finder = KeyFinder::default();
+        let computed = |content: &str, file: &str| -> Vec<String> {
+            finder
+                .find_keys_in_file(content, Some(Path::new(file)))
+                .into_iter()
+                .filter(|found| found.kind != KeyKind::Static)
+                .map(|found| found.key)
+                .collect()
+        };
+
+        let dart = "String t(String key) => key;\nfinal label = t(label);\n";
+        assert!(computed(dart, "lib/main.dart").is_empty());
+        let rust = "fn t(x: u32) -> u32 { x }\nlet n = t(count);\n";
+        assert!(computed(rust, "src/main.rs").is_empty());
+
+        let ts = "class I18n {\n  t(key: string): string { return key; }\n}\nt(labelKey);\n";
+        assert_eq!(computed(ts, "src/i18n.ts"), vec!["labelKey"]);
+        let php = "function __(string $key) {}\nfn t($key) => $key;\necho __($label);\n";
+        assert_eq!(computed(php, "app/helpers.php"), vec!["$label"]);
+    }
+
+    #[test]
+    fn test_uses_ast_extractor_for_selected_languages() {
+        let finder = KeyFinder::default().with_ast_languages(HashSet::from([SourceLanguage::Tsx]));
+        let content = r#"// t("commented.out")
+const label = "t('inside.string')";
+const { t } = useTranslation("shop", { keyPrefix: "checkout" });
+const title = t(
+  "title"
+);"#;
+
+        let keys = finder.find_keys_in_file(content, Some(Path::new("src/Checkout.tsx")));
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].key, "checkout.title");
+        assert_eq!((keys[0].line, keys[0].start_char), (4, 3));
+
+        let regex_keys = finder.find_keys_in_file(content, Some(Path::new("src/Checkout.ts")));
+        assert_eq!(regex_keys.len(), 3);
+    }
+
+    #[test]
+    fn test_limits_translator_scopes_to_their_function() {
+        let finder = KeyFinder::default();
+        let content = r#"
+export function Checkout() {
+  const { t } = useTranslation("translation", { keyPrefix: "checkout" });
+  return <button>{t("submit")}</button>;
+}
+
+export const Header = () => {
+  return <h1>{t("header.title")}</h1>;
+};
+"#;
+        let keys: Vec<String> = finder
+            .find_keys_in_file(content, Some(Path::new("src/App.tsx")))
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(keys, vec!["checkout.submit", "header.title"]);
+
+        let vue = r#"<template><p>{{ t("empty") }}</p></template>
+<script setup lang="ts">
+function cartLabels() {
+  const { t } = useI18n({ useScope: "cart" });
+  return [t("total")];
+}
+function globalLabels() {
+  const { t } = useI18n({ useScope: "global" });
+  return [t("nav.home")];
+}
+</script>
+"#;
+        let keys: Vec<String> = finder
+            .find_keys_in_file(vue, Some(Path::new("src/Cart.vue")))
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(keys, vec!["empty", "cart.total", "nav.home"]);
+    }
+
+    #[test]
+    fn test_applies_key_prefix_of_scoped_translators() {
+        let finder = KeyFinder::default();
+        let content = r#"
+function Checkout() {
+  const { t } = useTranslation("shop", { keyPrefix: "checkout" });
+  return t("title");
+}
+function Cart() {
+  const t = useTranslations("cart");
+  return t("empty");
+}
+export async function Page() {
+  const t = await getTranslations({ locale, namespace: "page.meta" });
+  return t("description");
+}
+const fixed = i18next.getFixedT(null, ["admin"], "users");
+fixed; t("list");
+function Plain() {
+  const { t } = useTranslation();
+  return t("home.title");
+}
+"#;
+        let keys: Vec<String> = finder
+            .find_keys_in_file(content, None)
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "checkout.title",
+                "cart.empty",
+                "page.meta.description",
+                "users.list",
+                "home.title"
+            ]
+        );
+
+        let namespaced =
+            KeyFinder::default().with_namespaces(Some(Namespaces::new("translation", ":")));
+        let keys: Vec<String> = namespaced
+            .find_keys_in_file(content, None)
+            .into_iter()
+            .map(|found| found.key)
+            .collect();
+        assert_eq!(keys[0], "shop:checkout.title");
+        assert_eq!(keys[3], "admin:users.list");
+        assert_eq!(keys[4], "translation:home.title");
+    }
 }
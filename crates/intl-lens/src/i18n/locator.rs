@@ -0,0 +1,1152 @@
+//! Positions of keys and values in locale file text.
+//!
+//! JSON, YAML and TOML are parsed through `serde`, which drops positions, so every locale file
+//! is also walked by a small structural scanner for its syntax that records where each key and
+//! its value are written. Keys are flattened the same way as by
+//! [`TranslationParser`](super::parser::TranslationParser): `a.b`, and `a.0` for list items.
+//! Positions are only used when the located keys [agree with](agrees_with) the parsed ones.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use super::parser::{
+    decode_xml_text, find_tag_end, find_xml_element, has_line_continuation, js_module_root,
+    split_property_line, unescape_property,
+};
+
+/// A zero-based range in a file. Characters are byte offsets within their line, like
+/// [`FoundKey`](super::key_finder::FoundKey) positions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSpan {
+    pub line: usize,
+    pub start_char: usize,
+    pub end_line: usize,
+    pub end_char: usize,
+}
+
+/// Byte ranges of a key, without its quotes, and of its value: the whole literal, or the
+/// brackets of a nested object. List items have no key of their own and use the value range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Located {
+    pub key: Range<usize>,
+    pub value: Range<usize>,
+}
+
+/// Locate every key of a locale file, choosing the syntax by file extension as
+/// [`TranslationParser::parse_file`](super::parser::TranslationParser::parse_file) does.
+pub fn locate_keys(content: &str, extension: &str) -> HashMap<String, Located> {
+    match extension {
+        "yaml" | "yml" => locate_yaml(content),
+        "toml" => locate_toml(content),
+        "properties" => locate_properties(content),
+        "resx" => locate_resx(content),
+        "js" | "mjs" | "cjs" | "ts" | "mts" | "cts" => js_module_root(content)
+            .map(|root| BraceWalker::new(content, Dialect::Js).locate_from(root))
+            .unwrap_or_default(),
+        "php" => BraceWalker::new(content, Dialect::Php).locate_root(),
+        _ => BraceWalker::new(content, Dialect::Json).locate_root(),
+    }
+}
+
+/// The entry for `key`, or else for its closest located parent, such as the list holding an
+/// item the scanner does not descend into.
+pub fn nearest<'a>(located: &'a HashMap<String, Located>, key: &str) -> Option<&'a Located> {
+    located.get(nearest_key(located, key)?)
+}
+
+fn nearest_key<'a>(located: &HashMap<String, Located>, key: &'a str) -> Option<&'a str> {
+    let mut scope = key;
+    loop {
+        if located.contains_key(scope) {
+            return Some(scope);
+        }
+        scope = scope.rsplit_once('.')?.0;
+    }
+}
+
+/// Whether `located` agrees with the keys a parser read from the same file: each parsed key is
+/// located, or falls under a located value the scanner does not descend into, and each located
+/// string literal was parsed. When they disagree, the scanner misread the file and its positions
+/// cannot be trusted.
+pub fn agrees_with<'a>(
+    located: &HashMap<String, Located>,
+    content: &str,
+    keys: impl IntoIterator<Item = &'a String>,
+) -> bool {
+    let keys: HashSet<&str> = keys.into_iter().map(String::as_str).collect();
+    let located_parents = parents(located.keys().map(String::as_str));
+    let parsed_parents = parents(keys.iter().copied());
+
+    let parsed_located = keys.iter().all(|key| {
+        nearest_key(located, key)
+            .is_some_and(|found| found.len() == key.len() || !located_parents.contains(found))
+    });
+    parsed_located
+        && located.iter().all(|(path, found)| {
+            located_parents.contains(path.as_str())
+                || !content
+                    .get(found.value.clone())
+                    .is_some_and(|value| value.starts_with(['"', '\'']))
+                || keys.contains(path.as_str())
+                || parsed_parents.contains(path.as_str())
+        })
+}
+
+/// Every proper prefix of `keys` ending at a `.`: `a` and `a.b` for `a.b.c`.
+fn parents<'a>(keys: impl Iterator<Item = &'a str>) -> HashSet<&'a str> {
+    keys.flat_map(|key| key.match_indices('.').map(move |(index, _)| &key[..index]))
+        .collect()
+}
+
+/// Converts byte offsets into line and character positions.
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(content: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self { line_starts }
+    }
+
+    pub fn span(&self, range: &Range<usize>) -> TextSpan {
+        let (line, start_char) = self.position(range.start);
+        let (end_line, end_char) = self.position(range.end);
+        TextSpan {
+            line,
+            start_char,
+            end_line,
+            end_char,
+        }
+    }
+
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let line = self
+            .line_starts
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1);
+        (line, offset - self.line_starts[line])
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialect {
+    Json,
+    Js,
+    Php,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Open(char),
+    Close(char),
+    Arrow,
+    Punct(char),
+    /// A string, identifier or number, with string escapes resolved.
+    Text(String),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    range: Range<usize>,
+    /// The range of a string's contents, without its quotes.
+    inner: Range<usize>,
+}
+
+/// Walks the nested objects and arrays of JSON(5), JavaScript object literals and PHP arrays.
+struct BraceWalker {
+    tokens: Vec<Token>,
+    pos: usize,
+    located: HashMap<String, Located>,
+}
+
+impl BraceWalker {
+    fn new(content: &str, dialect: Dialect) -> Self {
+        Self {
+            tokens: tokenize(content, dialect),
+            pos: 0,
+            located: HashMap::new(),
+        }
+    }
+
+    /// Walk the first object (JSON) or array (PHP) in the file.
+    fn locate_root(mut self) -> HashMap<String, Located> {
+        while let Some(token) = self.tokens.get(self.pos) {
+            let close = match &token.kind {
+                TokenKind::Open('{') => Some('}'),
+                TokenKind::Open('[') => Some(']'),
+                TokenKind::Text(ident) if ident == "array" && self.is_open_at(self.pos + 1) => {
+                    self.pos += 1;
+                    Some(')')
+                }
+                _ => None,
+            };
+            self.pos += 1;
+            if let Some(close) = close {
+                self.container(close, "");
+                break;
+            }
+        }
+        self.located
+    }
+
+    /// Walk the object opened by the `{` at byte `offset`.
+    fn locate_from(mut self, offset: usize) -> HashMap<String, Located> {
+        if let Some(index) = self
+            .tokens
+            .iter()
+            .position(|token| token.range.start == offset && token.kind == TokenKind::Open('{'))
+        {
+            self.pos = index + 1;
+            self.container('}', "");
+        }
+        self.located
+    }
+
+    fn is_open_at(&self, index: usize) -> bool {
+        self.tokens
+            .get(index)
+            .is_some_and(|token| token.kind == TokenKind::Open('('))
+    }
+
+    fn kind_at(&self, index: usize) -> Option<&TokenKind> {
+        self.tokens.get(index).map(|token| &token.kind)
+    }
+
+    /// Walk the members of a container whose opening bracket was just consumed, up to and
+    /// including its closing bracket.
+    fn container(&mut self, close: char, prefix: &str) {
+        let mut index = 0;
+
+        while let Some(token) = self.tokens.get(self.pos) {
+            match &token.kind {
+                TokenKind::Close(_) => {
+                    self.pos += 1;
+                    return;
+                }
+                TokenKind::Punct(',' | ';') => {
+                    self.pos += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let separator = match close {
+                '}' => TokenKind::Punct(':'),
+                _ => TokenKind::Arrow,
+            };
+            if let TokenKind::Text(key) = &token.kind {
+                if self.kind_at(self.pos + 1) == Some(&separator) {
+                    let path = join(prefix, key);
+                    let key_range = token.inner.clone();
+                    self.pos += 2;
+                    self.value(path, Some(key_range));
+                    continue;
+                }
+            }
+
+            if close == '}' {
+                // Spreads, computed keys, methods and shorthand properties have no static value.
+                self.skip_expression();
+            } else {
+                let path = join(prefix, &index.to_string());
+                index += 1;
+                self.value(path, None);
+            }
+        }
+    }
+
+    fn value(&mut self, path: String, key: Option<Range<usize>>) {
+        let Some(start) = self.tokens.get(self.pos).map(|token| token.range.start) else {
+            return;
+        };
+
+        match self.kind_at(self.pos) {
+            Some(TokenKind::Open('{')) => {
+                self.pos += 1;
+                self.container('}', &path);
+            }
+            Some(TokenKind::Open('[')) => {
+                self.pos += 1;
+                self.container(']', &path);
+            }
+            Some(TokenKind::Text(ident)) if ident == "array" && self.is_open_at(self.pos + 1) => {
+                self.pos += 2;
+                self.container(')', &path);
+            }
+            _ => self.skip_expression(),
+        }
+
+        let end = self.tokens[..self.pos]
+            .last()
+            .map_or(start, |token| token.range.end);
+        let value = start..end.max(start);
+        self.located.insert(
+            path,
+            Located {
+                key: key.unwrap_or_else(|| value.clone()),
+                value,
+            },
+        );
+    }
+
+    /// Skip tokens up to a `,`, `;` or closing bracket at the current nesting depth.
+    fn skip_expression(&mut self) {
+        let mut depth = 0usize;
+
+        while let Some(token) = self.tokens.get(self.pos) {
+            match token.kind {
+                TokenKind::Open(_) => depth += 1,
+                TokenKind::Close(_) if depth == 0 => return,
+                TokenKind::Close(_) => depth -= 1,
+                TokenKind::Punct(',' | ';') if depth == 0 => return,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+}
+
+fn tokenize(content: &str, dialect: Dialect) -> Vec<Token> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while let Some(ch) = content[pos..].chars().next() {
+        let start = pos;
+        let rest = &content[pos..];
+
+        if ch.is_whitespace() {
+            pos += ch.len_utf8();
+            continue;
+        }
+        if rest.starts_with("//") || (dialect == Dialect::Php && ch == '#') {
+            pos += rest.find('\n').unwrap_or(rest.len());
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("/*") {
+            pos += comment.find("*/").map_or(rest.len(), |end| end + 4);
+            continue;
+        }
+
+        let kind = match ch {
+            '"' | '\'' => {
+                let (text, end) = read_string(content, pos, ch);
+                pos = end;
+                tokens.push(Token {
+                    kind: TokenKind::Text(text),
+                    range: start..pos,
+                    inner: start + 1..pos.saturating_sub(1).max(start + 1),
+                });
+                continue;
+            }
+            '`' if dialect == Dialect::Js => {
+                pos = skip_template(content, pos);
+                TokenKind::Punct('`')
+            }
+            '=' if rest.starts_with("=>") => {
+                pos += 2;
+                TokenKind::Arrow
+            }
+            '{' | '[' | '(' => {
+                pos += 1;
+                TokenKind::Open(ch)
+            }
+            '}' | ']' | ')' => {
+                pos += 1;
+                TokenKind::Close(ch)
+            }
+            _ if ch.is_alphanumeric()
+                || ch == '_'
+                || ch == '$'
+                || (ch == '-' && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit)) =>
+            {
+                pos += ch.len_utf8();
+                while let Some(next) = content[pos..].chars().next() {
+                    if next.is_alphanumeric() || matches!(next, '_' | '$' | '.' | '-') {
+                        pos += next.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+                TokenKind::Text(content[start..pos].to_string())
+            }
+            _ => {
+                pos += ch.len_utf8();
+                TokenKind::Punct(ch)
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            range: start..pos,
+            inner: start..pos,
+        });
+    }
+
+    tokens
+}
+
+/// Read the string starting with the quote at `start`, returning its text and the offset
+/// after the closing quote.
+fn read_string(content: &str, start: usize, quote: char) -> (String, usize) {
+    let mut text = String::new();
+    let mut chars = content[start + 1..].char_indices();
+
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            _ if ch == quote => return (text, start + 1 + index + 1),
+            '\\' => match chars.next().map(|(_, escaped)| escaped) {
+                Some('n') => text.push('\n'),
+                Some('t') => text.push('\t'),
+                Some('r') => text.push('\r'),
+                Some('u') => {
+                    let digits: String = chars.by_ref().take(4).map(|(_, ch)| ch).collect();
+                    text.extend(
+                        u32::from_str_radix(&digits, 16)
+                            .ok()
+                            .and_then(char::from_u32),
+                    );
+                }
+                Some(other) => text.push(other),
+                None => {}
+            },
+            _ => text.push(ch),
+        }
+    }
+
+    (text, content.len())
+}
+
+/// Skip the template literal starting at `start`, including `${…}` expressions.
+fn skip_template(content: &str, start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut chars = content[start + 1..].char_indices();
+
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            '`' if depth == 0 => return start + 1 + index + 1,
+            '$' if depth == 0 && content[start + 1 + index..].starts_with("${") => {
+                chars.next();
+                depth = 1;
+            }
+            '{' if depth > 0 => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            _ => {}
+        }
+    }
+
+    content.len()
+}
+
+/// Physical lines with the byte offset they start at, without line terminators.
+fn lines_with_offsets(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line.trim_end_matches(['\n', '\r'])))
+    })
+}
+
+/// Offset of the first `#` that starts a comment, outside quotes and after whitespace.
+fn comment_start(text: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut previous = ' ';
+
+    for (index, ch) in text.char_indices() {
+        match (quote, ch) {
+            (Some('"'), '\\') if previous != '\\' => {}
+            (Some(open), _) if ch == open && !(open == '"' && previous == '\\') => quote = None,
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '#') if previous.is_whitespace() => return Some(index),
+            _ => {}
+        }
+        previous = ch;
+    }
+
+    None
+}
+
+/// A quoted or plain key at the start of `text`, followed by `separator`. Returns the key,
+/// its byte range in `text` and the offset after the separator.
+fn split_key(text: &str, separator: char) -> Option<(String, Range<usize>, usize)> {
+    let (key, range, after) = match text.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let (key, end) = read_string(text, 0, quote);
+            (key, 1..end.saturating_sub(1).max(1), end)
+        }
+        _ => {
+            let end = text.find(separator)?;
+            let key = text[..end].trim_end();
+            (key.to_string(), 0..key.len(), key.len())
+        }
+    };
+
+    let rest = &text[after..];
+    let separator_at = after + rest.len() - rest.trim_start().len();
+    if text[separator_at..].starts_with(separator) {
+        Some((key, range, separator_at + separator.len_utf8()))
+    } else {
+        None
+    }
+}
+
+/// A YAML mapping key: `key:` followed by a space, a tab or the end of the line.
+fn split_yaml_key(text: &str) -> Option<(String, Range<usize>, usize)> {
+    let (key, range, after) = match text.chars().next()? {
+        '"' | '\'' => split_key(text, ':')?,
+        _ => {
+            let end = text
+                .match_indices(':')
+                .map(|(index, _)| index)
+                .find(|index| {
+                    text[index + 1..]
+                        .chars()
+                        .next()
+                        .map_or(true, char::is_whitespace)
+                })?;
+            let key = text[..end].trim_end();
+            (key.to_string(), 0..key.len(), end + 1)
+        }
+    };
+    let ends_key = text[after..]
+        .chars()
+        .next()
+        .map_or(true, char::is_whitespace);
+    ends_key.then_some((key, range, after))
+}
+
+/// Trim whitespace and a trailing comment, returning the byte range of what is left.
+fn value_range(text: &str, offset: usize) -> Range<usize> {
+    let text = &text[..comment_start(text).unwrap_or(text.len())];
+    let trimmed = text.trim();
+    let start = offset + (text.len() - text.trim_start().len());
+    start..start + trimmed.len()
+}
+
+/// Mapping keys and sequence items of block-style YAML. Flow collections are located as a
+/// whole; their members fall back to the collection via [`nearest`].
+fn locate_yaml(content: &str) -> HashMap<String, Located> {
+    struct BlockScalar {
+        column: usize,
+        path: String,
+        key: Range<usize>,
+        value: Option<Range<usize>>,
+    }
+
+    fn finish(block: BlockScalar, located: &mut HashMap<String, Located>) {
+        let value = block.value.unwrap_or(block.key.end..block.key.end);
+        let key = block.key;
+        located.insert(block.path, Located { key, value });
+    }
+
+    fn list_item(text: &str) -> Option<&str> {
+        text.strip_prefix('-')
+            .filter(|item| item.is_empty() || item.starts_with([' ', '\t']))
+    }
+
+    let mut located = HashMap::new();
+    // Keys and list items whose children may follow, with the column their children must
+    // be indented past.
+    let mut parents: Vec<(usize, String)> = Vec::new();
+    let mut list_lengths: HashMap<String, usize> = HashMap::new();
+    let mut block: Option<BlockScalar> = None;
+
+    for (line_start, line) in lines_with_offsets(content) {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if let Some(scalar) = &mut block {
+            if trimmed.is_empty() || indent > scalar.column {
+                if !trimmed.is_empty() {
+                    let range = scalar.value.get_or_insert(line_start + indent..0);
+                    range.end = line_start + line.len();
+                }
+                continue;
+            }
+            if let Some(scalar) = block.take() {
+                finish(scalar, &mut located);
+            }
+        }
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with("---") || trimmed.starts_with("...") {
+            parents.clear();
+            list_lengths.clear();
+            continue;
+        }
+
+        // A list may sit at the same indentation as the key that holds it.
+        let is_item = list_item(trimmed).is_some();
+        while parents
+            .last()
+            .is_some_and(|(level, _)| *level > indent || (*level == indent && !is_item))
+        {
+            parents.pop();
+        }
+        let mut prefix = parents
+            .last()
+            .map_or_else(String::new, |(_, path)| path.clone());
+
+        let mut rest = trimmed;
+        let mut column = indent;
+        while let Some(item) = list_item(rest) {
+            let length = list_lengths.entry(prefix.clone()).or_insert(0);
+            prefix = join(&prefix, &length.to_string());
+            *length += 1;
+
+            rest = item.trim_start();
+            column = line.len() - rest.len();
+            // Keys of an item mapping are aligned after the dash.
+            parents.push((column - 1, prefix.clone()));
+            if !rest.is_empty() && list_item(rest).is_none() && split_yaml_key(rest).is_none() {
+                let value = value_range(rest, line_start + column);
+                located.insert(
+                    prefix.clone(),
+                    Located {
+                        key: value.clone(),
+                        value,
+                    },
+                );
+                rest = "";
+            }
+        }
+
+        let Some((key, key_range, after)) = split_yaml_key(rest) else {
+            continue;
+        };
+        let path = join(&prefix, &key);
+        let offset = line_start + column;
+        let key_range = offset + key_range.start..offset + key_range.end;
+
+        let mut value = value_range(&rest[after..], offset + after);
+        // An anchor names the value after it, which may be a nested mapping.
+        if let Some(anchor) = content[value.clone()].strip_prefix('&') {
+            let name_end = anchor.find(char::is_whitespace).unwrap_or(anchor.len());
+            let after_name = &anchor[name_end..];
+            value.start += 1 + name_end + (after_name.len() - after_name.trim_start().len());
+        }
+
+        match content[value.clone()].chars().next() {
+            Some('|' | '>') => {
+                block = Some(BlockScalar {
+                    column,
+                    path,
+                    key: key_range,
+                    value: None,
+                });
+            }
+            None => {
+                parents.push((column, path.clone()));
+                located.insert(
+                    path,
+                    Located {
+                        key: key_range,
+                        value: value.start..value.start,
+                    },
+                );
+            }
+            Some(_) => {
+                located.insert(
+                    path,
+                    Located {
+                        key: key_range,
+                        value,
+                    },
+                );
+            }
+        }
+    }
+
+    if let Some(scalar) = block {
+        finish(scalar, &mut located);
+    }
+    located
+}
+
+/// Keys of TOML tables, dotted keys and arrays of tables.
+fn locate_toml(content: &str) -> HashMap<String, Located> {
+    struct Pending {
+        path: String,
+        key: Range<usize>,
+        start: usize,
+        /// The delimiter closing a multi-line string, or `None` for a multi-line array.
+        closing: Option<&'static str>,
+    }
+
+    let mut located = HashMap::new();
+    let mut table = String::new();
+    let mut table_arrays: HashMap<String, usize> = HashMap::new();
+    let mut pending: Option<Pending> = None;
+    let mut depth = 0;
+
+    for (line_start, line) in lines_with_offsets(content) {
+        if let Some(value) = &pending {
+            let end = match value.closing {
+                Some(closing) => line
+                    .find(closing)
+                    .map(|index| line_start + index + closing.len()),
+                None => {
+                    depth += bracket_depth(line);
+                    (depth <= 0).then(|| value_range(line, line_start).end)
+                }
+            };
+            if let Some(end) = end {
+                if let Some(value) = pending.take() {
+                    located.insert(
+                        value.path,
+                        Located {
+                            key: value.key,
+                            value: value.start..end,
+                        },
+                    );
+                }
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let offset = line_start + line.len() - trimmed.len();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = trimmed.strip_prefix("[[") {
+            let header = &header[..header.find("]]").unwrap_or(header.len())];
+            let name = dotted_path(&split_dotted_key(header, offset + 2));
+            let length = table_arrays.entry(name.clone()).or_insert(0);
+            table = join(&name, &length.to_string());
+            *length += 1;
+            continue;
+        }
+        if let Some(header) = trimmed.strip_prefix('[') {
+            let header = &header[..header.find(']').unwrap_or(header.len())];
+            let parts = split_dotted_key(header, offset + 1);
+            table = dotted_path(&parts);
+            if let Some((_, key)) = parts.last() {
+                let value = offset..offset + trimmed.len();
+                let key = key.clone();
+                located.insert(table.clone(), Located { key, value });
+            }
+            continue;
+        }
+
+        let Some(equals) = find_unquoted(trimmed, '=') else {
+            continue;
+        };
+        let parts = split_dotted_key(&trimmed[..equals], offset);
+        let Some((_, key)) = parts.last() else {
+            continue;
+        };
+        let key = key.clone();
+        let path = join(&table, &dotted_path(&parts));
+
+        let value = value_range(&trimmed[equals + 1..], offset + equals + 1);
+        let text = &content[value.clone()];
+        let closing = ["\"\"\"", "'''"]
+            .into_iter()
+            .find(|quote| text.starts_with(quote) && !text[3..].contains(quote));
+        depth = bracket_depth(text);
+        if closing.is_some() || depth > 0 {
+            pending = Some(Pending {
+                path,
+                key,
+                start: value.start,
+                closing,
+            });
+        } else {
+            located.insert(path, Located { key, value });
+        }
+    }
+
+    located
+}
+
+fn dotted_path(parts: &[(String, Range<usize>)]) -> String {
+    parts
+        .iter()
+        .map(|(part, _)| part.as_str())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Net count of opening over closing brackets outside strings and comments.
+fn bracket_depth(text: &str) -> i32 {
+    let mut depth = 0;
+    let mut quote = None;
+    for ch in text.chars() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '#') => break,
+            (None, '[' | '{') => depth += 1,
+            (None, ']' | '}') => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+fn find_unquoted(text: &str, target: char) -> Option<usize> {
+    let mut quote = None;
+    for (index, ch) in text.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, _) if ch == target => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split a TOML key such as `a."b.c".d` into its parts and their ranges, offset by `offset`.
+fn split_dotted_key(text: &str, offset: usize) -> Vec<(String, Range<usize>)> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    while start < text.len() {
+        let rest = &text[start..];
+        let part_start = start + rest.len() - rest.trim_start().len();
+        let rest = &text[part_start..];
+        let Some(first) = rest.chars().next() else {
+            break;
+        };
+
+        let (part, range, end) = if first == '"' || first == '\'' {
+            let (part, end) = read_string(rest, 0, first);
+            let inner = part_start + 1..(part_start + end).saturating_sub(1);
+            (part, inner, part_start + end)
+        } else {
+            let end = rest.find('.').unwrap_or(rest.len());
+            let part = rest[..end].trim_end();
+            let range = part_start..part_start + part.len();
+            (part.to_string(), range, part_start + end)
+        };
+        if !part.is_empty() {
+            parts.push((part, offset + range.start..offset + range.end));
+        }
+
+        let rest = &text[end.min(text.len())..];
+        match rest.find('.') {
+            Some(dot) if rest[..dot].trim().is_empty() => start = end + dot + 1,
+            _ => break,
+        }
+    }
+
+    parts
+}
+
+/// Keys of `.properties` bundles, with values running over continuation lines.
+fn locate_properties(content: &str) -> HashMap<String, Located> {
+    let mut located: HashMap<String, Located> = HashMap::new();
+    let mut continued: Option<String> = None;
+
+    for (line_start, line) in lines_with_offsets(content) {
+        if let Some(key) = &continued {
+            if let Some(entry) = located.get_mut(key) {
+                entry.value.end = line_start + line.len();
+            }
+            if !has_line_continuation(line) {
+                continued = None;
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!') {
+            continue;
+        }
+
+        let offset = line_start + line.len() - trimmed.len();
+        let (key, value) = split_property_line(trimmed);
+        let name = unescape_property(key);
+        if name.is_empty() {
+            continue;
+        }
+        // Both halves borrow from `trimmed`.
+        let value_start = offset + (value.as_ptr() as usize - trimmed.as_ptr() as usize);
+        located.insert(
+            name.clone(),
+            Located {
+                key: offset..offset + key.len(),
+                value: value_start..value_start + value.len(),
+            },
+        );
+        if has_line_continuation(trimmed) {
+            continued = Some(name);
+        }
+    }
+
+    located
+}
+
+/// `name` attributes and `<value>` text of `.resx` string resources.
+fn locate_resx(content: &str) -> HashMap<String, Located> {
+    let mut located = HashMap::new();
+    let comments: Vec<Range<usize>> = content
+        .match_indices("<!--")
+        .map(|(start, _)| {
+            let end = content[start..]
+                .find("-->")
+                .map_or(content.len(), |end| start + end + 3);
+            start..end
+        })
+        .collect();
+
+    let mut offset = 0;
+    while let Some(found) = find_xml_element(&content[offset..], "data") {
+        let start = offset + found;
+        let Some(tag_end) = find_tag_end(&content[start..]).map(|end| start + end) else {
+            break;
+        };
+        offset = tag_end + 1;
+        if comments.iter().any(|comment| comment.contains(&start)) {
+            continue;
+        }
+
+        let tag = &content[start..tag_end];
+        let Some(name) = attribute_range(tag, "name") else {
+            continue;
+        };
+        let name = start + name.start..start + name.end;
+        let key = decode_xml_text(&content[name.clone()]);
+        let value = if tag.ends_with('/') {
+            name.clone()
+        } else {
+            let body = &content[offset..];
+            let data_end = body.find("</data>").unwrap_or(body.len());
+            element_text_range(&body[..data_end], "value").map_or(name.clone(), |range| {
+                offset + range.start..offset + range.end
+            })
+        };
+        located.insert(key, Located { key: name, value });
+    }
+
+    located
+}
+
+/// Range of the quoted value of `attribute` in a start tag.
+fn attribute_range(tag: &str, attribute: &str) -> Option<Range<usize>> {
+    let mut search = 0;
+    while let Some(found) = tag[search..].find(attribute) {
+        let start = search + found;
+        search = start + attribute.len();
+        let preceded_by_space = tag[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_whitespace);
+        let Some(rest) = tag[search..]
+            .trim_start()
+            .strip_prefix('=')
+            .map(str::trim_start)
+        else {
+            continue;
+        };
+        let Some(quote) = rest.chars().next().filter(|ch| matches!(ch, '"' | '\'')) else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let value_start = tag.len() - rest.len() + 1;
+        let value_end = value_start + tag[value_start..].find(quote)?;
+        return Some(value_start..value_end);
+    }
+    None
+}
+
+/// Range of the text inside the first `<name>` element.
+fn element_text_range(content: &str, name: &str) -> Option<Range<usize>> {
+    let start = find_xml_element(content, name)?;
+    let tag_end = start + find_tag_end(&content[start..])? + 1;
+    let end = tag_end + content[tag_end..].find(&format!("</{name}>"))?;
+    Some(tag_end..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(
+        content: &'a str,
+        located: &HashMap<String, Located>,
+        key: &str,
+    ) -> (&'a str, &'a str) {
+        let found = located
+            .get(key)
+            .unwrap_or_else(|| panic!("{key} not located"));
+        (&content[found.key.clone()], &content[found.value.clone()])
+    }
+
+    #[test]
+    fn test_locates_nested_json_keys_by_path() {
+        let content = r#"{
+  "title": "Home",
+  "a": {
+    // The page title
+    "title": "About",
+    "list": ["x", { "title": 'y' }],
+  }
+}"#;
+        let located = locate_keys(content, "json");
+        assert_eq!(find(content, &located, "a.title"), ("title", r#""About""#));
+        assert_eq!(find(content, &located, "a.list.1.title"), ("title", "'y'"));
+        assert_eq!(find(content, &located, "a.list.0"), (r#""x""#, r#""x""#));
+
+        let lines = LineIndex::new(content);
+        let span = lines.span(&located["a.title"].key);
+        assert_eq!((span.line, span.start_char, span.end_char), (4, 5, 10));
+        assert_eq!(lines.span(&located["title"].key).line, 1);
+    }
+
+    #[test]
+    fn test_locates_js_module_and_php_array_keys() {
+        let content = r#"type Messages = { title: string };
+const messages: Messages = {
+  title: 'Home',
+  nav: { home: `Start`, count: 1 + 2 },
+  ...shared,
+};
+export default messages;"#;
+        let located = locate_keys(content, "ts");
+        assert_eq!(find(content, &located, "title"), ("title", "'Home'"));
+        assert_eq!(find(content, &located, "nav.home"), ("home", "`Start`"));
+        assert_eq!(find(content, &located, "nav.count"), ("count", "1 + 2"));
+
+        let content = r#"<?php
+declare(strict_types=1);
+// 'title' => 'Commented',
+return [
+    'title' => 'Home',
+    "auth" => array('failed' => "Failed", 'list' => ['a', 'b']),
+];"#;
+        let located = locate_keys(content, "php");
+        assert_eq!(find(content, &located, "title"), ("title", "'Home'"));
+        assert_eq!(
+            find(content, &located, "auth.failed"),
+            ("failed", r#""Failed""#)
+        );
+        assert_eq!(find(content, &located, "auth.list.1"), ("'b'", "'b'"));
+    }
+
+    #[test]
+    fn test_locates_yaml_keys() {
+        let content = r#"en:
+  title: Home # the title
+  a:
+    title: "About"
+  body: |
+    First line
+    Second line
+  base: &base
+    ok: OK
+  list:
+  - one
+  - name: two
+    value: 2
+"#;
+        let located = locate_keys(content, "yml");
+        assert_eq!(find(content, &located, "en.title"), ("title", "Home"));
+        assert_eq!(
+            find(content, &located, "en.a.title"),
+            ("title", r#""About""#)
+        );
+        assert_eq!(
+            find(content, &located, "en.body"),
+            ("body", "First line\n    Second line")
+        );
+        assert_eq!(find(content, &located, "en.base.ok"), ("ok", "OK"));
+        assert_eq!(find(content, &located, "en.list.0"), ("one", "one"));
+        assert_eq!(find(content, &located, "en.list.1.name"), ("name", "two"));
+        assert_eq!(find(content, &located, "en.list.1.value"), ("value", "2"));
+    }
+
+    #[test]
+    fn test_locates_toml_properties_and_resx_keys() {
+        let content =
+            "title = \"Home\"\n[a.\"b.c\"]\ntitle = \"\"\"\nAbout\n\"\"\"\ncount = 1 # one\n";
+        let located = locate_keys(content, "toml");
+        assert_eq!(find(content, &located, "title"), ("title", "\"Home\""));
+        assert_eq!(
+            find(content, &located, "a.b.c.title"),
+            ("title", "\"\"\"\nAbout\n\"\"\"")
+        );
+        assert_eq!(find(content, &located, "a.b.c.count"), ("count", "1"));
+
+        let content = "# comment\ngreeting = Hello \\\n    world\nfarewell:Bye\n";
+        let located = locate_keys(content, "properties");
+        assert_eq!(
+            find(content, &located, "greeting"),
+            ("greeting", "Hello \\\n    world")
+        );
+        assert_eq!(find(content, &located, "farewell"), ("farewell", "Bye"));
+
+        let content = r#"<root>
+  <!-- <data name="Old"><value>Old</value></data> -->
+  <data name="Title" xml:space="preserve"><value>Home</value></data>
+</root>"#;
+        let located = locate_keys(content, "resx");
+        assert!(!located.contains_key("Old"));
+        assert_eq!(find(content, &located, "Title"), ("Title", "Home"));
+    }
+
+    #[test]
+    fn test_checks_located_keys_against_parsed_keys() {
+        let content =
+            r#"{"title": "title", "list": [1, 2], "empty": null, "nav": {"title": "nav.title"}}"#;
+        let located = locate_keys(content, "json");
+        assert_eq!(find(content, &located, "title"), ("title", r#""title""#));
+        assert_eq!(
+            find(content, &located, "nav.title"),
+            ("title", r#""nav.title""#)
+        );
+
+        let keys = |keys: &[&str]| -> Vec<String> { keys.iter().map(|k| k.to_string()).collect() };
+        let parsed = keys(&["title", "list.0", "list.1", "nav.title"]);
+        assert!(agrees_with(&located, content, &parsed));
+        // A parsed key the scanner missed, or a located string the parser did not read.
+        assert!(!agrees_with(
+            &located,
+            content,
+            &keys(&["title", "list.0", "list.1", "nav.title", "nav.other"])
+        ));
+        assert!(!agrees_with(
+            &located,
+            content,
+            &keys(&["list.0", "nav.title"])
+        ));
+
+        let content = "en:\n  base: &base\n    ok: OK\n  other:\n    <<: *base\n    own: Own\n";
+        let located = locate_keys(content, "yml");
+        let parsed = keys(&["en.base.ok", "en.other.<<.ok", "en.other.own"]);
+        assert!(agrees_with(&located, content, &parsed));
+    }
+
+    #[test]
+    fn test_nearest_falls_back_to_parent() {
+        let located = locate_keys(r#"{"a": {"b": "x"}}"#, "json");
+        assert_eq!(nearest(&located, "a.b.c"), located.get("a.b"));
+        assert_eq!(nearest(&located, "z"), None);
+    }
+}
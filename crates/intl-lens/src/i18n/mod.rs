@@ -1,6 +1,13 @@
+pub mod ast;
+pub mod icu;
+pub mod jsonc;
 pub mod key_finder;
+pub mod locator;
+pub mod namespace;
 pub mod parser;
+pub mod plural;
 pub mod store;
+pub mod suppression;
 
 pub use key_finder::KeyFinder;
 pub use store::TranslationStore;
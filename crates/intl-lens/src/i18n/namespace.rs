@@ -0,0 +1,90 @@
+//! i18next-style namespaces: `locales/en/common.json` holds the `common` namespace and code
+//! refers to it as `t("common:button.save")` or through `useTranslation("common")`.
+
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Namespaces {
+    pub default_namespace: String,
+    pub separator: String,
+}
+
+impl Namespaces {
+    pub fn new(default_namespace: &str, separator: &str) -> Self {
+        Self {
+            default_namespace: default_namespace.to_string(),
+            separator: separator.to_string(),
+        }
+    }
+
+    pub fn qualify(&self, namespace: &str, key: &str) -> String {
+        format!("{}{}{}", namespace, self.separator, key)
+    }
+
+    /// Split `common:button.save` into `("common", "button.save")`. Keys without a namespace
+    /// belong to the default namespace.
+    pub fn split<'a>(&'a self, key: &'a str) -> (&'a str, &'a str) {
+        match self.explicit_namespace(key) {
+            Some((namespace, rest)) => (namespace, rest),
+            None => (self.default_namespace.as_str(), key),
+        }
+    }
+
+    pub fn explicit_namespace<'a>(&self, key: &'a str) -> Option<(&'a str, &'a str)> {
+        if self.separator.is_empty() {
+            return None;
+        }
+        key.split_once(self.separator.as_str())
+            .filter(|(namespace, rest)| !namespace.is_empty() && !rest.is_empty())
+    }
+
+    /// Qualify a key found in source code, using `scope` (from `useTranslation("ns")`) or the
+    /// default namespace when the key has no explicit namespace.
+    pub fn resolve(&self, key: &str, scope: Option<&str>) -> String {
+        if self.explicit_namespace(key).is_some() {
+            return key.to_string();
+        }
+        self.qualify(scope.unwrap_or(&self.default_namespace), key)
+    }
+
+    /// The namespace a locale file contributes to: its file stem when the file sits in a locale
+    /// directory (`en/common.json`), otherwise the default namespace (`en.json`).
+    pub fn namespace_for_file<'a>(&'a self, path: &'a Path, locale: &str) -> &'a str {
+        let in_locale_dir = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .and_then(|name| name.to_str())
+            == Some(locale);
+        match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(stem) if in_locale_dir && !stem.is_empty() && stem != locale => stem,
+            _ => &self.default_namespace,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_explicit_scoped_and_default_namespaces() {
+        let namespaces = Namespaces::new("translation", ":");
+
+        assert_eq!(
+            namespaces.resolve("common:save", Some("home")),
+            "common:save"
+        );
+        assert_eq!(namespaces.resolve("save", Some("home")), "home:save");
+        assert_eq!(namespaces.resolve("save", None), "translation:save");
+        assert_eq!(namespaces.split("common:a.b"), ("common", "a.b"));
+        assert_eq!(namespaces.split("a.b"), ("translation", "a.b"));
+        assert_eq!(
+            namespaces.namespace_for_file(Path::new("locales/en/common.json"), "en"),
+            "common"
+        );
+        assert_eq!(
+            namespaces.namespace_for_file(Path::new("locales/en.json"), "en"),
+            "translation"
+        );
+    }
+}
@@ -4,19 +4,37 @@ use std::path::Path;
 use anyhow::{bail, Result};
 use serde_json::Value as JsonValue;
 use serde_yaml::Value as YamlValue;
+use toml::Value as TomlValue;
+
+use super::suppression::IGNORE_MARKER;
 
 pub struct TranslationParser;
 
 impl TranslationParser {
     pub fn parse_file(path: &Path) -> Result<HashMap<String, String>> {
         let content = std::fs::read_to_string(path)?;
+        Self::parse_file_content(path, &content)
+    }
+
+    /// Like [`Self::parse_file`], for content already read from `path`.
+    pub fn parse_file_content(path: &Path, content: &str) -> Result<HashMap<String, String>> {
         let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
         match extension {
-            "yaml" | "yml" => Self::parse_yaml(&content),
-            "php" => Self::parse_php(&content),
-            "arb" => Self::parse_arb(&content),
-            _ => Self::parse_json(&content),
+            "js" | "mjs" | "cjs" | "ts" | "mts" | "cts" => {
+                let (translations, warnings) = Self::parse_js_module_with_warnings(content)?;
+                for warning in warnings {
+                    tracing::warn!("{}: {}", path.display(), warning);
+                }
+                Ok(translations)
+            }
+            "yaml" | "yml" => Self::parse_yaml(content),
+            "toml" => Self::parse_toml(content),
+            "php" => Self::parse_php(content),
+            "arb" => Self::parse_arb(content),
+            "properties" => Self::parse_properties(content),
+            "resx" => Self::parse_resx(content),
+            _ => Self::parse_json(content),
         }
     }
 
@@ -28,6 +46,11 @@ impl TranslationParser {
 
         if let JsonValue::Object(map) = value {
             for (key, val) in map {
+                if key == IGNORE_MARKER {
+                    Self::flatten_json(&val, key, &mut result);
+                    continue;
+                }
+
                 // Skip metadata keys: @@locale, @keyName (descriptions), etc.
                 if key.starts_with('@') {
                     continue;
@@ -43,6 +66,197 @@ impl TranslationParser {
         Ok(result)
     }
 
+    /// Parse Java `.properties` resource bundles.
+    /// Supports `=`, `:` and whitespace separators, `#`/`!` comments, line continuations
+    /// and `\uXXXX` escapes. MessageFormat placeholders such as `{0}` are kept verbatim.
+    pub fn parse_properties(content: &str) -> Result<HashMap<String, String>> {
+        let mut result = HashMap::new();
+        let mut lines = content.lines();
+
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!') {
+                continue;
+            }
+
+            let mut logical_line = trimmed.to_string();
+            while has_line_continuation(&logical_line) {
+                logical_line.pop();
+                match lines.next() {
+                    Some(next) => logical_line.push_str(next.trim_start()),
+                    None => break,
+                }
+            }
+
+            let (key, value) = split_property_line(&logical_line);
+            let key = unescape_property(key);
+            if key.is_empty() {
+                continue;
+            }
+            result.insert(key, unescape_property(value));
+        }
+
+        Ok(result)
+    }
+
+    /// Parse .NET `.resx` XML resources.
+    /// Only string `<data>` entries are returned; binary and typed resources are skipped.
+    pub fn parse_resx(content: &str) -> Result<HashMap<String, String>> {
+        let content = strip_xml_comments(content);
+        let attribute_regex = regex::Regex::new(r#"([\w:.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)?;
+        let mut result = HashMap::new();
+        let mut rest = content.as_str();
+
+        while let Some(start) = find_xml_element(rest, "data") {
+            let Some(tag_end) = find_tag_end(&rest[start..]) else {
+                bail!("Unterminated <data> tag");
+            };
+            let tag = &rest[start..start + tag_end];
+            let after_tag = &rest[start + tag_end + 1..];
+
+            let mut name = None;
+            let mut is_string_resource = true;
+            for capture in attribute_regex.captures_iter(tag) {
+                let attribute = &capture[1];
+                let value = capture
+                    .get(2)
+                    .or_else(|| capture.get(3))
+                    .map(|m| m.as_str())
+                    .unwrap_or_default();
+                match attribute {
+                    "name" => name = Some(decode_xml_text(value)),
+                    "mimetype" => is_string_resource = false,
+                    "type" if !value.starts_with("System.String") => is_string_resource = false,
+                    _ => {}
+                }
+            }
+
+            if tag.ends_with('/') {
+                rest = after_tag;
+                continue;
+            }
+
+            let Some(data_end) = after_tag.find("</data>") else {
+                bail!("Unterminated <data> element");
+            };
+            let inner = &after_tag[..data_end];
+            rest = &after_tag[data_end + "</data>".len()..];
+
+            let (Some(name), true) = (name, is_string_resource) else {
+                continue;
+            };
+            if let Some(value) = extract_xml_element_text(inner, "value") {
+                result.insert(name, value);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parse the `<i18n>` custom blocks of a Vue single-file component into per-locale messages.
+    /// Blocks with a `locale` attribute hold messages for that locale; blocks without one are keyed
+    /// by locale at the top level. Blocks loaded from `src` and blocks that fail to parse are skipped.
+    pub fn parse_vue_i18n_blocks(
+        content: &str,
+    ) -> Result<HashMap<String, HashMap<String, String>>> {
+        let attribute_regex = regex::Regex::new(r#"([\w:.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)?;
+        let mut result: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut rest = content;
+
+        while let Some(start) = find_xml_element(rest, "i18n") {
+            let Some(tag_end) = find_tag_end(&rest[start..]) else {
+                bail!("Unterminated <i18n> tag");
+            };
+            let tag = &rest[start..start + tag_end];
+            let after_tag = &rest[start + tag_end + 1..];
+
+            if tag.ends_with('/') {
+                rest = after_tag;
+                continue;
+            }
+
+            let Some(block_end) = after_tag.find("</i18n>") else {
+                bail!("Unterminated <i18n> block");
+            };
+            let body = &after_tag[..block_end];
+            rest = &after_tag[block_end + "</i18n>".len()..];
+
+            let mut lang = "json".to_string();
+            let mut locale = None;
+            let mut external = false;
+            for capture in attribute_regex.captures_iter(tag) {
+                let value = capture
+                    .get(2)
+                    .or_else(|| capture.get(3))
+                    .map(|m| m.as_str().trim().to_string())
+                    .unwrap_or_default();
+                match &capture[1] {
+                    "lang" => lang = value.to_ascii_lowercase(),
+                    "locale" if !value.is_empty() => locale = Some(value),
+                    "src" => external = true,
+                    _ => {}
+                }
+            }
+
+            if external || body.trim().is_empty() {
+                continue;
+            }
+
+            let parsed = match lang.as_str() {
+                "yaml" | "yml" => Self::parse_yaml(body),
+                "json" | "json5" => Self::parse_json(body),
+                other => {
+                    tracing::warn!("Unsupported <i18n> block lang \"{}\"", other);
+                    continue;
+                }
+            };
+            let messages = match parsed {
+                Ok(messages) => messages,
+                Err(e) => {
+                    tracing::warn!("Failed to parse <i18n> block: {}", e);
+                    continue;
+                }
+            };
+
+            match locale {
+                Some(locale) => result.entry(locale).or_default().extend(messages),
+                None => {
+                    for (key, value) in messages {
+                        if let Some((locale, key)) = key.split_once('.') {
+                            result
+                                .entry(locale.to_string())
+                                .or_default()
+                                .insert(key.to_string(), value);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parse JavaScript/TypeScript locale modules that export a static object literal
+    /// (`export default { … }`, `module.exports = { … }`). Spreads, computed keys, references
+    /// and template strings with expressions are skipped with a warning.
+    pub fn parse_js_module(content: &str) -> Result<HashMap<String, String>> {
+        let (translations, warnings) = Self::parse_js_module_with_warnings(content)?;
+        for warning in warnings {
+            tracing::warn!("{}", warning);
+        }
+        Ok(translations)
+    }
+
+    fn parse_js_module_with_warnings(
+        content: &str,
+    ) -> Result<(HashMap<String, String>, Vec<String>)> {
+        let value = JsModuleParser::new(content).parse_module()?;
+        let mut result = HashMap::new();
+        let mut warnings = Vec::new();
+        flatten_js(&value, String::new(), &mut result, &mut warnings);
+        Ok((result, warnings))
+    }
+
     pub fn parse_php(content: &str) -> Result<HashMap<String, String>> {
         let mut parser = PhpParser::new(content);
         let value = parser.parse_root_array()?;
@@ -51,13 +265,42 @@ impl TranslationParser {
         Ok(result)
     }
 
+    /// Parse JSON locale files, falling back to JSON5 (a superset of JSONC) so comments,
+    /// trailing commas and unquoted keys are accepted.
     pub fn parse_json(content: &str) -> Result<HashMap<String, String>> {
-        let value: JsonValue = serde_json::from_str(content)?;
+        let value: JsonValue = match serde_json::from_str(content) {
+            Ok(value) => value,
+            Err(strict_error) => json5::from_str(content).map_err(|_| strict_error)?,
+        };
         let mut result = HashMap::new();
         Self::flatten_json(&value, String::new(), &mut result);
         Ok(result)
     }
 
+    /// Parse a JSON file whose top-level keys are literal strings (Laravel's `lang/vi.json`),
+    /// so `"Log in."` stays a single key instead of being split on the dot.
+    pub fn parse_json_literal(content: &str) -> Result<HashMap<String, String>> {
+        let value: JsonValue = match serde_json::from_str(content) {
+            Ok(value) => value,
+            Err(strict_error) => json5::from_str(content).map_err(|_| strict_error)?,
+        };
+        let Some(object) = value.as_object() else {
+            bail!("Literal-key JSON file root is not an object");
+        };
+
+        let mut result = HashMap::new();
+        for (key, value) in object {
+            match value {
+                JsonValue::String(s) => {
+                    result.insert(key.clone(), s.clone());
+                }
+                JsonValue::Null => {}
+                _ => Self::flatten_json(value, key.clone(), &mut result),
+            }
+        }
+        Ok(result)
+    }
+
     pub fn parse_yaml(content: &str) -> Result<HashMap<String, String>> {
         let value: YamlValue = serde_yaml::from_str(content)?;
         let mut result = HashMap::new();
@@ -65,6 +308,13 @@ impl TranslationParser {
         Ok(result)
     }
 
+    pub fn parse_toml(content: &str) -> Result<HashMap<String, String>> {
+        let value: TomlValue = toml::from_str(content)?;
+        let mut result = HashMap::new();
+        Self::flatten_toml(&value, String::new(), &mut result);
+        Ok(result)
+    }
+
     fn flatten_json(value: &JsonValue, prefix: String, result: &mut HashMap<String, String>) {
         match value {
             JsonValue::Object(map) => {
@@ -130,6 +380,258 @@ impl TranslationParser {
             YamlValue::Null | YamlValue::Tagged(_) => {}
         }
     }
+
+    fn flatten_toml(value: &TomlValue, prefix: String, result: &mut HashMap<String, String>) {
+        match value {
+            TomlValue::Table(map) => {
+                for (key, val) in map {
+                    let new_key = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    Self::flatten_toml(val, new_key, result);
+                }
+            }
+            TomlValue::String(s) => {
+                result.insert(prefix, s.clone());
+            }
+            TomlValue::Integer(n) => {
+                result.insert(prefix, n.to_string());
+            }
+            TomlValue::Float(n) => {
+                result.insert(prefix, n.to_string());
+            }
+            TomlValue::Boolean(b) => {
+                result.insert(prefix, b.to_string());
+            }
+            TomlValue::Datetime(d) => {
+                result.insert(prefix, d.to_string());
+            }
+            TomlValue::Array(arr) => {
+                for (i, val) in arr.iter().enumerate() {
+                    let new_key = format!("{}.{}", prefix, i);
+                    Self::flatten_toml(val, new_key, result);
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn has_line_continuation(line: &str) -> bool {
+    line.chars().rev().take_while(|ch| *ch == '\\').count() % 2 == 1
+}
+
+pub(super) fn split_property_line(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    let mut key_end = line.len();
+
+    for (index, ch) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' => escaped = true,
+            '=' | ':' => {
+                key_end = index;
+                break;
+            }
+            _ if ch.is_whitespace() => {
+                key_end = index;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let key = &line[..key_end];
+    let mut value = line[key_end..].trim_start();
+    if let Some(stripped) = value.strip_prefix(['=', ':']) {
+        value = stripped.trim_start();
+    }
+
+    (key, value)
+}
+
+pub(super) fn unescape_property(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('f') => result.push('\u{0C}'),
+            Some('u') => {
+                let Some(high) = read_unicode_escape(&mut chars) else {
+                    continue;
+                };
+
+                if (0xD800..0xDC00).contains(&high) {
+                    let mut lookahead = chars.clone();
+                    if lookahead.next() == Some('\\') && lookahead.next() == Some('u') {
+                        if let Some(low) = read_unicode_escape(&mut lookahead) {
+                            if (0xDC00..0xE000).contains(&low) {
+                                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                                result.extend(char::from_u32(code));
+                                chars = lookahead;
+                                continue;
+                            }
+                        }
+                    }
+                }
+
+                result.push(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+
+    result
+}
+
+fn read_unicode_escape(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<u32> {
+    let mut hex = String::with_capacity(4);
+    for _ in 0..4 {
+        hex.push(chars.next_if(char::is_ascii_hexdigit)?);
+    }
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+fn strip_xml_comments(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("<!--") {
+        result.push_str(&rest[..start]);
+        match rest[start..].find("-->") {
+            Some(end) => rest = &rest[start + end + "-->".len()..],
+            None => return result,
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Find the byte offset of the next `<name` start tag, ignoring longer names such as `<database`.
+pub(super) fn find_xml_element(content: &str, name: &str) -> Option<usize> {
+    let needle = format!("<{name}");
+    let mut offset = 0;
+
+    while let Some(found) = content[offset..].find(&needle) {
+        let start = offset + found;
+        let next = content[start + needle.len()..].chars().next();
+        if next.is_some_and(|ch| ch.is_whitespace() || ch == '>' || ch == '/') {
+            return Some(start);
+        }
+        offset = start + needle.len();
+    }
+
+    None
+}
+
+/// Find the offset of the `>` closing a start tag, skipping quoted attribute values.
+pub(super) fn find_tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+
+    for (index, ch) in tag.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn extract_xml_element_text(content: &str, name: &str) -> Option<String> {
+    let start = find_xml_element(content, name)?;
+    let tag_end = start + find_tag_end(&content[start..])?;
+    if content[..tag_end].ends_with('/') {
+        return Some(String::new());
+    }
+
+    let body = &content[tag_end + 1..];
+    let end = body.find(&format!("</{name}>"))?;
+    Some(decode_xml_text(&body[..end]))
+}
+
+pub(super) fn decode_xml_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("<![CDATA[") {
+        result.push_str(&decode_xml_entities(&rest[..start]));
+        let cdata = &rest[start + "<![CDATA[".len()..];
+        match cdata.find("]]>") {
+            Some(end) => {
+                result.push_str(&cdata[..end]);
+                rest = &cdata[end + "]]>".len()..];
+            }
+            None => {
+                result.push_str(cdata);
+                return result;
+            }
+        }
+    }
+
+    result.push_str(&decode_xml_entities(rest));
+    result
+}
+
+fn decode_xml_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        let entity_text = &rest[start + 1..];
+        let Some(end) = entity_text.find(';') else {
+            result.push_str(&rest[start..]);
+            return result;
+        };
+
+        let entity = &entity_text[..end];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+
+        match decoded {
+            Some(ch) => {
+                result.push(ch);
+                rest = &entity_text[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = entity_text;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
 }
 
 #[derive(Debug, Clone)]
@@ -519,6 +1021,559 @@ fn flatten_php(value: &PhpValue, prefix: String, result: &mut HashMap<String, St
     }
 }
 
+#[derive(Debug, Clone)]
+enum JsValue {
+    String(String),
+    Number(String),
+    Bool(bool),
+    Null,
+    Object(Vec<(String, JsValue)>),
+    Array(Vec<JsValue>),
+    Unsupported { line: usize, reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsToken {
+    Punct(char),
+    Spread,
+    String(String),
+    Template { text: String, has_expressions: bool },
+    Number(String),
+    Ident(String),
+}
+
+struct JsLexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> JsLexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    /// Returns the next token together with the byte offset where it starts.
+    fn next_token(&mut self) -> Option<(usize, JsToken)> {
+        self.skip_whitespace_and_comments();
+        let start = self.pos;
+        let ch = self.next_char()?;
+
+        let token = match ch {
+            '\'' | '"' => JsToken::String(self.read_string(ch)),
+            '`' => self.read_template(),
+            '.' if self.input[self.pos..].starts_with("..") => {
+                self.pos += 2;
+                JsToken::Spread
+            }
+            _ if ch.is_ascii_digit() => {
+                let mut number = ch.to_string();
+                while let Some(next) = self.peek_char() {
+                    if next.is_ascii_alphanumeric() || next == '.' || next == '_' {
+                        number.push(next);
+                        self.next_char();
+                    } else {
+                        break;
+                    }
+                }
+                JsToken::Number(number)
+            }
+            _ if ch.is_alphabetic() || ch == '_' || ch == '$' => {
+                let mut ident = ch.to_string();
+                while let Some(next) = self.peek_char() {
+                    if next.is_alphanumeric() || next == '_' || next == '$' {
+                        ident.push(next);
+                        self.next_char();
+                    } else {
+                        break;
+                    }
+                }
+                JsToken::Ident(ident)
+            }
+            other => JsToken::Punct(other),
+        };
+
+        Some((start, token))
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            while self.peek_char().is_some_and(char::is_whitespace) {
+                self.next_char();
+            }
+
+            let rest = &self.input[self.pos..];
+            if rest.starts_with("//") {
+                self.pos += rest.find('\n').unwrap_or(rest.len());
+                continue;
+            }
+            if let Some(comment) = rest.strip_prefix("/*") {
+                self.pos += comment.find("*/").map_or(rest.len(), |end| end + 4);
+                continue;
+            }
+
+            break;
+        }
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let ch = self.peek_char()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn read_string(&mut self, quote: char) -> String {
+        let mut result = String::new();
+
+        while let Some(ch) = self.next_char() {
+            match ch {
+                _ if ch == quote => break,
+                '\\' => self.read_escape(&mut result),
+                _ => result.push(ch),
+            }
+        }
+
+        result
+    }
+
+    fn read_template(&mut self) -> JsToken {
+        let mut text = String::new();
+        let mut has_expressions = false;
+
+        while let Some(ch) = self.next_char() {
+            match ch {
+                '`' => break,
+                '\\' => self.read_escape(&mut text),
+                '$' if self.peek_char() == Some('{') => {
+                    has_expressions = true;
+                    self.skip_template_expression();
+                }
+                _ => text.push(ch),
+            }
+        }
+
+        JsToken::Template {
+            text,
+            has_expressions,
+        }
+    }
+
+    fn skip_template_expression(&mut self) {
+        let mut depth = 0;
+
+        while let Some(ch) = self.next_char() {
+            match ch {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                '\'' | '"' => {
+                    self.read_string(ch);
+                }
+                '`' => {
+                    self.read_template();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn read_escape(&mut self, result: &mut String) {
+        let Some(escaped) = self.next_char() else {
+            return;
+        };
+
+        match escaped {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            'b' => result.push('\u{08}'),
+            'f' => result.push('\u{0C}'),
+            'v' => result.push('\u{0B}'),
+            '0' => result.push('\0'),
+            '\n' => {}
+            '\r' => {
+                if self.peek_char() == Some('\n') {
+                    self.next_char();
+                }
+            }
+            'x' => {
+                let code = self.read_hex_digits(2);
+                result.extend(code.and_then(char::from_u32));
+            }
+            'u' if self.peek_char() == Some('{') => {
+                self.next_char();
+                let end = self.input[self.pos..].find('}').unwrap_or(0);
+                let code = u32::from_str_radix(&self.input[self.pos..self.pos + end], 16).ok();
+                self.pos += end + 1;
+                result.extend(code.and_then(char::from_u32));
+            }
+            'u' => {
+                let code = self.read_hex_digits(4);
+                result.push(
+                    code.and_then(char::from_u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER),
+                );
+            }
+            other => result.push(other),
+        }
+    }
+
+    fn read_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + count)?;
+        let code = u32::from_str_radix(digits, 16).ok()?;
+        self.pos += count;
+        Some(code)
+    }
+
+    fn line_at(&self, offset: usize) -> usize {
+        self.input[..offset.min(self.input.len())]
+            .bytes()
+            .filter(|byte| *byte == b'\n')
+            .count()
+            + 1
+    }
+}
+
+/// Parser for static object literals exported from JavaScript/TypeScript locale modules,
+/// e.g. `export default { … }` or `module.exports = { … }`.
+struct JsModuleParser<'a> {
+    lexer: JsLexer<'a>,
+    lookahead: Option<(usize, JsToken)>,
+    /// Offset of the `{` opening the exported object, once found.
+    root: Option<usize>,
+}
+
+impl<'a> JsModuleParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            lexer: JsLexer::new(input),
+            lookahead: None,
+            root: None,
+        }
+    }
+
+    fn parse_module(&mut self) -> Result<JsValue> {
+        while let Some(token) = self.next_token() {
+            let is_export = match token {
+                JsToken::Ident(ref ident) if ident == "export" => self.consume_ident("default"),
+                JsToken::Ident(ref ident) if ident == "module" => {
+                    self.consume_punct('.')
+                        && self.consume_ident("exports")
+                        && self.consume_punct('=')
+                }
+                _ => false,
+            };
+
+            if is_export {
+                return self.parse_export_target();
+            }
+        }
+
+        bail!("No `export default` or `module.exports` object found")
+    }
+
+    fn parse_export_target(&mut self) -> Result<JsValue> {
+        self.root = Some(self.peek_offset());
+        match self.next_token() {
+            Some(JsToken::Punct('{')) => Ok(self.parse_object()),
+            // Wrapper helpers such as `defineMessages({ … })` or `defineI18nLocale({ … })`
+            Some(JsToken::Ident(_)) if self.consume_punct('(') => {
+                self.root = Some(self.peek_offset());
+                if self.consume_punct('{') {
+                    Ok(self.parse_object())
+                } else {
+                    bail!("Exported call does not take an object literal")
+                }
+            }
+            Some(JsToken::Ident(name)) => self.parse_variable_object(&name),
+            _ => bail!("Exported value is not an object literal"),
+        }
+    }
+
+    /// Resolve `export default messages` to `const messages = { … }` declared in the same file.
+    fn parse_variable_object(&mut self, name: &str) -> Result<JsValue> {
+        let mut declarations = JsModuleParser::new(self.lexer.input);
+
+        while let Some(token) = declarations.next_token() {
+            let is_declaration = matches!(
+                token,
+                JsToken::Ident(ref keyword) if matches!(keyword.as_str(), "const" | "let" | "var")
+            );
+            if !is_declaration || !declarations.consume_ident(name) {
+                continue;
+            }
+
+            // Skip an optional type annotation up to `=`.
+            while let Some(token) = declarations.next_token() {
+                if token == JsToken::Punct('=') {
+                    break;
+                }
+            }
+
+            self.root = Some(declarations.peek_offset());
+            if declarations.consume_punct('{') {
+                return Ok(declarations.parse_object());
+            }
+        }
+
+        bail!("Could not find an object literal for exported `{}`", name)
+    }
+
+    fn parse_object(&mut self) -> JsValue {
+        let mut entries = Vec::new();
+
+        loop {
+            let offset = self.peek_offset();
+            let Some(token) = self.next_token() else {
+                break;
+            };
+
+            let key = match token {
+                JsToken::Punct('}') => break,
+                JsToken::Punct(',') => continue,
+                JsToken::Ident(key) | JsToken::String(key) | JsToken::Number(key) => key,
+                JsToken::Template {
+                    text,
+                    has_expressions: false,
+                } => text,
+                JsToken::Spread => {
+                    self.skip_expression();
+                    entries.push((
+                        format!("...@{}", offset),
+                        self.unsupported(offset, "spread element"),
+                    ));
+                    continue;
+                }
+                JsToken::Punct('[') => {
+                    self.skip_until_closing(']');
+                    self.consume_punct(':');
+                    self.skip_expression();
+                    entries.push((
+                        format!("[computed]@{}", offset),
+                        self.unsupported(offset, "computed property key"),
+                    ));
+                    continue;
+                }
+                _ => {
+                    self.skip_expression();
+                    continue;
+                }
+            };
+
+            let value_offset = self.peek_offset();
+            let value = if self.consume_punct(':') {
+                self.parse_value()
+            } else if self.peek_token() == Some(JsToken::Punct('(')) {
+                self.skip_expression();
+                self.unsupported(value_offset, "method definition")
+            } else {
+                self.unsupported(value_offset, "shorthand property reference")
+            };
+
+            entries.push((key, value));
+        }
+
+        JsValue::Object(entries)
+    }
+
+    fn parse_array(&mut self) -> JsValue {
+        let mut items = Vec::new();
+
+        loop {
+            match self.peek_token() {
+                None => break,
+                Some(JsToken::Punct(']')) => {
+                    self.next_token();
+                    break;
+                }
+                Some(JsToken::Punct(',')) => {
+                    self.next_token();
+                }
+                Some(_) => items.push(self.parse_value()),
+            }
+        }
+
+        JsValue::Array(items)
+    }
+
+    fn parse_value(&mut self) -> JsValue {
+        let offset = self.peek_offset();
+        let value = match self.next_token() {
+            Some(JsToken::String(value)) => JsValue::String(value),
+            Some(JsToken::Template {
+                text,
+                has_expressions: false,
+            }) => JsValue::String(text),
+            Some(JsToken::Template {
+                has_expressions: true,
+                ..
+            }) => self.unsupported(offset, "template string with expressions"),
+            Some(JsToken::Number(value)) => JsValue::Number(value),
+            Some(JsToken::Punct('-')) => match self.next_token() {
+                Some(JsToken::Number(value)) => JsValue::Number(format!("-{value}")),
+                _ => self.unsupported(offset, "unary expression"),
+            },
+            Some(JsToken::Ident(ident)) => match ident.as_str() {
+                "true" => JsValue::Bool(true),
+                "false" => JsValue::Bool(false),
+                "null" | "undefined" => JsValue::Null,
+                _ => self.unsupported(offset, &format!("reference to `{ident}`")),
+            },
+            Some(JsToken::Punct('{')) => self.parse_object(),
+            Some(JsToken::Punct('[')) => self.parse_array(),
+            _ => self.unsupported(offset, "expression"),
+        };
+
+        // Anything other than a separator after the value (`+`, calls, `as const`, …)
+        // means the value is not a plain literal.
+        match self.peek_token() {
+            None | Some(JsToken::Punct(',' | '}' | ']')) => value,
+            Some(JsToken::Ident(ref ident)) if ident == "as" || ident == "satisfies" => {
+                self.skip_expression();
+                value
+            }
+            Some(_) => {
+                self.skip_expression();
+                self.unsupported(offset, "computed expression")
+            }
+        }
+    }
+
+    /// Skip tokens until a `,`, `}` or `]` at the current nesting depth.
+    fn skip_expression(&mut self) {
+        let mut depth = 0usize;
+
+        while let Some(token) = self.peek_token() {
+            match token {
+                JsToken::Punct('(' | '[' | '{') => depth += 1,
+                JsToken::Punct(')' | ']' | '}') if depth > 0 => depth -= 1,
+                JsToken::Punct(',' | '}' | ']') if depth == 0 => return,
+                JsToken::Punct(')') => return,
+                _ => {}
+            }
+            self.next_token();
+        }
+    }
+
+    fn skip_until_closing(&mut self, closing: char) {
+        let mut depth = 0usize;
+
+        while let Some(token) = self.next_token() {
+            match token {
+                JsToken::Punct('(' | '[' | '{') => depth += 1,
+                JsToken::Punct(ch) if ch == closing && depth == 0 => return,
+                JsToken::Punct(')' | ']' | '}') => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+
+    fn unsupported(&self, offset: usize, reason: &str) -> JsValue {
+        JsValue::Unsupported {
+            line: self.lexer.line_at(offset),
+            reason: reason.to_string(),
+        }
+    }
+
+    fn consume_punct(&mut self, punct: char) -> bool {
+        if self.peek_token() == Some(JsToken::Punct(punct)) {
+            self.next_token();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn consume_ident(&mut self, ident: &str) -> bool {
+        if self.peek_token() == Some(JsToken::Ident(ident.to_string())) {
+            self.next_token();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek_offset(&mut self) -> usize {
+        if self.lookahead.is_none() {
+            self.lookahead = self.lexer.next_token();
+        }
+        self.lookahead
+            .as_ref()
+            .map_or(self.lexer.pos, |(offset, _)| *offset)
+    }
+
+    fn peek_token(&mut self) -> Option<JsToken> {
+        if self.lookahead.is_none() {
+            self.lookahead = self.lexer.next_token();
+        }
+        self.lookahead.as_ref().map(|(_, token)| token.clone())
+    }
+
+    fn next_token(&mut self) -> Option<JsToken> {
+        self.lookahead
+            .take()
+            .or_else(|| self.lexer.next_token())
+            .map(|(_, token)| token)
+    }
+}
+
+/// Offset of the `{` opening the object a JavaScript/TypeScript locale module exports.
+pub(super) fn js_module_root(content: &str) -> Option<usize> {
+    let mut parser = JsModuleParser::new(content);
+    parser.parse_module().ok()?;
+    parser.root
+}
+
+fn flatten_js(
+    value: &JsValue,
+    prefix: String,
+    result: &mut HashMap<String, String>,
+    warnings: &mut Vec<String>,
+) {
+    match value {
+        JsValue::String(value) | JsValue::Number(value) => {
+            if !prefix.is_empty() {
+                result.insert(prefix, value.clone());
+            }
+        }
+        JsValue::Bool(value) => {
+            if !prefix.is_empty() {
+                result.insert(prefix, value.to_string());
+            }
+        }
+        JsValue::Null => {}
+        JsValue::Object(entries) => {
+            for (key, entry) in entries {
+                let new_prefix = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_js(entry, new_prefix, result, warnings);
+            }
+        }
+        JsValue::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten_js(item, format!("{}.{}", prefix, i), result, warnings);
+            }
+        }
+        JsValue::Unsupported { line, reason } => {
+            let key = prefix.split('@').next().unwrap_or(&prefix);
+            warnings.push(format!(
+                "skipped `{}` at line {}: cannot evaluate {}",
+                key, line, reason
+            ));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -562,6 +1617,23 @@ mod tests {
         assert_eq!(result.get("common.bye"), Some(&"Goodbye".to_string()));
     }
 
+    #[test]
+    fn test_parse_nested_toml() {
+        let toml = r#"
+hello = "Hello"
+
+[common]
+bye = "Goodbye"
+
+[common.buttons]
+save = "Save"
+"#;
+        let result = TranslationParser::parse_toml(toml).unwrap();
+        assert_eq!(result.get("hello"), Some(&"Hello".to_string()));
+        assert_eq!(result.get("common.bye"), Some(&"Goodbye".to_string()));
+        assert_eq!(result.get("common.buttons.save"), Some(&"Save".to_string()));
+    }
+
     #[test]
     fn test_parse_flat_php() {
         let php = r#"<?php return ['hello' => 'Hello', "world" => "World"];"#;
@@ -631,4 +1703,192 @@ mod tests {
         );
         assert!(!result.contains_key("@itemCount"));
     }
+
+    #[test]
+    fn test_parse_properties_separators_and_comments() {
+        let properties = "# comment\n! another\nhello=Hello\ncolon : Colon\nspace Space value\n";
+        let result = TranslationParser::parse_properties(properties).unwrap();
+        assert_eq!(result.get("hello"), Some(&"Hello".to_string()));
+        assert_eq!(result.get("colon"), Some(&"Colon".to_string()));
+        assert_eq!(result.get("space"), Some(&"Space value".to_string()));
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_properties_escapes_and_continuations() {
+        let properties = r"email.body=Xin ch\u00e0o {0},\
+    b\u1EA1n c\u00f3 {1} tin nh\u1EAFn\n
+path\:key=a\=b
+emoji=\uD83D\uDE00";
+        let result = TranslationParser::parse_properties(properties).unwrap();
+        assert_eq!(
+            result.get("email.body"),
+            Some(&"Xin chào {0},bạn có {1} tin nhắn\n".to_string())
+        );
+        assert_eq!(result.get("path:key"), Some(&"a=b".to_string()));
+        assert_eq!(result.get("emoji"), Some(&"😀".to_string()));
+    }
+
+    #[test]
+    fn test_parse_resx_string_resources() {
+        let resx = r#"<?xml version="1.0" encoding="utf-8"?>
+<root>
+  <!--
+    <data name="Example"><value>ignored</value></data>
+  -->
+  <resheader name="resmimetype"><value>text/microsoft-resx</value></resheader>
+  <data name="Greeting" xml:space="preserve">
+    <value>Hello {0} &amp; welcome</value>
+    <comment>Shown on the dashboard</comment>
+  </data>
+  <data name="Empty" xml:space="preserve"><value /></data>
+  <data name="Icon" type="System.Drawing.Bitmap, System.Drawing" mimetype="application/x-microsoft.net.object.bytearray.base64">
+    <value>AAAA</value>
+  </data>
+</root>"#;
+        let result = TranslationParser::parse_resx(resx).unwrap();
+        assert_eq!(
+            result.get("Greeting"),
+            Some(&"Hello {0} & welcome".to_string())
+        );
+        assert_eq!(result.get("Empty"), Some(&String::new()));
+        assert!(!result.contains_key("Example"));
+        assert!(!result.contains_key("Icon"));
+        assert!(!result.contains_key("resmimetype"));
+    }
+
+    #[test]
+    fn test_parse_js_module_export_default() {
+        let js = r#"
+// English messages
+export default {
+  common: {
+    greeting: 'Hello {name}',
+    "farewell": "Goodbye\n",
+    retry: `Try again`,
+  },
+  items: ['One', 'Two'],
+  count: 3,
+  enabled: true,
+};
+"#;
+        let result = TranslationParser::parse_js_module(js).unwrap();
+        assert_eq!(
+            result.get("common.greeting"),
+            Some(&"Hello {name}".to_string())
+        );
+        assert_eq!(
+            result.get("common.farewell"),
+            Some(&"Goodbye\n".to_string())
+        );
+        assert_eq!(result.get("common.retry"), Some(&"Try again".to_string()));
+        assert_eq!(result.get("items.1"), Some(&"Two".to_string()));
+        assert_eq!(result.get("count"), Some(&"3".to_string()));
+        assert_eq!(result.get("enabled"), Some(&"true".to_string()));
+    }
+
+    #[test]
+    fn test_parse_js_module_exports_and_typed_constants() {
+        let cjs = "module.exports = { title: 'Dashboard' };";
+        let result = TranslationParser::parse_js_module(cjs).unwrap();
+        assert_eq!(result.get("title"), Some(&"Dashboard".to_string()));
+
+        let ts = r#"
+import type { Messages } from './types';
+
+const messages: Messages = {
+  nav: { home: 'Home' },
+} as const;
+
+export default messages;
+"#;
+        let result = TranslationParser::parse_js_module(ts).unwrap();
+        assert_eq!(result.get("nav.home"), Some(&"Home".to_string()));
+
+        let wrapped = "export default defineI18nLocale({ save: 'Save' });";
+        let result = TranslationParser::parse_js_module(wrapped).unwrap();
+        assert_eq!(result.get("save"), Some(&"Save".to_string()));
+
+        assert!(TranslationParser::parse_js_module("const a = 1;").is_err());
+    }
+
+    #[test]
+    fn test_parse_js_module_skips_computed_parts_with_warnings() {
+        let js = r#"export default {
+  ...shared,
+  [KEY]: 'computed',
+  brand,
+  welcome: `Hi ${user}`,
+  joined: 'a' + 'b',
+  label() { return 'x'; },
+  ok: 'Kept',
+};"#;
+        let (result, warnings) = TranslationParser::parse_js_module_with_warnings(js).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.get("ok"), Some(&"Kept".to_string()));
+        assert_eq!(warnings.len(), 6);
+        assert!(warnings.iter().any(|w| w.contains("`welcome` at line 5")));
+        assert!(warnings.iter().any(|w| w.contains("spread element")));
+    }
+
+    #[test]
+    fn test_parse_vue_i18n_blocks() {
+        let sfc = r#"<template>
+  <i18n-t keypath="cart.total" tag="p" />
+  <h1>{{ t('title') }}</h1>
+</template>
+
+<i18n>
+{
+  "en": { "title": "Cart", "items": { "empty": "No items" } },
+  "vi": { "title": "Giỏ hàng" }
+}
+</i18n>
+
+<i18n lang="yaml" locale="fr">
+title: Panier
+</i18n>
+
+<i18n src="./messages.json"></i18n>
+"#;
+        let result = TranslationParser::parse_vue_i18n_blocks(sfc).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result["en"].get("title"), Some(&"Cart".to_string()));
+        assert_eq!(
+            result["en"].get("items.empty"),
+            Some(&"No items".to_string())
+        );
+        assert_eq!(result["vi"].get("title"), Some(&"Giỏ hàng".to_string()));
+        assert_eq!(result["fr"].get("title"), Some(&"Panier".to_string()));
+
+        let plain = "<template><p>{{ t('title') }}</p></template>";
+        assert!(TranslationParser::parse_vue_i18n_blocks(plain)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_json_accepts_comments_and_trailing_commas() {
+        let jsonc = r#"{
+  // Navigation
+  "nav": {
+    home: 'Home', /* unquoted key */
+  },
+}"#;
+        let result = TranslationParser::parse_json(jsonc).unwrap();
+        assert_eq!(result.get("nav.home"), Some(&"Home".to_string()));
+        assert!(TranslationParser::parse_json("{ \"a\": }").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_literal_keeps_sentence_keys() {
+        let json = r#"{"Log in.": "Đăng nhập.", "Welcome, :name": "Chào :name", "count": 3}"#;
+        let result = TranslationParser::parse_json_literal(json).unwrap();
+        assert_eq!(result.get("Log in."), Some(&"Đăng nhập.".to_string()));
+        assert_eq!(
+            result.get("Welcome, :name"),
+            Some(&"Chào :name".to_string())
+        );
+        assert_eq!(result.get("count"), Some(&"3".to_string()));
+    }
 }
@@ -0,0 +1,78 @@
+//! CLDR cardinal plural categories per language, bundled so audits work offline.
+//!
+//! Data follows the CLDR 44 `plurals.xml` cardinal rules. Only the category names are needed to
+//! check completeness, so the rule expressions themselves are not included.
+
+const OTHER: &[&str] = &["other"];
+const ONE_OTHER: &[&str] = &["one", "other"];
+const ONE_MANY_OTHER: &[&str] = &["one", "many", "other"];
+const ZERO_ONE_OTHER: &[&str] = &["zero", "one", "other"];
+const ONE_TWO_OTHER: &[&str] = &["one", "two", "other"];
+const ONE_FEW_OTHER: &[&str] = &["one", "few", "other"];
+const ONE_TWO_FEW_OTHER: &[&str] = &["one", "two", "few", "other"];
+const ONE_FEW_MANY_OTHER: &[&str] = &["one", "few", "many", "other"];
+const ONE_TWO_FEW_MANY_OTHER: &[&str] = &["one", "two", "few", "many", "other"];
+
+/// Every category name CLDR uses, in canonical order.
+pub const CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
+/// The cardinal plural categories a translation for `locale` must provide, or `None` when the
+/// language is not in the bundled table. Accepts tags such as `pt-BR`, `zh_Hant` or `sr-Latn`.
+pub fn plural_categories(locale: &str) -> Option<&'static [&'static str]> {
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_ascii_lowercase();
+
+    let categories = match language.as_str() {
+        "bm" | "bo" | "dz" | "hnj" | "id" | "ig" | "ii" | "in" | "ja" | "jbo" | "jv" | "jw"
+        | "kde" | "kea" | "km" | "ko" | "lkt" | "lo" | "ms" | "my" | "nqo" | "osa" | "sah"
+        | "ses" | "sg" | "su" | "th" | "to" | "tpi" | "vi" | "wo" | "yo" | "yue" | "zh" => OTHER,
+        "af" | "ak" | "am" | "an" | "as" | "asa" | "ast" | "az" | "bal" | "bem" | "bez" | "bg"
+        | "bho" | "bn" | "brx" | "ce" | "ceb" | "cgg" | "chr" | "ckb" | "csw" | "da" | "de"
+        | "doi" | "dv" | "ee" | "el" | "en" | "eo" | "et" | "eu" | "fa" | "ff" | "fi" | "fil"
+        | "fo" | "fur" | "fy" | "gl" | "gsw" | "gu" | "guw" | "ha" | "haw" | "hi" | "hu" | "hy"
+        | "ia" | "io" | "is" | "ji" | "jgo" | "jmc" | "ka" | "kab" | "kaj" | "kcg" | "kk"
+        | "kkj" | "kl" | "kn" | "ks" | "ksb" | "ku" | "ky" | "lb" | "lg" | "lij" | "ln" | "mas"
+        | "mg" | "mgo" | "mk" | "ml" | "mn" | "mr" | "nah" | "nb" | "nd" | "ne" | "nl" | "nn"
+        | "nnh" | "no" | "nr" | "nso" | "ny" | "nyn" | "om" | "or" | "os" | "pa" | "pap"
+        | "pcm" | "ps" | "rm" | "rof" | "rwk" | "saq" | "sc" | "sd" | "sdh" | "seh" | "si"
+        | "sn" | "so" | "sq" | "ss" | "ssy" | "st" | "sv" | "sw" | "syr" | "ta" | "te" | "teo"
+        | "ti" | "tig" | "tk" | "tl" | "tn" | "tr" | "ts" | "tzm" | "ug" | "ur" | "uz" | "ve"
+        | "vo" | "vun" | "wa" | "wae" | "xh" | "xog" | "yi" | "zu" => ONE_OTHER,
+        "ca" | "es" | "fr" | "it" | "lld" | "pt" | "scn" | "vec" => ONE_MANY_OTHER,
+        "ksh" | "lag" | "lv" | "prg" => ZERO_ONE_OTHER,
+        "he" | "iu" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms" => ONE_TWO_OTHER,
+        "bs" | "hr" | "mo" | "ro" | "sh" | "shi" | "sr" => ONE_FEW_OTHER,
+        "dsb" | "gd" | "hsb" | "sl" => ONE_TWO_FEW_OTHER,
+        "be" | "cs" | "lt" | "pl" | "ru" | "sk" | "uk" => ONE_FEW_MANY_OTHER,
+        "br" | "ga" | "gv" | "mt" => ONE_TWO_FEW_MANY_OTHER,
+        "ar" | "ars" | "cy" | "kw" => CATEGORIES,
+        _ => return None,
+    };
+
+    Some(categories)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_categories_from_locale_tags() {
+        assert_eq!(
+            plural_categories("ru"),
+            Some(&["one", "few", "many", "other"][..])
+        );
+        assert_eq!(
+            plural_categories("pl_PL"),
+            Some(&["one", "few", "many", "other"][..])
+        );
+        assert_eq!(plural_categories("ja"), Some(&["other"][..]));
+        assert_eq!(plural_categories("zh-Hant"), Some(&["other"][..]));
+        assert_eq!(plural_categories("EN-us"), Some(&["one", "other"][..]));
+        assert_eq!(plural_categories("ar").map(<[_]>::len), Some(6));
+        assert_eq!(plural_categories("xx"), None);
+    }
+}
@@ -1,41 +1,186 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use globset::Glob;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use walkdir::WalkDir;
 
+use crate::cache::IndexCache;
+use crate::walker::WorkspaceWalker;
+
+use super::locator::{self, LineIndex, TextSpan};
+use super::namespace::Namespaces;
 use super::parser::TranslationParser;
+use super::plural::CATEGORIES;
+use super::suppression::take_ignore_markers;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationEntry {
     pub value: String,
     pub file_path: PathBuf,
+    /// Where the key is written in `file_path`: its last segment, without quotes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_span: Option<TextSpan>,
+    /// Where the value is written: the whole literal, quotes included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_span: Option<TextSpan>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationLocation {
     pub file_path: PathBuf,
     pub line: usize,
+    #[serde(default)]
+    pub start_char: usize,
+    #[serde(default)]
+    pub end_char: usize,
+}
+
+impl TranslationEntry {
+    /// Where the key is written, or the start of the file when its span is unknown.
+    pub fn location(&self) -> TranslationLocation {
+        let span = self.key_span.unwrap_or_default();
+        TranslationLocation {
+            file_path: self.file_path.clone(),
+            line: span.line,
+            start_char: span.start_char,
+            end_char: span.end_char,
+        }
+    }
+}
+
+/// Which i18next suffixes make a stored key a variant of the key a call looks up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variants {
+    /// Plural forms, which `t("item", { count })` resolves: `item_one`, `item_ordinal_few`.
+    Plural,
+    /// Plural forms and contexts, which `t("friend", { context })` resolves: `friend_male`,
+    /// `friend_male_one`.
+    Context,
+}
+
+impl Variants {
+    /// The variants a call resolves, depending on whether it passes a `context` option.
+    pub fn for_call(context: bool) -> Self {
+        if context {
+            Self::Context
+        } else {
+            Self::Plural
+        }
+    }
+}
+
+/// A stored key, indexed under the key it is a variant of.
+#[derive(Debug, Clone)]
+struct Variant {
+    key: String,
+    /// Whether the suffix includes a context, which only `t(key, { context })` resolves.
+    context: bool,
+}
+
+/// A key that more than one locale file defines for the same locale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyConflict {
+    pub key: String,
+    pub locale: String,
+    /// Every definition in load order; the last one is the value the store resolves.
+    pub definitions: Vec<TranslationEntry>,
+}
+
+impl KeyConflict {
+    /// Whether every definition has the same value, so only the duplication itself is wrong.
+    pub fn has_same_value(&self) -> bool {
+        self.definitions
+            .windows(2)
+            .all(|pair| pair[0].value == pair[1].value)
+    }
 }
 
 pub struct TranslationStore {
     translations: DashMap<String, HashMap<String, TranslationEntry>>,
     locale_files: DashMap<String, HashSet<PathBuf>>,
+    /// i18next variants by locale, then by the key they are variants of.
+    variants: DashMap<String, HashMap<String, Vec<Variant>>>,
+    /// Keys defined by more than one file, keyed by locale then key, with every definition.
+    duplicates: DashMap<String, HashMap<String, Vec<TranslationEntry>>>,
+    /// Component-scoped messages from Vue `<i18n>` blocks, keyed by component path then locale.
+    component_translations: DashMap<PathBuf, HashMap<String, HashMap<String, TranslationEntry>>>,
+    /// Keys excluded from checks by `"@i18nlens-ignore"` markers, together with their subtrees.
+    ignored_keys: DashSet<String>,
+    literal_key_paths: Vec<String>,
+    namespaces: Option<Namespaces>,
+    /// Locale of base bundles without a locale in their name, such as `messages.properties`.
+    source_locale: Option<String>,
+    walker: WorkspaceWalker,
+    cache: Option<Arc<IndexCache>>,
     workspace_root: PathBuf,
 }
 
+/// A parsed locale file, merged into the store after all files are parsed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct LoadedFile {
+    path: PathBuf,
+    /// Entries by locale; a Rails-style YAML file may hold several locales.
+    locales: Vec<(String, HashMap<String, TranslationEntry>)>,
+    ignored_keys: Vec<String>,
+}
+
 impl TranslationStore {
     pub fn new(workspace_root: PathBuf) -> Self {
         Self {
             translations: DashMap::new(),
             locale_files: DashMap::new(),
+            variants: DashMap::new(),
+            duplicates: DashMap::new(),
+            component_translations: DashMap::new(),
+            ignored_keys: DashSet::new(),
+            literal_key_paths: Vec::new(),
+            namespaces: None,
+            source_locale: None,
+            walker: WorkspaceWalker::new(&workspace_root),
+            cache: None,
             workspace_root,
         }
     }
 
+    /// Treat JSON files under these locale paths as keyed by literal strings
+    /// (see `I18nConfig::literal_key_paths`).
+    pub fn with_literal_key_paths(mut self, paths: &[String]) -> Self {
+        self.literal_key_paths = paths.to_vec();
+        self
+    }
+
+    /// Prefix keys with the namespace of the file they come from (`common:button.save`).
+    pub fn with_namespaces(mut self, namespaces: Option<Namespaces>) -> Self {
+        self.namespaces = namespaces;
+        self
+    }
+
+    /// Load Java and .NET base bundles without a locale in their name (`messages.properties`,
+    /// `Strings.resx`) as this locale (see `I18nConfig::source_locale`).
+    pub fn with_source_locale(mut self, locale: &str) -> Self {
+        self.source_locale = Some(locale.to_string());
+        self
+    }
+
+    /// Skip locale files matching these globs when expanding glob locale paths
+    /// (see `I18nConfig::exclude`).
+    pub fn with_exclude(mut self, patterns: &[String]) -> Self {
+        self.walker = self.walker.with_exclude(patterns);
+        self
+    }
+
+    /// Reuse parsed locale files from the index cache while they are unchanged.
+    pub fn with_cache(mut self, cache: Option<Arc<IndexCache>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
     pub fn scan_and_load(&self, locale_paths: &[String]) {
+        let mut files = Vec::new();
         for locale_path in locale_paths {
             let trimmed = locale_path.trim_end_matches(['/', '\\']);
             if trimmed.is_empty() {
@@ -43,32 +188,54 @@ impl TranslationStore {
             }
 
             if has_glob_meta(trimmed) {
-                self.scan_glob_path(trimmed);
+                self.collect_glob_path(trimmed, &mut files);
                 continue;
             }
 
             let full_path = self.workspace_root.join(trimmed);
             if full_path.is_file() {
-                self.scan_file(&full_path);
+                files.push(full_path);
             } else if full_path.exists() {
-                self.scan_directory(&full_path);
+                collect_locale_directory(&full_path, &mut files);
+            }
+        }
+
+        // A glob can overlap a directory path; load each file once.
+        let mut seen = HashSet::new();
+        files.retain(|path| seen.insert(path.clone()));
+
+        // Parse on all cores, then merge in discovery order so the same file wins a duplicate
+        // key on every run.
+        let loaded: Vec<LoadedFile> = files
+            .par_iter()
+            .filter_map(|path| match &self.cache {
+                Some(cache) => cache.locale_file(path, || self.parse_locale_file(path)),
+                None => self.parse_locale_file(path),
+            })
+            .collect();
+        for file in loaded {
+            self.insert_loaded_file(file);
+        }
+
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache.save() {
+                tracing::warn!("Failed to save index cache: {}", e);
             }
         }
     }
 
-    fn scan_glob_path(&self, locale_path: &str) {
+    pub fn cache(&self) -> Option<&Arc<IndexCache>> {
+        self.cache.as_ref()
+    }
+
+    fn collect_glob_path(&self, locale_path: &str, files: &mut Vec<PathBuf>) {
         let Ok(glob) = Glob::new(locale_path) else {
             tracing::warn!("Invalid locale path glob: {}", locale_path);
             return;
         };
         let matcher = glob.compile_matcher();
 
-        for entry in WalkDir::new(&self.workspace_root)
-            .into_iter()
-            .filter_entry(|entry| !is_ignored_workspace_dir(entry.path()))
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
+        for path in self.walker.walk(&self.workspace_root) {
             let Ok(relative_path) = path.strip_prefix(&self.workspace_root) else {
                 continue;
             };
@@ -78,45 +245,170 @@ impl TranslationStore {
             }
 
             if path.is_dir() {
-                self.scan_directory(path);
+                collect_locale_directory(&path, files);
             } else if path.is_file() {
-                self.scan_file(path);
+                files.push(path);
             }
         }
     }
 
-    fn scan_directory(&self, dir: &Path) {
-        let json_glob = Glob::new("*.json").unwrap().compile_matcher();
-        let yaml_glob = Glob::new("*.{yaml,yml}").unwrap().compile_matcher();
-        let php_glob = Glob::new("*.php").unwrap().compile_matcher();
-        let arb_glob = Glob::new("*.arb").unwrap().compile_matcher();
+    fn parse_locale_file(&self, path: &Path) -> Option<LoadedFile> {
+        let locale = self.extract_locale_from_path(path);
+        let is_yaml = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml" | "yml")
+        );
+        if locale.is_none() && !is_yaml {
+            return None;
+        }
 
-        for entry in WalkDir::new(dir)
-            .max_depth(3)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-            let file_name = path.file_name().unwrap_or_default();
-
-            if path.is_file()
-                && (json_glob.is_match(file_name)
-                    || yaml_glob.is_match(file_name)
-                    || php_glob.is_match(file_name)
-                    || arb_glob.is_match(file_name))
-            {
-                self.scan_file(path);
+        let parsed = std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| {
+                let translations =
+                    if uses_literal_keys(&self.workspace_root, path, &self.literal_key_paths) {
+                        TranslationParser::parse_json_literal(&content)?
+                    } else {
+                        TranslationParser::parse_file_content(path, &content)?
+                    };
+                Ok((content, translations))
+            });
+        let (content, mut translations) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                tracing::warn!("Failed to parse {:?}: {}", path, e);
+                return None;
             }
+        };
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let mut located = locator::locate_keys(&content, extension);
+        if !locator::agrees_with(&located, &content, translations.keys()) {
+            tracing::debug!(
+                "Key positions in {:?} disagree with its keys, dropping them",
+                path
+            );
+            located.clear();
         }
+        let ignored = take_ignore_markers(&mut translations);
+
+        let lines = LineIndex::new(&content);
+        let translations: HashMap<String, TranslationEntry> = translations
+            .into_iter()
+            .map(|(key, value)| {
+                let found = locator::nearest(&located, &key);
+                let entry = TranslationEntry {
+                    value,
+                    file_path: path.to_path_buf(),
+                    key_span: found.map(|found| lines.span(&found.key)),
+                    value_span: found.map(|found| lines.span(&found.value)),
+                };
+                (key, entry)
+            })
+            .collect();
+
+        let translations = if is_yaml {
+            match split_locale_roots(translations, locale.as_deref()) {
+                Ok(by_locale) => {
+                    let mut ignored_keys = Vec::new();
+                    for key in ignored {
+                        match key.split_once('.') {
+                            Some((_, rest)) => ignored_keys.push(rest.to_string()),
+                            // A marker directly under the locale root covers the whole locale.
+                            None => ignored_keys.extend(
+                                by_locale
+                                    .get(&key)
+                                    .into_iter()
+                                    .flat_map(|entries| entries.keys().cloned()),
+                            ),
+                        }
+                    }
+
+                    return Some(LoadedFile {
+                        path: path.to_path_buf(),
+                        locales: by_locale.into_iter().collect(),
+                        ignored_keys,
+                    });
+                }
+                Err(translations) => translations,
+            }
+        } else {
+            translations
+        };
+
+        let locale = locale?;
+        let file_stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let prefix = if extension == "php" && !file_stem.is_empty() && !is_locale_code(file_stem) {
+            Some(file_stem)
+        } else {
+            None
+        };
+        let namespace = self
+            .namespaces
+            .as_ref()
+            .map(|namespaces| (namespaces, namespaces.namespace_for_file(path, &locale)));
+        let qualify = |key: String| {
+            let full_key = match prefix {
+                Some(prefix) => format!("{}.{}", prefix, key),
+                None => key,
+            };
+            match namespace {
+                Some((namespaces, namespace)) => namespaces.qualify(namespace, &full_key),
+                None => full_key,
+            }
+        };
+
+        let entries = translations
+            .into_iter()
+            .map(|(key, entry)| (qualify(key), entry))
+            .collect();
+        Some(LoadedFile {
+            path: path.to_path_buf(),
+            locales: vec![(locale, entries)],
+            ignored_keys: ignored.into_iter().map(qualify).collect(),
+        })
     }
 
-    fn scan_file(&self, path: &Path) {
-        if let Some(locale) = self.extract_locale_from_path(path) {
+    fn insert_loaded_file(&self, file: LoadedFile) {
+        for key in file.ignored_keys {
+            self.ignored_keys.insert(key);
+        }
+
+        for (locale, entries) in file.locales {
             self.locale_files
                 .entry(locale.clone())
                 .or_default()
-                .insert(path.to_path_buf());
-            self.load_translation_file(path, &locale);
+                .insert(file.path.clone());
+            let mut locale_map = self.translations.entry(locale.clone()).or_default();
+            let mut locale_variants = self.variants.entry(locale.clone()).or_default();
+            for (key, entry) in entries {
+                match locale_map.get(&key) {
+                    Some(previous) if previous.file_path != entry.file_path => {
+                        self.duplicates
+                            .entry(locale.clone())
+                            .or_default()
+                            .entry(key.clone())
+                            .or_insert_with(|| vec![previous.clone()])
+                            .push(entry.clone());
+                    }
+                    Some(_) => {}
+                    None => {
+                        for (base, variant) in variant_bases(&key) {
+                            locale_variants
+                                .entry(base.to_string())
+                                .or_default()
+                                .push(variant);
+                        }
+                    }
+                }
+                locale_map.insert(key, entry);
+            }
+
+            tracing::debug!(
+                "Loaded {} translations from {:?} for locale {}",
+                locale_map.len(),
+                file.path,
+                locale
+            );
         }
     }
 
@@ -127,13 +419,20 @@ impl TranslationStore {
             return Some(file_stem.to_string());
         }
 
-        // Handle ARB naming convention: app_en.arb, app_es.arb, etc.
+        // Handle ARB and Java resource bundle naming: app_en.arb, messages_vi.properties, etc.
+        // and dotted naming: Strings.vi.resx, devise.en.yml
         if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
-            if ext == "arb" {
-                // Try to extract locale from patterns like "app_en" or "messages_en_US"
-                if let Some(locale) = extract_locale_from_arb_filename(file_stem) {
-                    return Some(locale);
-                }
+            let locale = match ext {
+                "arb" => extract_locale_from_suffixed_filename(file_stem),
+                "properties" => extract_locale_from_suffixed_filename(file_stem)
+                    .or_else(|| self.base_bundle_locale(path, file_stem, '_')),
+                "resx" => extract_locale_from_dotted_filename(file_stem)
+                    .or_else(|| self.base_bundle_locale(path, file_stem, '.')),
+                "yaml" | "yml" => extract_locale_from_dotted_filename(file_stem),
+                _ => None,
+            };
+            if locale.is_some() {
+                return locale;
             }
         }
 
@@ -148,51 +447,156 @@ impl TranslationStore {
         None
     }
 
-    fn load_translation_file(&self, path: &Path, locale: &str) {
-        match TranslationParser::parse_file(path) {
-            Ok(translations) => {
-                let mut locale_map = self.translations.entry(locale.to_string()).or_default();
-                let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-                let file_stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
-                let prefix =
-                    if extension == "php" && !file_stem.is_empty() && !is_locale_code(file_stem) {
-                        Some(file_stem)
-                    } else {
-                        None
-                    };
+    /// The locale of a base bundle such as `messages.properties`: the source locale, unless a
+    /// bundle for the source locale (`messages_en.properties`) sits next to it.
+    fn base_bundle_locale(&self, path: &Path, file_stem: &str, separator: char) -> Option<String> {
+        let Some(source) = self.source_locale.as_deref() else {
+            tracing::warn!("Skipping {:?}: its name has no locale", path);
+            return None;
+        };
+        let extension = path.extension()?.to_str()?;
+        let has_source_bundle =
+            [source.to_string(), source.replace('-', "_")]
+                .iter()
+                .any(|locale| {
+                    path.with_file_name(format!("{file_stem}{separator}{locale}.{extension}"))
+                        .exists()
+                });
+        if has_source_bundle {
+            tracing::warn!(
+                "Skipping base bundle {:?}: a bundle for the source locale {} sits next to it",
+                path,
+                source
+            );
+            return None;
+        }
+        Some(source.to_string())
+    }
 
-                for (key, value) in translations {
-                    let full_key = match prefix {
-                        Some(prefix) => format!("{}.{}", prefix, key),
-                        None => key,
-                    };
+    /// Register the `<i18n>` blocks of a Vue single-file component so keys used inside that
+    /// component resolve against its local messages before the global locale files.
+    pub fn load_component_translations(&self, component: &Path, content: &str) {
+        let blocks = match TranslationParser::parse_vue_i18n_blocks(content) {
+            Ok(blocks) => blocks,
+            Err(e) => {
+                tracing::warn!("Failed to parse <i18n> blocks in {:?}: {}", component, e);
+                HashMap::new()
+            }
+        };
+
+        if blocks.is_empty() {
+            self.component_translations.remove(component);
+            return;
+        }
 
-                    locale_map.insert(
-                        full_key,
-                        TranslationEntry {
+        let messages = blocks
+            .into_iter()
+            .map(|(locale, translations)| {
+                let entries = translations
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let entry = TranslationEntry {
                             value,
-                            file_path: path.to_path_buf(),
-                        },
-                    );
-                }
+                            file_path: component.to_path_buf(),
+                            key_span: None,
+                            value_span: None,
+                        };
+                        (key, entry)
+                    })
+                    .collect();
+                (locale, entries)
+            })
+            .collect();
+
+        self.component_translations
+            .insert(component.to_path_buf(), messages);
+    }
+
+    /// Like [`Self::get_translation`], but checks the component's own `<i18n>` messages first.
+    pub fn get_component_translation(
+        &self,
+        component: Option<&Path>,
+        key: &str,
+        locale: &str,
+    ) -> Option<String> {
+        component
+            .and_then(|path| self.component_translations.get(path))
+            .and_then(|messages| {
+                messages
+                    .get(locale)
+                    .and_then(|map| map.get(key).map(|e| e.value.clone()))
+            })
+            .or_else(|| self.get_translation(key, locale))
+    }
 
-                tracing::debug!(
-                    "Loaded {} translations from {:?} for locale {}",
-                    locale_map.len(),
-                    path,
-                    locale
-                );
+    /// Like [`Self::get_all_translations`], with the component's own messages taking precedence.
+    pub fn get_all_component_translations(
+        &self,
+        component: Option<&Path>,
+        key: &str,
+    ) -> HashMap<String, TranslationEntry> {
+        let mut result = self.get_all_translations(key);
+        if let Some(messages) = component.and_then(|path| self.component_translations.get(path)) {
+            for (locale, translations) in messages.value() {
+                if let Some(entry) = translations.get(key) {
+                    result.insert(locale.clone(), entry.clone());
+                }
             }
-            Err(e) => {
-                tracing::warn!("Failed to parse {:?}: {}", path, e);
+        }
+        result
+    }
+
+    pub fn component_key_exists(
+        &self,
+        component: Option<&Path>,
+        key: &str,
+        lookup: Variants,
+    ) -> bool {
+        component
+            .and_then(|path| self.component_translations.get(path))
+            .is_some_and(|messages| messages.values().any(|map| map.contains_key(key)))
+            || self.key_exists(key, lookup)
+    }
+
+    /// Locales (global or component-local) in which `key` is defined neither by the component
+    /// nor by the global locale files.
+    pub fn get_component_missing_locales(
+        &self,
+        component: Option<&Path>,
+        key: &str,
+        lookup: Variants,
+    ) -> Vec<String> {
+        let Some(messages) = component.and_then(|path| self.component_translations.get(path))
+        else {
+            return self.get_missing_locales(key, lookup);
+        };
+
+        let mut locales: Vec<String> = self.get_locales();
+        for locale in messages.keys() {
+            if !locales.contains(locale) {
+                locales.push(locale.clone());
             }
         }
+
+        locales
+            .into_iter()
+            .filter(|locale| {
+                let local = messages
+                    .get(locale)
+                    .is_some_and(|map| map.contains_key(key));
+                !local && !self.locale_defines(locale, key, lookup)
+            })
+            .collect()
     }
 
+    /// The value of `key` in `locale`. Keys only defined through i18next plural suffixes
+    /// resolve to their `_other` form (or the first variant), which is what `t(key, { count })`
+    /// shows.
     pub fn get_translation(&self, key: &str, locale: &str) -> Option<String> {
-        self.translations
-            .get(locale)
-            .and_then(|map| map.get(key).map(|e| e.value.clone()))
+        self.translations.get(locale).and_then(|map| {
+            let variants = self.variants.get(locale);
+            resolve_entry(&map, variants.as_deref(), key).map(|e| e.value.clone())
+        })
     }
 
     pub fn get_all_translations(&self, key: &str) -> HashMap<String, TranslationEntry> {
@@ -207,39 +611,64 @@ impl TranslationStore {
     }
 
     pub fn get_translation_location(&self, key: &str, locale: &str) -> Option<TranslationLocation> {
-        self.translations.get(locale).and_then(|map| {
-            map.get(key).map(|e| {
-                let line = Self::find_key_line_in_file(&e.file_path, key).unwrap_or(0);
-                TranslationLocation {
-                    file_path: e.file_path.clone(),
-                    line,
-                }
-            })
-        })
+        self.translations
+            .get(locale)
+            .and_then(|map| map.get(key).map(TranslationEntry::location))
     }
 
-    fn find_key_line_in_file(file_path: &Path, key: &str) -> Option<usize> {
-        let content = std::fs::read_to_string(file_path).ok()?;
+    /// Keys defined by more than one locale file for the same locale, such as `en.json` and
+    /// `en/common.json`, sorted by locale and key. Only the last definition loaded is used.
+    pub fn get_key_conflicts(&self) -> Vec<KeyConflict> {
+        let mut conflicts: Vec<KeyConflict> = self
+            .duplicates
+            .iter()
+            .flat_map(|entry| {
+                let locale = entry.key().clone();
+                entry
+                    .value()
+                    .iter()
+                    .map(|(key, definitions)| {
+                        let mut definitions = definitions.clone();
+                        definitions.sort_by_key(|definition| {
+                            let location = definition.location();
+                            (location.file_path, location.line, location.start_char)
+                        });
+                        KeyConflict {
+                            key: key.clone(),
+                            locale: locale.clone(),
+                            definitions,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        conflicts.sort_by(|a, b| (&a.locale, &a.key).cmp(&(&b.locale, &b.key)));
+        conflicts
+    }
 
-        let last_part = key.split('.').next_back().unwrap_or(key);
-        let search_patterns = [
-            format!("\"{}\"", last_part),
-            format!("'{}'", last_part),
-            format!("{}: ", last_part),
-            format!("{}:", last_part),
-        ];
+    /// Whether a `"@i18nlens-ignore"` marker covers the key, one of its parent objects or its
+    /// plural group.
+    pub fn is_ignored_key(&self, key: &str) -> bool {
+        if self.ignored_keys.is_empty() {
+            return false;
+        }
+        if plural_group_key(key).is_some_and(|group| self.ignored_keys.contains(group)) {
+            return true;
+        }
 
-        for (line_num, line) in content.lines().enumerate() {
-            for pattern in &search_patterns {
-                if line.contains(pattern) {
-                    return Some(line_num);
-                }
+        let mut scope = key;
+        loop {
+            if self.ignored_keys.contains(scope) {
+                return true;
+            }
+            match scope.rsplit_once('.') {
+                Some((parent, _)) => scope = parent,
+                None => return false,
             }
         }
-
-        None
     }
 
+    /// Every key defined in any locale, sorted.
     pub fn get_all_keys(&self) -> Vec<String> {
         let mut keys = std::collections::HashSet::new();
         for entry in self.translations.iter() {
@@ -247,17 +676,65 @@ impl TranslationStore {
                 keys.insert(key.clone());
             }
         }
-        keys.into_iter().collect()
+        let mut keys: Vec<String> = keys.into_iter().collect();
+        keys.sort();
+        keys
     }
 
+    /// Keys defined in any locale that start with `prefix`, such as the keys a template key
+    /// `` t(`status.${s}`) `` can resolve to.
+    pub fn get_keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        self.get_all_keys()
+            .into_iter()
+            .filter(|key| key.starts_with(prefix))
+            .collect()
+    }
+
+    /// The loaded locales, sorted.
     pub fn get_locales(&self) -> Vec<String> {
-        self.translations.iter().map(|e| e.key().clone()).collect()
+        let mut locales: Vec<String> = self.translations.iter().map(|e| e.key().clone()).collect();
+        locales.sort();
+        locales
     }
 
-    pub fn key_exists(&self, key: &str) -> bool {
-        self.translations
-            .iter()
-            .any(|entry| entry.value().contains_key(key))
+    /// Whether `key` is defined in any locale, either directly or through the i18next plural
+    /// (`key_one`, `key_ordinal_few`) or, with [`Variants::Context`], context (`key_male`,
+    /// `key_male_other`) suffixes.
+    pub fn key_exists(&self, key: &str, lookup: Variants) -> bool {
+        self.translations.iter().any(|entry| {
+            let variants = self.variants.get(entry.key());
+            has_key_or_variant(entry.value(), variants.as_deref(), key, lookup)
+        })
+    }
+
+    fn locale_defines(&self, locale: &str, key: &str, lookup: Variants) -> bool {
+        self.translations.get(locale).is_some_and(|map| {
+            let variants = self.variants.get(locale);
+            has_key_or_variant(&map, variants.as_deref(), key, lookup)
+        })
+    }
+
+    /// Stored keys that are i18next suffix variants of `key`, grouped by context and then in
+    /// CLDR plural order (`item_one`, `item_other`, `item_male_one`, …).
+    pub fn get_key_variants(&self, key: &str, lookup: Variants) -> Vec<String> {
+        let mut variants = HashSet::new();
+        for entry in self.variants.iter() {
+            for variant in matching_variants(Some(entry.value()), key, lookup) {
+                variants.insert(variant.to_string());
+            }
+        }
+
+        let mut variants: Vec<String> = variants.into_iter().collect();
+        variants.sort_by_cached_key(|variant| {
+            let suffix = &variant[key.len() + 1..];
+            let (context, form) = split_plural_form(suffix);
+            (
+                context.to_string(),
+                plural_form_rank(form),
+                form.to_string(),
+            )
+        });
+        variants
     }
 
     pub fn get_locale_file_paths(&self, locale: &str) -> Vec<PathBuf> {
@@ -270,20 +747,197 @@ impl TranslationStore {
         result
     }
 
-    pub fn get_missing_locales(&self, key: &str) -> Vec<String> {
+    pub fn get_missing_locales(&self, key: &str, lookup: Variants) -> Vec<String> {
         let all_locales: Vec<String> = self.get_locales();
         all_locales
             .into_iter()
-            .filter(|locale| {
-                self.translations
-                    .get(locale)
-                    .map(|m| !m.contains_key(key))
-                    .unwrap_or(true)
-            })
+            .filter(|locale| !self.locale_defines(locale, key, lookup))
             .collect()
     }
 }
 
+fn has_key_or_variant(
+    map: &HashMap<String, TranslationEntry>,
+    variants: Option<&HashMap<String, Vec<Variant>>>,
+    key: &str,
+    lookup: Variants,
+) -> bool {
+    map.contains_key(key) || !matching_variants(variants, key, lookup).is_empty()
+}
+
+/// The stored keys indexed as variants of `key` that `lookup` accepts.
+fn matching_variants<'a>(
+    variants: Option<&'a HashMap<String, Vec<Variant>>>,
+    key: &str,
+    lookup: Variants,
+) -> Vec<&'a str> {
+    variants
+        .and_then(|variants| variants.get(key))
+        .map(|variants| {
+            variants
+                .iter()
+                .filter(|variant| lookup == Variants::Context || !variant.context)
+                .map(|variant| variant.key.as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn resolve_entry<'a>(
+    map: &'a HashMap<String, TranslationEntry>,
+    variants: Option<&HashMap<String, Vec<Variant>>>,
+    key: &str,
+) -> Option<&'a TranslationEntry> {
+    if let Some(entry) = map.get(key) {
+        return Some(entry);
+    }
+
+    map.get(&format!("{}_other", key)).or_else(|| {
+        matching_variants(variants, key, Variants::Plural)
+            .into_iter()
+            .min()
+            .and_then(|variant| map.get(variant))
+    })
+}
+
+/// The keys `stored` is a variant of: `item` for `item_one` or `item_ordinal_few`, and with
+/// a context, `friend` for `friend_male` and `friend_male_one` (besides `friend_male`).
+fn variant_bases(stored: &str) -> Vec<(&str, Variant)> {
+    let variant = |context| Variant {
+        key: stored.to_string(),
+        context,
+    };
+    let mut bases = Vec::new();
+    let rest = match plural_group_key(stored) {
+        Some(base) => {
+            bases.push((base, variant(false)));
+            base
+        }
+        None => stored,
+    };
+
+    if let Some((base, context)) = rest.rsplit_once('_') {
+        let context_ok = !context.is_empty()
+            && context.chars().all(|c| c.is_alphanumeric() || c == '-')
+            && context != "ordinal";
+        if !base.is_empty() && context_ok {
+            bases.push((base, variant(true)));
+        }
+    }
+    bases
+}
+
+/// The base of an i18next plural key: `item` for `item_one`, `item_few`, `item_ordinal_few`,
+/// or the legacy `item_plural` and `item_0`…`item_5`.
+pub fn plural_group_key(key: &str) -> Option<&str> {
+    let (base, form) = key.rsplit_once('_')?;
+    let base = match base.strip_suffix("_ordinal") {
+        Some(stripped) if CATEGORIES.contains(&form) => stripped,
+        _ => base,
+    };
+    (!base.is_empty() && is_plural_form(form)).then_some(base)
+}
+
+/// Split `male_one` into `("male", "one")`; suffixes without a plural form return an empty form.
+fn split_plural_form(suffix: &str) -> (&str, &str) {
+    if is_plural_form(suffix) {
+        return ("", suffix);
+    }
+    if let Some(index) = suffix.find("_ordinal_") {
+        if is_plural_form(&suffix[index + 1..]) {
+            return (&suffix[..index], &suffix[index + 1..]);
+        }
+    }
+    match suffix.rsplit_once('_') {
+        Some((context, form)) if is_plural_form(form) => (context, form),
+        _ => (suffix, ""),
+    }
+}
+
+/// CLDR categories, optionally ordinal, and the suffixes of i18next's v3 JSON format: `plural`
+/// and the numbered forms `0`…`5`.
+fn is_plural_form(form: &str) -> bool {
+    CATEGORIES.contains(&form.strip_prefix("ordinal_").unwrap_or(form))
+        || is_legacy_plural_form(form)
+}
+
+fn is_legacy_plural_form(form: &str) -> bool {
+    matches!(form, "plural" | "0" | "1" | "2" | "3" | "4" | "5")
+}
+
+fn plural_form_rank(form: &str) -> usize {
+    let ordinal = form.starts_with("ordinal_");
+    let form = form.strip_prefix("ordinal_").unwrap_or(form);
+    let rank = CATEGORIES
+        .iter()
+        .position(|category| *category == form)
+        .or_else(|| (form == "plural").then_some(CATEGORIES.len()))
+        .or_else(|| {
+            form.parse::<usize>()
+                .ok()
+                .map(|number| CATEGORIES.len() + 1 + number)
+        })
+        .unwrap_or(CATEGORIES.len() + 10);
+    rank + if ordinal { 100 } else { 0 }
+}
+
+/// Whether a JSON locale file is keyed by literal strings: either it sits under one of the
+/// configured `literal_key_paths`, or it is a Laravel-style `lang/vi.json` next to PHP locale
+/// directories such as `lang/vi/auth.php`.
+pub fn uses_literal_keys(workspace_root: &Path, path: &Path, literal_key_paths: &[String]) -> bool {
+    if !matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("json" | "jsonc" | "json5")
+    ) {
+        return false;
+    }
+
+    let relative = path.strip_prefix(workspace_root).unwrap_or(path);
+    for literal_path in literal_key_paths {
+        let trimmed = literal_path.trim_end_matches(['/', '\\']);
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if has_glob_meta(trimmed) {
+            if Glob::new(trimmed)
+                .map(|glob| glob.compile_matcher().is_match(relative))
+                .unwrap_or(false)
+            {
+                return true;
+            }
+        } else if relative.starts_with(trimmed) {
+            return true;
+        }
+    }
+
+    let Some(parent) = path.parent() else {
+        return false;
+    };
+    let is_locale_file = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(is_locale_code);
+    let Ok(entries) = std::fs::read_dir(parent) else {
+        return false;
+    };
+
+    is_locale_file
+        && entries.flatten().any(|entry| {
+            let dir = entry.path();
+            dir.is_dir()
+                && dir
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(is_locale_code)
+                && std::fs::read_dir(&dir).is_ok_and(|files| {
+                    files
+                        .flatten()
+                        .any(|file| file.path().extension().and_then(|e| e.to_str()) == Some("php"))
+                })
+        })
+}
+
 fn is_locale_code(s: &str) -> bool {
     let locale_patterns = [
         r"^[a-z]{2}$",
@@ -306,27 +960,85 @@ fn is_locale_code(s: &str) -> bool {
     common_locales.contains(&s)
 }
 
-fn has_glob_meta(path: &str) -> bool {
-    path.contains('*') || path.contains('?') || path.contains('[')
+fn collect_locale_directory(dir: &Path, files: &mut Vec<PathBuf>) {
+    let json_glob = Glob::new("*.{json,jsonc,json5}").unwrap().compile_matcher();
+    let yaml_glob = Glob::new("*.{yaml,yml}").unwrap().compile_matcher();
+    let toml_glob = Glob::new("*.toml").unwrap().compile_matcher();
+    let php_glob = Glob::new("*.php").unwrap().compile_matcher();
+    let arb_glob = Glob::new("*.arb").unwrap().compile_matcher();
+    let properties_glob = Glob::new("*.properties").unwrap().compile_matcher();
+    let resx_glob = Glob::new("*.resx").unwrap().compile_matcher();
+    let js_module_glob = Glob::new("*.{js,mjs,cjs,ts,mts,cts}")
+        .unwrap()
+        .compile_matcher();
+
+    for entry in WalkDir::new(dir)
+        .max_depth(3)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        let file_name = path.file_name().unwrap_or_default();
+
+        if path.is_file()
+            && (json_glob.is_match(file_name)
+                || yaml_glob.is_match(file_name)
+                || toml_glob.is_match(file_name)
+                || php_glob.is_match(file_name)
+                || arb_glob.is_match(file_name)
+                || properties_glob.is_match(file_name)
+                || resx_glob.is_match(file_name)
+                || js_module_glob.is_match(file_name))
+        {
+            files.push(path.to_path_buf());
+        }
+    }
 }
 
-fn is_ignored_workspace_dir(path: &Path) -> bool {
-    if !path.is_dir() {
-        return false;
+/// Rails-style YAML nests every key under its locale (`en: { users: … }`), and a single file
+/// may hold several locales. Returns the translations unchanged when the file is not laid out
+/// that way.
+fn split_locale_roots<V>(
+    translations: HashMap<String, V>,
+    path_locale: Option<&str>,
+) -> Result<HashMap<String, HashMap<String, V>>, HashMap<String, V>> {
+    let mut roots = HashSet::new();
+    let all_nested = translations.keys().all(|key| {
+        key.split_once('.')
+            .map(|(root, _)| roots.insert(root))
+            .is_some()
+    });
+
+    // A file named after its locale must be rooted at exactly that locale; otherwise every
+    // root has to look like a locale code.
+    let is_rooted = all_nested
+        && match path_locale {
+            Some(locale) => roots.len() == 1 && roots.contains(locale),
+            None => !roots.is_empty() && roots.iter().all(|root| is_locale_code(root)),
+        };
+    if !is_rooted {
+        return Err(translations);
     }
 
-    path.file_name()
-        .and_then(|name| name.to_str())
-        .is_some_and(|name| {
-            matches!(
-                name,
-                "node_modules" | ".git" | "target" | "dist" | "build" | ".nuxt" | ".output"
-            )
-        })
+    let mut by_locale: HashMap<String, HashMap<String, V>> = HashMap::new();
+    for (key, value) in translations {
+        if let Some((root, rest)) = key.split_once('.') {
+            by_locale
+                .entry(root.to_string())
+                .or_default()
+                .insert(rest.to_string(), value);
+        }
+    }
+    Ok(by_locale)
 }
 
-/// Extract locale from ARB filename patterns like "app_en", "messages_en_US", "intl_vi"
-fn extract_locale_from_arb_filename(file_stem: &str) -> Option<String> {
+fn has_glob_meta(path: &str) -> bool {
+    path.contains('*') || path.contains('?') || path.contains('[')
+}
+
+/// Extract locale from ARB and `.properties` filename patterns like "app_en", "messages_en_US", "intl_vi"
+fn extract_locale_from_suffixed_filename(file_stem: &str) -> Option<String> {
     // Common ARB file prefixes
     let prefixes = ["app_", "intl_", "messages_", "l10n_", "strings_"];
 
@@ -359,6 +1071,12 @@ fn extract_locale_from_arb_filename(file_stem: &str) -> Option<String> {
     None
 }
 
+/// Extract locale from .NET resource filename patterns like "Strings.vi" or "Resources.en-US"
+fn extract_locale_from_dotted_filename(file_stem: &str) -> Option<String> {
+    let (_, locale) = file_stem.rsplit_once('.')?;
+    is_locale_code(locale).then(|| locale.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -406,6 +1124,458 @@ mod tests {
         fs::remove_dir_all(root).ok();
     }
 
+    #[test]
+    fn scan_and_load_keeps_laravel_json_keys_literal() {
+        let root = test_workspace("laravel-literal-json");
+        let lang_dir = root.join("lang");
+        fs::create_dir_all(lang_dir.join("vi")).expect("create lang dir");
+        fs::write(
+            lang_dir.join("vi/auth.php"),
+            "<?php\nreturn ['failed' => 'Sai thông tin.'];\n",
+        )
+        .expect("write php");
+        fs::write(lang_dir.join("vi.json"), r#"{"Log in.": "Đăng nhập."}"#).expect("write json");
+
+        let custom_dir = root.join("resources/strings");
+        fs::create_dir_all(&custom_dir).expect("create custom dir");
+        fs::write(custom_dir.join("fr.json"), r#"{"Sign up.": "S'inscrire."}"#)
+            .expect("write json");
+
+        let store = TranslationStore::new(root.clone())
+            .with_literal_key_paths(&["resources/strings".to_string()]);
+        store.scan_and_load(&["lang".to_string(), "resources/strings".to_string()]);
+
+        assert_eq!(
+            store.get_translation("Log in.", "vi").as_deref(),
+            Some("Đăng nhập.")
+        );
+        assert_eq!(
+            store.get_translation("auth.failed", "vi").as_deref(),
+            Some("Sai thông tin.")
+        );
+        assert_eq!(
+            store.get_translation("Sign up.", "fr").as_deref(),
+            Some("S'inscrire.")
+        );
+        assert_eq!(
+            store
+                .get_translation_location("Log in.", "vi")
+                .map(|location| location.line),
+            Some(0)
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn scan_and_load_merges_files_in_path_order() {
+        let root = test_workspace("merge-order");
+        let locale_dir = root.join("locales/en");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        for (index, name) in ["a", "b", "c", "d", "e", "f"].iter().enumerate() {
+            fs::write(
+                locale_dir.join(format!("{name}.json")),
+                format!(r#"{{"shared":"{name}","only_{index}":"{name}"}}"#),
+            )
+            .expect("write locale file");
+        }
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["locales".to_string()]);
+
+        assert_eq!(store.get_translation("shared", "en").as_deref(), Some("f"));
+        assert_eq!(store.get_all_keys().len(), 7);
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn scan_and_load_collects_ignore_markers() {
+        let root = test_workspace("ignore-markers");
+        let locale_dir = root.join("locales");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(
+            locale_dir.join("en.json"),
+            r#"{"beta":{"@i18nlens-ignore":true,"banner":"New!"},"@i18nlens-ignore":["tests.fixture"],"home":"Home"}"#,
+        )
+        .expect("write en json");
+        fs::write(
+            locale_dir.join("admin.yml"),
+            "fr:\n  admin:\n    '@i18nlens-ignore': [draft]\n    draft: Brouillon\n",
+        )
+        .expect("write rails yaml");
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["locales".to_string()]);
+
+        assert!(store.is_ignored_key("beta.banner"));
+        assert!(store.is_ignored_key("tests.fixture"));
+        assert!(store.is_ignored_key("admin.draft"));
+        assert!(!store.is_ignored_key("home"));
+        assert!(!store
+            .get_all_keys()
+            .iter()
+            .any(|key| key.contains("@i18nlens-ignore")));
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn scan_and_load_strips_rails_yaml_locale_roots() {
+        let root = test_workspace("rails-yaml-locale-roots");
+        let locale_dir = root.join("config/locales");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(
+            locale_dir.join("en.yml"),
+            "en:\n  users:\n    title: Users\n",
+        )
+        .expect("write en yaml");
+        fs::write(
+            locale_dir.join("devise.vi.yml"),
+            "vi:\n  devise:\n    sign_in: Đăng nhập\n",
+        )
+        .expect("write devise yaml");
+        fs::write(
+            locale_dir.join("models.yml"),
+            "en:\n  models:\n    user: User\nfr:\n  models:\n    user: Utilisateur\n",
+        )
+        .expect("write multi-locale yaml");
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["config/locales".to_string()]);
+
+        assert_eq!(
+            store.get_translation("users.title", "en").as_deref(),
+            Some("Users")
+        );
+        assert_eq!(
+            store.get_translation("devise.sign_in", "vi").as_deref(),
+            Some("Đăng nhập")
+        );
+        assert_eq!(
+            store.get_translation("models.user", "fr").as_deref(),
+            Some("Utilisateur")
+        );
+        assert!(!store.key_exists("en.users.title", Variants::Plural));
+        assert_eq!(
+            store.get_locale_file_paths("fr"),
+            vec![locale_dir.join("models.yml")]
+        );
+        let location = store
+            .get_translation_location("models.user", "fr")
+            .expect("fr location");
+        assert_eq!((location.line, location.start_char), (5, 4));
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn translation_entries_record_key_and_value_spans() {
+        let root = test_workspace("translation-spans");
+        let locale_dir = root.join("locales");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(
+            locale_dir.join("en.json"),
+            "{\n  \"title\": \"Home\",\n  \"about\": {\n    \"title\": \"About us\"\n  }\n}\n",
+        )
+        .expect("write en json");
+        // The value holds the key text, which must not be mistaken for the key.
+        fs::write(
+            locale_dir.join("fr.json"),
+            "{\n  \"intro\": \"title\",\n  \"title\": \"title\"\n}\n",
+        )
+        .expect("write fr json");
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["locales".to_string()]);
+
+        let location = store
+            .get_translation_location("about.title", "en")
+            .expect("about.title location");
+        assert_eq!(
+            (location.line, location.start_char, location.end_char),
+            (3, 5, 10)
+        );
+        assert_eq!(
+            store
+                .get_translation_location("title", "en")
+                .map(|location| location.line),
+            Some(1)
+        );
+
+        let entry = &store.get_all_translations("about.title")["en"];
+        let value = entry.value_span.expect("value span");
+        assert_eq!((value.line, value.start_char, value.end_char), (3, 13, 23));
+
+        let location = store
+            .get_translation_location("title", "fr")
+            .expect("fr title location");
+        assert_eq!(
+            (location.line, location.start_char, location.end_char),
+            (2, 3, 8)
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn key_conflicts_record_every_definition_for_a_locale() {
+        let root = test_workspace("key-conflicts");
+        let locale_dir = root.join("locales");
+        fs::create_dir_all(locale_dir.join("en")).expect("create locale dir");
+        fs::write(
+            locale_dir.join("en.json"),
+            "{\n  \"title\": \"Home\",\n  \"save\": \"Save\"\n}\n",
+        )
+        .expect("write en json");
+        fs::write(
+            locale_dir.join("en/common.json"),
+            "{\n  \"save\": \"Save\",\n  \"title\": \"Start\"\n}\n",
+        )
+        .expect("write en common");
+        fs::write(locale_dir.join("vi.json"), r#"{"title":"Trang chủ"}"#).expect("write vi");
+
+        let store = TranslationStore::new(root.clone());
+        // The glob overlaps the directory path; a file is never a conflict with itself.
+        store.scan_and_load(&["locales".to_string(), "locales/*.json".to_string()]);
+
+        let conflicts = store.get_key_conflicts();
+        let keys: Vec<(&str, &str)> = conflicts
+            .iter()
+            .map(|conflict| (conflict.locale.as_str(), conflict.key.as_str()))
+            .collect();
+        assert_eq!(keys, vec![("en", "save"), ("en", "title")]);
+        assert!(conflicts[0].has_same_value());
+        assert!(!conflicts[1].has_same_value());
+
+        let title = &conflicts[1].definitions;
+        assert_eq!(title.len(), 2);
+        assert_eq!(title[0].file_path, locale_dir.join("en/common.json"));
+        assert_eq!(title[0].location().line, 2);
+        assert_eq!(title[1].file_path, locale_dir.join("en.json"));
+        assert_eq!(title[1].location().line, 1);
+        assert_eq!(
+            store.get_translation("title", "en").as_deref(),
+            Some("Home")
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn component_translations_resolve_before_global_messages() {
+        let root = test_workspace("vue-component-translations");
+        let locale_dir = root.join("locales");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(locale_dir.join("en.json"), r#"{"title":"Global"}"#).expect("write en");
+        fs::write(locale_dir.join("vi.json"), r#"{"title":"Toàn cục"}"#).expect("write vi");
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["locales".to_string()]);
+
+        let component = root.join("src/Cart.vue");
+        store.load_component_translations(
+            &component,
+            r#"<i18n>{"en":{"title":"Cart","empty":"No items"}}</i18n>"#,
+        );
+
+        assert_eq!(
+            store
+                .get_component_translation(Some(&component), "title", "en")
+                .as_deref(),
+            Some("Cart")
+        );
+        assert_eq!(
+            store
+                .get_component_translation(Some(&component), "title", "vi")
+                .as_deref(),
+            Some("Toàn cục")
+        );
+        assert!(store.component_key_exists(Some(&component), "empty", Variants::Plural));
+        assert!(!store.component_key_exists(None, "empty", Variants::Plural));
+        assert_eq!(
+            store.get_component_missing_locales(Some(&component), "empty", Variants::Plural),
+            vec!["vi".to_string()]
+        );
+
+        store.load_component_translations(&component, "<template></template>");
+        assert!(!store.component_key_exists(Some(&component), "empty", Variants::Plural));
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn scan_and_load_reads_js_and_ts_locale_modules() {
+        let root = test_workspace("js-ts-locale-modules");
+        let locale_dir = root.join("locales");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(
+            locale_dir.join("en.ts"),
+            "export default { nav: { home: 'Home' } } as const;\n",
+        )
+        .expect("write ts locale");
+        fs::write(
+            locale_dir.join("fr.js"),
+            "module.exports = { nav: { home: 'Accueil' } };\n",
+        )
+        .expect("write js locale");
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["locales".to_string()]);
+
+        assert_eq!(
+            store.get_translation("nav.home", "en").as_deref(),
+            Some("Home")
+        );
+        assert_eq!(
+            store.get_translation("nav.home", "fr").as_deref(),
+            Some("Accueil")
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn scan_and_load_reads_properties_and_resx_bundles() {
+        let root = test_workspace("properties-resx-bundles");
+        let locale_dir = root.join("i18n");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(
+            locale_dir.join("messages_vi.properties"),
+            "email.subject=Ch\\u00e0o {0}\n",
+        )
+        .expect("write properties bundle");
+        fs::write(
+            locale_dir.join("messages_en_US.properties"),
+            "email.subject=Hello {0}\n",
+        )
+        .expect("write properties bundle");
+        fs::write(
+            locale_dir.join("Strings.fr.resx"),
+            r#"<root><data name="Greeting" xml:space="preserve"><value>Bonjour</value></data></root>"#,
+        )
+        .expect("write resx bundle");
+
+        fs::write(
+            locale_dir.join("messages.properties"),
+            "email.subject=Hello {0}\nonly.default=Default\n",
+        )
+        .expect("write base bundle");
+
+        let store = TranslationStore::new(root.clone()).with_source_locale("en");
+        store.scan_and_load(&["i18n".to_string()]);
+
+        assert_eq!(
+            store.get_translation("email.subject", "vi").as_deref(),
+            Some("Chào {0}")
+        );
+        // The base bundle holds the source locale's messages.
+        assert_eq!(
+            store.get_translation("only.default", "en").as_deref(),
+            Some("Default")
+        );
+        assert_eq!(
+            store.get_translation("email.subject", "en_US").as_deref(),
+            Some("Hello {0}")
+        );
+        assert_eq!(
+            store.get_translation("Greeting", "fr").as_deref(),
+            Some("Bonjour")
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn resolves_i18next_plural_and_context_suffixes() {
+        let root = test_workspace("i18next-suffixes");
+        let locale_dir = root.join("locales");
+        fs::create_dir_all(&locale_dir).expect("create locale dir");
+        fs::write(
+            locale_dir.join("en.json"),
+            r#"{
+                "item_one": "{{count}} item",
+                "item_other": "{{count}} items",
+                "place_ordinal_few": "{{count}}rd place",
+                "friend_male": "A boyfriend",
+                "friend_female_other": "{{count}} girlfriends",
+                "save_draft": "Save draft",
+                "legacy": "Legacy item",
+                "legacy_plural": "Legacy items",
+                "count_0": "zero",
+                "count_1": "one",
+                "count_2": "two"
+            }"#,
+        )
+        .expect("write en locale");
+        fs::write(
+            locale_dir.join("ja.json"),
+            r#"{ "item_other": "{{count}} 個" }"#,
+        )
+        .expect("write ja locale");
+
+        let store = TranslationStore::new(root.clone());
+        store.scan_and_load(&["locales".to_string()]);
+
+        for key in ["item", "place", "legacy", "count"] {
+            assert!(
+                store.key_exists(key, Variants::Plural),
+                "{key} should resolve"
+            );
+        }
+        // Context suffixes only count for calls that pass a `context`: `save_draft` is a
+        // separate key for other calls.
+        for key in ["friend", "save"] {
+            assert!(
+                !store.key_exists(key, Variants::Plural),
+                "{key} should not resolve"
+            );
+        }
+        assert!(store.key_exists("friend", Variants::Context));
+        assert!(store.key_exists("save", Variants::Context));
+        assert!(!store.key_exists("ite", Variants::Context));
+        assert!(!store.key_exists("friend_male.extra", Variants::Context));
+        assert_eq!(
+            store.get_key_variants("item", Variants::Plural),
+            vec!["item_one", "item_other"]
+        );
+        assert!(store
+            .get_key_variants("friend", Variants::Plural)
+            .is_empty());
+        assert_eq!(
+            store.get_key_variants("friend", Variants::Context),
+            vec!["friend_female_other", "friend_male"]
+        );
+        assert_eq!(
+            store.get_key_variants("friend_female", Variants::Plural),
+            vec!["friend_female_other"]
+        );
+        assert_eq!(
+            store.get_translation("item", "en").as_deref(),
+            Some("{{count}} items")
+        );
+        assert_eq!(store.get_translation("save", "en"), None);
+        assert!(store
+            .get_missing_locales("item", Variants::Plural)
+            .is_empty());
+        assert_eq!(
+            store.get_missing_locales("friend", Variants::Context),
+            vec!["ja".to_string()]
+        );
+        assert_eq!(plural_group_key("item_one"), Some("item"));
+        assert_eq!(plural_group_key("place_ordinal_few"), Some("place"));
+        assert_eq!(plural_group_key("friend_male"), None);
+        assert_eq!(plural_group_key("item_plural"), Some("item"));
+        assert_eq!(plural_group_key("count_0"), Some("count"));
+        assert_eq!(plural_group_key("save_draft"), None);
+        assert_eq!(
+            store.get_key_variants("count", Variants::Plural),
+            vec!["count_0", "count_1", "count_2"]
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
     fn test_workspace(name: &str) -> PathBuf {
         let nonce = SystemTime::now()
             .duration_since(UNIX_EPOCH)
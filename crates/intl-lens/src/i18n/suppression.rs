@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// Locale file key whose value lists keys to leave out of every check:
+/// `"@i18nlens-ignore": ["beta.banner"]`, or `true` for the whole object it sits in.
+pub const IGNORE_MARKER: &str = "@i18nlens-ignore";
+
+/// Lines of a source file covered by `i18nlens-ignore-next-line`, `i18nlens-ignore` and
+/// `i18nlens-disable` … `i18nlens-enable` comments.
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    ranges: Vec<SuppressedRange>,
+}
+
+#[derive(Debug, Clone)]
+struct SuppressedRange {
+    first_line: usize,
+    last_line: usize,
+    /// Rules named after the directive; empty suppresses every rule.
+    rules: Vec<String>,
+}
+
+impl Suppressions {
+    /// Collect the directives of a source file. They are recognised after `//`, `/*`, `#`,
+    /// `<!--` and `{{--` comment openers, so `{/* i18nlens-ignore */}` works in JSX.
+    pub fn parse(content: &str) -> Self {
+        static DIRECTIVE_REGEX: OnceLock<Regex> = OnceLock::new();
+        let directive_regex = DIRECTIVE_REGEX.get_or_init(|| {
+            Regex::new(r"i18nlens-(ignore-next-line|ignore|disable|enable)\b([^\n]*)")
+                .expect("valid suppression regex")
+        });
+
+        let mut ranges = Vec::new();
+        let mut open: Vec<(usize, Vec<String>)> = Vec::new();
+
+        for (line_number, line) in content.lines().enumerate() {
+            let Some(captures) = directive_regex.captures(line) else {
+                continue;
+            };
+            let directive = captures.get(0).map_or(0, |m| m.start());
+            let Some(code_before) = strip_comment_opener(&line[..directive]) else {
+                continue;
+            };
+            let rules = parse_rules(&captures[2]);
+
+            match &captures[1] {
+                "ignore-next-line" => ranges.push(SuppressedRange {
+                    first_line: line_number + 1,
+                    last_line: line_number + 1,
+                    rules,
+                }),
+                "ignore" => {
+                    // A comment next to code covers its own line, a standalone one the next line.
+                    let target = if code_before.is_empty() && !has_code_after(&captures[2]) {
+                        line_number + 1
+                    } else {
+                        line_number
+                    };
+                    ranges.push(SuppressedRange {
+                        first_line: target,
+                        last_line: target,
+                        rules,
+                    });
+                }
+                "disable" => open.push((line_number, rules)),
+                _ => {
+                    let (closed, still_open) = open.into_iter().partition(|(_, disabled)| {
+                        rules.is_empty()
+                            || disabled.is_empty()
+                            || disabled.iter().any(|rule| rules.contains(rule))
+                    });
+                    open = still_open;
+                    ranges.extend(
+                        closed
+                            .into_iter()
+                            .map(|(first_line, rules)| SuppressedRange {
+                                first_line,
+                                last_line: line_number,
+                                rules,
+                            }),
+                    );
+                }
+            }
+        }
+
+        ranges.extend(open.into_iter().map(|(first_line, rules)| SuppressedRange {
+            first_line,
+            last_line: usize::MAX,
+            rules,
+        }));
+
+        Self { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Whether a finding with diagnostic `code` on the 0-based `line` is suppressed.
+    pub fn is_suppressed(&self, line: usize, code: &str) -> bool {
+        self.ranges.iter().any(|range| {
+            (range.first_line..=range.last_line).contains(&line)
+                && (range.rules.is_empty()
+                    || range.rules.iter().any(|rule| rule_matches(rule, code)))
+        })
+    }
+}
+
+/// A rule is a diagnostic code (`missing-translation`) or the `--fail-on` category covering it
+/// (`missing`, `unused`, `placeholder`, `dynamic`).
+fn rule_matches(rule: &str, code: &str) -> bool {
+    let category = match code {
+        "missing-translation" | "incomplete-translation" | "raw-translation" => "missing",
+        "unused-key" => "unused",
+        "placeholder-mismatch" => "placeholder",
+        "dynamic-translation-key" | "missing-translation-prefix" => "dynamic",
+        _ => code,
+    };
+    rule == code || rule == category
+}
+
+/// Remove the [`IGNORE_MARKER`] entries of a flattened locale file and return the keys they
+/// exclude. Keys listed under a marker are relative to the object holding it.
+pub fn take_ignore_markers(translations: &mut HashMap<String, String>) -> Vec<String> {
+    let markers: Vec<(String, String)> = translations
+        .keys()
+        .filter(|key| marker_scope(key).is_some())
+        .cloned()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .filter_map(|key| translations.remove_entry(&key))
+        .collect();
+
+    let mut ignored = Vec::new();
+    for (key, value) in markers {
+        let scope = marker_scope(&key).unwrap_or_default();
+        match value.trim() {
+            "false" | "" => {}
+            "true" if scope.is_empty() => ignored.extend(translations.keys().cloned()),
+            "true" => ignored.push(scope.to_string()),
+            listed if scope.is_empty() => ignored.push(listed.to_string()),
+            listed => ignored.push(format!("{}.{}", scope, listed)),
+        }
+    }
+    ignored
+}
+
+/// The object path holding a marker: `""` for `@i18nlens-ignore` or `@i18nlens-ignore.0`,
+/// `beta` for `beta.@i18nlens-ignore`.
+fn marker_scope(key: &str) -> Option<&str> {
+    let (scope, rest) = match key.strip_prefix(IGNORE_MARKER) {
+        Some(rest) => ("", rest),
+        None => {
+            let at = key.find(&format!(".{}", IGNORE_MARKER))?;
+            (&key[..at], &key[at + 1 + IGNORE_MARKER.len()..])
+        }
+    };
+
+    let is_marker = rest.is_empty()
+        || rest
+            .strip_prefix('.')
+            .is_some_and(|index| index.parse::<usize>().is_ok());
+    is_marker.then_some(scope)
+}
+
+/// The code before a comment opener ending `before`, or `None` when the directive is not in a
+/// comment.
+fn strip_comment_opener(before: &str) -> Option<&str> {
+    let before = before.trim_end();
+    let code = ["//", "/*", "#", "<!--", "{{--"]
+        .iter()
+        .find_map(|opener| before.strip_suffix(opener))?
+        .trim();
+    // `{/* … */}` is how JSX spells a comment; the brace is not code.
+    Some(code.strip_suffix('{').unwrap_or(code).trim_end())
+}
+
+/// Whether code follows the comment closer in `text`, the rest of a directive's line, as in
+/// `{/* i18nlens-ignore */} t("x")`. Line comments run to the end of the line.
+fn has_code_after(text: &str) -> bool {
+    ["*/", "-->", "--}}"]
+        .iter()
+        .filter_map(|closer| text.find(closer).map(|index| &text[index + closer.len()..]))
+        .next()
+        .is_some_and(|after| {
+            let after = after.trim_start();
+            // `{/* … */}` is how JSX spells a comment; the brace is not code.
+            !after.strip_prefix('}').unwrap_or(after).trim().is_empty()
+        })
+}
+
+/// Rule names after a directive, up to the comment closer or a `--` description.
+fn parse_rules(text: &str) -> Vec<String> {
+    let end = ["*/", "-->", "--}}", " --"]
+        .iter()
+        .filter_map(|closer| text.find(closer))
+        .min()
+        .unwrap_or(text.len());
+
+    text[..end]
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|rule| !rule.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignore_next_line_and_trailing_ignore() {
+        let content = r#"
+// i18nlens-ignore-next-line
+t('fixture.one');
+t('fixture.two'); // i18nlens-ignore missing-translation
+{/* i18nlens-ignore */}
+<Trans i18nKey="fixture.three" />
+t('checked');
+{/* i18nlens-ignore */} t('fixture.four');
+t('also.checked');
+<!-- i18nlens-ignore missing --> <p>{{ $t('fixture.five') }}</p>
+t('checked.too');
+"#;
+        let suppressions = Suppressions::parse(content);
+
+        assert!(suppressions.is_suppressed(2, "missing-translation"));
+        assert!(suppressions.is_suppressed(3, "missing-translation"));
+        assert!(!suppressions.is_suppressed(3, "dynamic-translation-key"));
+        assert!(suppressions.is_suppressed(5, "incomplete-translation"));
+        assert!(!suppressions.is_suppressed(6, "missing-translation"));
+        // A leading comment followed by code on the same line covers that line only.
+        assert!(suppressions.is_suppressed(7, "missing-translation"));
+        assert!(!suppressions.is_suppressed(8, "missing-translation"));
+        assert!(suppressions.is_suppressed(9, "missing-translation"));
+        assert!(!suppressions.is_suppressed(10, "missing-translation"));
+    }
+
+    #[test]
+    fn test_disable_until_enable() {
+        let content = r#"
+/* i18nlens-disable missing -- feature flag */
+t('beta.one');
+t(key);
+// i18nlens-enable
+t('beta.two');
+# i18nlens-disable dynamic-translation-key
+t(other);
+"#;
+        let suppressions = Suppressions::parse(content);
+
+        assert!(suppressions.is_suppressed(2, "missing-translation"));
+        assert!(suppressions.is_suppressed(2, "incomplete-translation"));
+        assert!(!suppressions.is_suppressed(3, "dynamic-translation-key"));
+        assert!(!suppressions.is_suppressed(5, "missing-translation"));
+        assert!(suppressions.is_suppressed(7, "dynamic-translation-key"));
+    }
+
+    #[test]
+    fn test_directive_outside_comment_is_ignored() {
+        let suppressions = Suppressions::parse("t('i18nlens-ignore-next-line');\nt('x');\n");
+        assert!(suppressions.is_empty());
+    }
+
+    #[test]
+    fn test_take_ignore_markers() {
+        let mut translations: HashMap<String, String> = [
+            ("@i18nlens-ignore.0", "legacy.title"),
+            ("beta.@i18nlens-ignore", "true"),
+            ("beta.banner", "New!"),
+            ("tests.@i18nlens-ignore", "fixture"),
+            ("tests.fixture", "Fixture"),
+            ("home.title", "Home"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+        let mut ignored = take_ignore_markers(&mut translations);
+        ignored.sort();
+
+        assert_eq!(ignored, vec!["beta", "legacy.title", "tests.fixture"]);
+        assert_eq!(translations.len(), 3);
+        assert!(!translations.keys().any(|key| key.contains(IGNORE_MARKER)));
+    }
+}
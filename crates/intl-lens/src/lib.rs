@@ -1,17 +1,25 @@
 pub mod audit;
 pub mod backend;
+pub mod cache;
 pub mod cli_app;
 pub mod config;
 pub mod document;
 pub mod i18n;
+pub mod preset;
 pub mod scanner;
+pub mod walker;
 
 pub use audit::{
-    AuditReport, AuditResult, AuditSummary, FixSuggestion, KeyUsage, MissingTranslation,
-    PlaceholderIssue, PlaceholderIssueType, UnusedKey,
+    AuditReport, AuditResult, AuditSummary, DynamicKeyUsage, FixSuggestion, KeyUsage,
+    MissingTranslation, PlaceholderIssue, PlaceholderIssueType, UnusedKey,
 };
-pub use config::{DisplayMode, I18nConfig, KeyStyle};
-pub use i18n::key_finder::{FoundKey, KeyFinder};
+pub use cache::IndexCache;
+pub use config::{
+    DisplayMode, DynamicKeyPolicy, I18nConfig, KeyExtractor, KeyStyle, PlaceholderSyntax,
+};
+pub use i18n::key_finder::{FoundKey, FunctionPattern, KeyFinder, KeyKind};
 pub use i18n::parser::TranslationParser;
 pub use i18n::store::{TranslationEntry, TranslationLocation, TranslationStore};
+pub use preset::Preset;
 pub use scanner::{CodeKeyOccurrence, CodeScanner, ScannedFile};
+pub use walker::WorkspaceWalker;
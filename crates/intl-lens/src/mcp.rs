@@ -1,17 +1,22 @@
+use std::collections::HashMap;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use anyhow::{anyhow, Context, Result};
 use intl_lens::audit::{
-    AuditReport, AuditResult, FixSuggestion, MissingTranslation, PlaceholderIssue,
+    extract_placeholders_with, AuditReport, AuditResult, FixSuggestion, MissingTranslation,
+    PlaceholderIssue,
 };
+use intl_lens::cache::IndexCache;
 use intl_lens::config::I18nConfig;
-use intl_lens::i18n::store::TranslationStore;
+use intl_lens::i18n::jsonc;
+use intl_lens::i18n::store::{uses_literal_keys, TranslationStore};
+use intl_lens::walker::WorkspaceWalker;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
-use walkdir::WalkDir;
 
 #[derive(Debug, Deserialize)]
 struct JsonRpcRequest {
@@ -159,11 +164,16 @@ struct FixSuggestionResponse {
 
 struct McpServer {
     workspace_root: PathBuf,
+    /// Index caches by workspace, kept between tool calls so only changed files are parsed.
+    caches: Mutex<HashMap<PathBuf, Arc<IndexCache>>>,
 }
 
 impl McpServer {
     fn new(workspace_root: PathBuf) -> Self {
-        Self { workspace_root }
+        Self {
+            workspace_root,
+            caches: Mutex::new(HashMap::new()),
+        }
     }
 
     fn handle_request(&self, request: JsonRpcRequest) -> JsonRpcResponse {
@@ -562,7 +572,8 @@ impl McpServer {
                 json!({
                     "locale": locale,
                     "value": entry.value,
-                    "file": entry.file_path
+                    "file": entry.file_path,
+                    "line": entry.key_span.map(|span| span.line + 1)
                 })
             })
             .collect();
@@ -649,9 +660,13 @@ impl McpServer {
                 .collect()
         };
 
+        let config = I18nConfig::load_from_workspace(&workspace);
+        let walker = WorkspaceWalker::new(&workspace)
+            .with_include(&config.include)
+            .with_exclude(&config.exclude);
         let mut candidates = Vec::new();
         for root in roots {
-            for file in source_files(&root) {
+            for file in source_files(&walker, &root) {
                 let content = std::fs::read_to_string(&file)
                     .with_context(|| format!("Failed to read source file {}", file.display()))?;
                 candidates.extend(find_hardcoded_strings(
@@ -690,17 +705,40 @@ impl McpServer {
 
     fn load_store(&self, workspace: &Path) -> (I18nConfig, TranslationStore) {
         let config = I18nConfig::load_from_workspace(workspace);
-        let store = TranslationStore::new(workspace.to_path_buf());
+        let store = TranslationStore::new(workspace.to_path_buf())
+            .with_literal_key_paths(&config.literal_key_paths)
+            .with_namespaces(config.namespaces())
+            .with_exclude(&config.exclude)
+            .with_source_locale(&config.source_locale)
+            .with_cache(Some(self.index_cache(workspace, &config)));
         store.scan_and_load(&config.locale_paths);
         (config, store)
     }
 
+    /// The workspace's index, on disk or, when `cache` is disabled, in memory.
+    fn index_cache(&self, workspace: &Path, config: &I18nConfig) -> Arc<IndexCache> {
+        let mut caches = self
+            .caches
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(cache) = caches.get(workspace) {
+            if cache.matches_config(config) {
+                return cache.clone();
+            }
+        }
+
+        let cache = IndexCache::open_or_in_memory(workspace, config);
+        caches.insert(workspace.to_path_buf(), cache.clone());
+        cache
+    }
+
     fn plan_translation_patches(
         &self,
         workspace: &Path,
         translations: Vec<TranslationPatchInput>,
     ) -> Result<TranslationPatchPlan> {
         let report = self.build_report(workspace)?;
+        let config = I18nConfig::load_from_workspace(workspace);
         let mut patches = Vec::new();
         let mut skipped = Vec::new();
 
@@ -725,8 +763,10 @@ impl McpServer {
                 continue;
             };
 
-            let expected_placeholders = extract_placeholders(&missing.source_value);
-            let actual_placeholders = extract_placeholders(&translation.value);
+            let expected_placeholders =
+                extract_placeholders_with(&missing.source_value, config.placeholder_syntax);
+            let actual_placeholders =
+                extract_placeholders_with(&translation.value, config.placeholder_syntax);
             if expected_placeholders != actual_placeholders {
                 skipped.push(json!({
                     "key": translation.key,
@@ -738,7 +778,19 @@ impl McpServer {
                 continue;
             }
 
-            let Some(file) = find_locale_file(workspace, &translation.locale) else {
+            let (file, key) = match config.namespaces() {
+                Some(namespaces) => {
+                    let (namespace, key) = namespaces.split(&translation.key);
+                    let file =
+                        find_namespace_file(workspace, &config, &translation.locale, namespace);
+                    (file, key.to_string())
+                }
+                None => (
+                    find_locale_file(workspace, &translation.locale),
+                    translation.key.clone(),
+                ),
+            };
+            let Some(file) = file else {
                 skipped.push(json!({
                     "key": translation.key,
                     "locale": translation.locale,
@@ -749,8 +801,9 @@ impl McpServer {
 
             let before = std::fs::read_to_string(&file)
                 .with_context(|| format!("Failed to read locale file {}", file.display()))?;
+            let literal = uses_literal_keys(workspace, &file, &config.literal_key_paths);
             let Some(after) =
-                add_translation_to_content(&file, &before, &translation.key, &translation.value)?
+                add_translation_to_content(&file, &before, &key, &translation.value, literal)?
             else {
                 skipped.push(json!({
                     "key": translation.key,
@@ -803,6 +856,29 @@ fn strip_missing_suggestions(items: &[MissingTranslation]) -> Vec<Value> {
         .collect()
 }
 
+fn find_namespace_file(
+    workspace: &Path,
+    config: &I18nConfig,
+    locale: &str,
+    namespace: &str,
+) -> Option<PathBuf> {
+    for locale_path in &config.locale_paths {
+        let base = workspace.join(locale_path).join(locale);
+        for extension in ["json", "jsonc", "json5", "yaml", "yml"] {
+            let candidate = base.join(format!("{}.{}", namespace, extension));
+            if candidate.exists() {
+                return Some(candidate);
+            }
+        }
+    }
+
+    if namespace == config.default_namespace {
+        return find_locale_file(workspace, locale);
+    }
+
+    None
+}
+
 fn find_locale_file(workspace: &Path, locale: &str) -> Option<PathBuf> {
     let config = I18nConfig::load_from_workspace(workspace);
 
@@ -812,7 +888,7 @@ fn find_locale_file(workspace: &Path, locale: &str) -> Option<PathBuf> {
             continue;
         }
 
-        for extension in ["json", "yaml", "yml", "arb", "php"] {
+        for extension in ["json", "jsonc", "json5", "yaml", "yml", "arb", "php"] {
             let candidate = base.join(format!("{}.{}", locale, extension));
             if candidate.exists() {
                 return Some(candidate);
@@ -845,11 +921,14 @@ fn add_translation_to_content(
     content: &str,
     key: &str,
     value: &str,
+    literal: bool,
 ) -> Result<Option<String>> {
     match path.extension().and_then(|extension| extension.to_str()) {
-        Some("json") => add_json_translation_to_content(content, key, value).map(Some),
+        Some("json" | "jsonc" | "json5") => {
+            add_json_translation_to_content(content, key, value, literal).map(Some)
+        }
         Some("yaml") | Some("yml") => {
-            add_yaml_translation_to_content(content, key, value).map(Some)
+            add_yaml_translation_to_content(path, content, key, value).map(Some)
         }
         Some("arb") => add_arb_translation_to_content(content, key, value).map(Some),
         Some("php") => add_php_translation_to_content(content, key, value).map(Some),
@@ -857,18 +936,46 @@ fn add_translation_to_content(
     }
 }
 
-fn add_json_translation_to_content(content: &str, key: &str, value: &str) -> Result<String> {
-    let mut json: serde_json::Value = serde_json::from_str(content)?;
-    insert_json_key(&mut json, key, value);
+fn add_json_translation_to_content(
+    content: &str,
+    key: &str,
+    value: &str,
+    literal: bool,
+) -> Result<String> {
+    let Ok(mut json) = serde_json::from_str::<serde_json::Value>(content) else {
+        // JSONC/JSON5: splice the key into the text so comments survive.
+        let parts: Vec<&str> = if literal {
+            vec![key]
+        } else {
+            key.split('.').collect()
+        };
+        return jsonc::insert_key_path(content, &parts, value);
+    };
+    if literal {
+        if !json.is_object() {
+            json = json!({});
+        }
+        json[key] = Value::String(value.to_string());
+    } else {
+        insert_json_key(&mut json, key, value);
+    }
 
     let mut output = serde_json::to_string_pretty(&json)?;
     output.push('\n');
     Ok(output)
 }
 
-fn add_yaml_translation_to_content(content: &str, key: &str, value: &str) -> Result<String> {
+fn add_yaml_translation_to_content(
+    path: &Path,
+    content: &str,
+    key: &str,
+    value: &str,
+) -> Result<String> {
     let mut yaml: serde_yaml::Value = serde_yaml::from_str(content)?;
-    insert_yaml_key(&mut yaml, key, value);
+    match yaml_locale_root(path, &yaml) {
+        Some(root) => insert_yaml_key(&mut yaml, &format!("{root}.{key}"), value),
+        None => insert_yaml_key(&mut yaml, key, value),
+    }
     Ok(serde_yaml::to_string(&yaml)?)
 }
 
@@ -922,6 +1029,17 @@ fn insert_json_key(json: &mut serde_json::Value, key: &str, value: &str) {
     }
 }
 
+/// Rails-style YAML nests every key under the locale the file is named after (`en.yml` → `en:`).
+fn yaml_locale_root(path: &Path, yaml: &serde_yaml::Value) -> Option<String> {
+    let mapping = yaml.as_mapping()?;
+    if mapping.len() != 1 {
+        return None;
+    }
+    let root = mapping.keys().next()?.as_str()?;
+    let stem = path.file_stem()?.to_str()?;
+    (stem == root || stem.ends_with(&format!(".{root}"))).then(|| root.to_string())
+}
+
 fn insert_yaml_key(yaml: &mut serde_yaml::Value, key: &str, value: &str) {
     if !yaml.is_mapping() {
         *yaml = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
@@ -962,30 +1080,6 @@ fn detect_php_root_indent(content: &str) -> String {
         .unwrap_or_else(|| "    ".to_string())
 }
 
-fn extract_placeholders(value: &str) -> Vec<String> {
-    let mut placeholders = Vec::new();
-    for pattern in [
-        r"\{\{\s*([a-zA-Z_][a-zA-Z0-9_]*)\s*\}\}",
-        r"\{([a-zA-Z_][a-zA-Z0-9_]*)\}",
-        r"%[sdif]",
-    ] {
-        let regex = Regex::new(pattern).expect("placeholder regex");
-        for capture in regex.captures_iter(value) {
-            let placeholder = capture
-                .get(1)
-                .or_else(|| capture.get(0))
-                .map(|match_| match_.as_str().to_string());
-            if let Some(placeholder) = placeholder {
-                if !placeholders.contains(&placeholder) {
-                    placeholders.push(placeholder);
-                }
-            }
-        }
-    }
-    placeholders.sort();
-    placeholders
-}
-
 fn unified_diff(workspace: &Path, path: &Path, before: &str, after: &str) -> String {
     let relative = path.strip_prefix(workspace).unwrap_or(path).display();
     let mut diff = String::new();
@@ -1006,6 +1100,8 @@ fn review_should_block(report: &AuditReport, fail_on: &[String]) -> bool {
         "missing" => report.summary.missing_translations > 0,
         "unused" => report.summary.unused_keys > 0,
         "placeholder" => report.summary.placeholder_mismatches > 0,
+        "dynamic" => report.summary.dynamic_keys > 0,
+        "duplicate" => report.summary.duplicate_keys > 0,
         _ => false,
     })
 }
@@ -1032,7 +1128,9 @@ fn review_findings(report: &AuditReport, fail_on: &[String]) -> Vec<Value> {
                 "kind": "placeholder",
                 "severity": "error",
                 "key": item.key,
+                "issue_type": item.issue_type,
                 "expected_placeholders": item.expected_placeholders,
+                "details": item.details,
                 "locales": item.locale_values.keys().cloned().collect::<Vec<_>>()
             }));
         }
@@ -1044,7 +1142,36 @@ fn review_findings(report: &AuditReport, fail_on: &[String]) -> Vec<Value> {
                 "kind": "unused",
                 "severity": "warning",
                 "key": item.key,
-                "file": item.defined_in.file_path
+                "file": item.defined_in.file_path,
+                "line": item.defined_in.line + 1
+            }));
+        }
+    }
+
+    if fail_on.iter().any(|kind| kind == "dynamic") {
+        for item in &report.dynamic_keys {
+            findings.push(json!({
+                "kind": "dynamic",
+                "severity": "warning",
+                "key": item.key,
+                "key_kind": item.kind,
+                "matched_keys": item.matched_keys,
+                "files": item.used_in.iter().map(|usage| &usage.file).collect::<Vec<_>>()
+            }));
+        }
+    }
+
+    if fail_on.iter().any(|kind| kind == "duplicate") {
+        for item in &report.duplicate_keys {
+            findings.push(json!({
+                "kind": "duplicate",
+                "severity": if item.conflicting { "error" } else { "warning" },
+                "key": item.key,
+                "locale": item.locale,
+                "definitions": item.defined_in.iter().map(|location| json!({
+                    "file": location.file_path,
+                    "line": location.line + 1
+                })).collect::<Vec<_>>()
             }));
         }
     }
@@ -1068,6 +1195,14 @@ fn review_markdown(report: &AuditReport, blocking: bool, findings: &[Value]) ->
         "- Placeholder issues: {}\n",
         report.summary.placeholder_mismatches
     ));
+    markdown.push_str(&format!(
+        "- Dynamic keys: {}\n",
+        report.summary.dynamic_keys
+    ));
+    markdown.push_str(&format!(
+        "- Duplicate keys: {}\n",
+        report.summary.duplicate_keys
+    ));
 
     if !findings.is_empty() {
         markdown.push_str("\n### Findings\n\n");
@@ -1083,51 +1218,11 @@ fn review_markdown(report: &AuditReport, blocking: bool, findings: &[Value]) ->
     markdown
 }
 
-fn source_files(root: &Path) -> Vec<PathBuf> {
-    let walker = if root.is_file() {
+fn source_files(walker: &WorkspaceWalker, root: &Path) -> Vec<PathBuf> {
+    if root.is_file() {
         return vec![root.to_path_buf()];
-    } else {
-        WalkDir::new(root)
-    };
-
-    walker
-        .into_iter()
-        .filter_entry(|entry| !is_ignored_dir(entry.path()))
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.path().is_file() && is_supported_source_file(entry.path()))
-        .map(|entry| entry.path().to_path_buf())
-        .collect()
-}
-
-fn is_ignored_dir(path: &Path) -> bool {
-    if !path.is_dir() {
-        return false;
     }
-
-    path.file_name()
-        .and_then(|name| name.to_str())
-        .is_some_and(|name| {
-            matches!(
-                name,
-                "node_modules" | ".git" | "target" | "dist" | "build" | ".nuxt" | ".output"
-            )
-        })
-}
-
-fn is_supported_source_file(path: &Path) -> bool {
-    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
-        return false;
-    };
-
-    file_name.ends_with(".ts")
-        || file_name.ends_with(".tsx")
-        || file_name.ends_with(".js")
-        || file_name.ends_with(".jsx")
-        || file_name.ends_with(".vue")
-        || file_name.ends_with(".svelte")
-        || file_name.ends_with(".php")
-        || file_name.ends_with(".blade.php")
-        || file_name.ends_with(".dart")
+    walker.source_files(root)
 }
 
 fn find_hardcoded_strings(
@@ -1329,7 +1424,7 @@ fn tool_definitions() -> Vec<ToolDefinition> {
                     "workspace": { "type": "string" },
                     "fail_on": {
                         "type": "array",
-                        "items": { "type": "string", "enum": ["missing", "unused", "placeholder"] },
+                        "items": { "type": "string", "enum": ["missing", "unused", "placeholder", "dynamic", "duplicate"] },
                         "default": ["missing", "placeholder"]
                     }
                 }
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::PlaceholderSyntax;
+use crate::i18n::ast::SourceLanguage;
+use crate::i18n::key_finder::FunctionPattern;
+
+/// An i18n library whose call patterns, locale directories and placeholder syntax are known.
+/// Selected with `"presets"` in the config, or detected from the project's dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Preset {
+    I18next,
+    VueI18n,
+    NextIntl,
+    ReactIntl,
+    Laravel,
+    FlutterEasyLocalization,
+    AngularNgxTranslate,
+}
+
+impl Preset {
+    /// Key patterns for the library's translation calls, scoped to [`Self::languages`].
+    pub fn function_patterns(self) -> Vec<FunctionPattern> {
+        self.patterns()
+            .iter()
+            .map(|pattern| FunctionPattern::for_languages(*pattern, self.languages()))
+            .collect()
+    }
+
+    /// Languages of the files the library's calls appear in.
+    pub fn languages(self) -> &'static [SourceLanguage] {
+        match self {
+            Self::Laravel => SourceLanguage::PHP,
+            Self::FlutterEasyLocalization => &[SourceLanguage::Dart],
+            _ => SourceLanguage::WEB,
+        }
+    }
+
+    fn patterns(self) -> &'static [&'static str] {
+        match self {
+            Self::I18next => &[
+                r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#,
+                r#"\bi18n(?:ext)?\.t\s*\(\s*["']([^"']+)["']"#,
+                r#"<Trans\s+i18nKey\s*=\s*["']([^"']+)["']"#,
+            ],
+            Self::VueI18n => &[
+                r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#,
+                r#"\$t\s*\(\s*["']([^"']+)["']"#,
+                r#"\$tc\s*\(\s*["']([^"']+)["']"#,
+                r#"\$te\s*\(\s*["']([^"']+)["']"#,
+                r#"\bi18n\.(?:global\.)?t\s*\(\s*["']([^"']+)["']"#,
+                r#"\bv-t\s*=\s*"'([^']+)'""#,
+            ],
+            Self::NextIntl => &[
+                r#"(?:^|[^\w.])t\s*\(\s*["']([^"']+)["']"#,
+                r#"(?:^|[^\w.])t\.(?:rich|markup|raw|has)\s*\(\s*["']([^"']+)["']"#,
+            ],
+            Self::ReactIntl => &[
+                r#"formatMessage\s*\(\s*\{\s*id:\s*["']([^"']+)["']"#,
+                r#"<Formatted(?:Message|Plural)\s[^>]*?\bid\s*=\s*["']([^"']+)["']"#,
+            ],
+            Self::Laravel => &[
+                r#"__\s*\(\s*["']([^"']+)["']"#,
+                r#"trans(?:_choice)?\s*\(\s*["']([^"']+)["']"#,
+                r#"Lang::(?:get|choice|has)\s*\(\s*["']([^"']+)["']"#,
+                r#"@lang\s*\(\s*["']([^"']+)["']"#,
+                r#"@choice\s*\(\s*["']([^"']+)["']"#,
+            ],
+            Self::FlutterEasyLocalization => &[
+                r#"['"]([^'"]+)['"]\s*\.tr\("#,
+                r#"(?:^|[^\w.])tr\(\s*['"]([^'"]+)['"]"#,
+                r#"context\.tr\(\s*['"]([^'"]+)['"]"#,
+                r#"['"]([^'"]+)['"]\s*\.plural\("#,
+                r#"(?:^|[^\w.])plural\(\s*['"]([^'"]+)['"]"#,
+            ],
+            Self::AngularNgxTranslate => &[
+                r#"translateService\.(?:instant|get|stream)\s*\(\s*["']([^"']+)["']"#,
+                r#"["']([^"']+)["']\s*\|\s*translate\b"#,
+                r#"\[translate\]\s*=\s*"'([^']+)'""#,
+            ],
+        }
+    }
+
+    /// Conventional locale directories, relative to the workspace root.
+    pub fn locale_paths(self) -> &'static [&'static str] {
+        match self {
+            Self::I18next => &["public/locales", "locales", "src/locales", "src/i18n"],
+            Self::VueI18n => &[
+                "src/locales",
+                "src/i18n",
+                "src/lang",
+                "locales",
+                "i18n/locales",
+            ],
+            Self::NextIntl => &["messages", "src/messages", "locales"],
+            Self::ReactIntl => &["lang", "src/lang", "src/i18n", "compiled-lang"],
+            Self::Laravel => &["lang", "resources/lang"],
+            Self::FlutterEasyLocalization => &["assets/translations", "assets/langs"],
+            Self::AngularNgxTranslate => &["src/assets/i18n"],
+        }
+    }
+
+    pub fn placeholder_syntax(self) -> PlaceholderSyntax {
+        match self {
+            Self::Laravel => PlaceholderSyntax::Laravel,
+            _ => PlaceholderSyntax::Icu,
+        }
+    }
+}
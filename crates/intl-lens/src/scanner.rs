@@ -1,6 +1,15 @@
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use crate::i18n::key_finder::KeyFinder;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::IndexCache;
+use crate::i18n::ast::SourceLanguage;
+use crate::i18n::key_finder::{FunctionPattern, KeyFinder, KeyKind};
+use crate::i18n::namespace::Namespaces;
+use crate::walker::WorkspaceWalker;
 
 #[derive(Debug, Clone)]
 pub struct ScannedFile {
@@ -9,79 +18,109 @@ pub struct ScannedFile {
     pub found_keys: Vec<CodeKeyOccurrence>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeKeyOccurrence {
     pub key: String,
     pub line: usize,
     pub start_char: usize,
     pub end_char: usize,
     pub code_snippet: String,
+    pub kind: KeyKind,
+    /// See [`crate::i18n::key_finder::FoundKey::context`].
+    #[serde(default)]
+    pub context: bool,
 }
 
 pub struct CodeScanner {
     key_finder: KeyFinder,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    cache: Option<Arc<IndexCache>>,
 }
 
 impl CodeScanner {
-    pub fn new(patterns: &[String]) -> Self {
+    pub fn new(patterns: &[FunctionPattern]) -> Self {
         Self {
             key_finder: KeyFinder::new(patterns),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            cache: None,
         }
     }
 
-    pub fn scan_directory(&self, root: &Path) -> Vec<ScannedFile> {
-        let mut scanned_files = Vec::new();
+    /// See [`KeyFinder::with_namespaces`].
+    pub fn with_namespaces(mut self, namespaces: Option<Namespaces>) -> Self {
+        self.key_finder = self.key_finder.with_namespaces(namespaces);
+        self
+    }
 
-        // Supported file extensions
-        let extensions = ["ts", "tsx", "js", "jsx", "vue", "php", "blade.php", "dart"];
+    /// See [`KeyFinder::with_ast_languages`].
+    pub fn with_ast_languages(mut self, languages: HashSet<SourceLanguage>) -> Self {
+        self.key_finder = self.key_finder.with_ast_languages(languages);
+        self
+    }
 
-        for entry in walkdir::WalkDir::new(root)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-            if !path.is_file() {
-                continue;
-            }
+    /// See [`WorkspaceWalker::with_include`].
+    pub fn with_include(mut self, patterns: &[String]) -> Self {
+        self.include = patterns.to_vec();
+        self
+    }
 
-            // Check if file has supported extension
-            if let Some(ext) = path.extension() {
-                let ext_str = ext.to_string_lossy().to_string();
-                if !extensions.iter().any(|e| ext_str.contains(e)) {
-                    continue;
-                }
-            } else {
-                continue;
-            }
+    /// See [`WorkspaceWalker::with_exclude`].
+    pub fn with_exclude(mut self, patterns: &[String]) -> Self {
+        self.exclude = patterns.to_vec();
+        self
+    }
 
-            // Skip node_modules, .git, etc.
-            let path_str = path.to_string_lossy();
-            if path_str.contains("node_modules")
-                || path_str.contains(".git")
-                || path_str.contains("target")
-                || path_str.contains("dist")
-                || path_str.contains("build")
-            {
-                continue;
-            }
+    /// Reuse the keys found in a file from the index cache while it is unchanged.
+    pub fn with_cache(mut self, cache: Option<Arc<IndexCache>>) -> Self {
+        self.cache = cache;
+        self
+    }
 
-            if let Ok(content) = std::fs::read_to_string(path) {
-                let found_keys = self.scan_content(&content);
-                if !found_keys.is_empty() {
-                    scanned_files.push(ScannedFile {
-                        path: path.to_path_buf(),
-                        content,
-                        found_keys,
-                    });
-                }
+    pub fn scan_directory(&self, root: &Path) -> Vec<ScannedFile> {
+        let walker = WorkspaceWalker::new(root)
+            .with_include(&self.include)
+            .with_exclude(&self.exclude);
+
+        // Files are read and scanned on all cores; `collect` keeps the walk order.
+        walker
+            .source_files(root)
+            .into_par_iter()
+            .filter_map(|path| {
+                let content = std::fs::read_to_string(&path).ok()?;
+                let found_keys = self.scan_file(&path, &content);
+                (!found_keys.is_empty()).then_some(ScannedFile {
+                    path,
+                    content,
+                    found_keys,
+                })
+            })
+            .collect()
+    }
+
+    /// Scan `content` of the file at `path`, reusing the index cache when one is set and
+    /// `content` is unchanged since it was cached.
+    pub fn scan_file(&self, path: &Path, content: &str) -> Vec<CodeKeyOccurrence> {
+        match &self.cache {
+            Some(cache) => {
+                cache.source_file(path, content, || self.scan_file_content(content, path))
             }
+            None => self.scan_file_content(content, path),
         }
-
-        scanned_files
     }
 
     pub fn scan_content(&self, content: &str) -> Vec<CodeKeyOccurrence> {
-        let found = self.key_finder.find_keys(content);
+        self.collect_occurrences(content, None)
+    }
+
+    /// Like [`Self::scan_content`], resolving lazy lookups relative to `path`.
+    pub fn scan_file_content(&self, content: &str, path: &Path) -> Vec<CodeKeyOccurrence> {
+        self.collect_occurrences(content, Some(path))
+    }
+
+    fn collect_occurrences(&self, content: &str, path: Option<&Path>) -> Vec<CodeKeyOccurrence> {
+        let found = self.key_finder.find_keys_in_file(content, path);
 
         found
             .into_iter()
@@ -107,6 +146,8 @@ impl CodeScanner {
                     start_char: k.start_char,
                     end_char: k.end_char,
                     code_snippet,
+                    kind: k.kind,
+                    context: k.context,
                 }
             })
             .collect()
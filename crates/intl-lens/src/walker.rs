@@ -0,0 +1,194 @@
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+
+/// Source extensions scanned when no `include` globs are configured.
+const SOURCE_EXTENSIONS: [&str; 30] = [
+    ".ts",
+    ".tsx",
+    ".js",
+    ".jsx",
+    ".vue",
+    ".svelte",
+    ".astro",
+    ".mdx",
+    ".html",
+    ".php",
+    ".blade.php",
+    ".dart",
+    ".rb",
+    ".erb",
+    ".haml",
+    ".slim",
+    ".py",
+    ".jinja",
+    ".jinja2",
+    ".j2",
+    ".go",
+    ".ex",
+    ".exs",
+    ".eex",
+    ".heex",
+    ".kt",
+    ".kts",
+    ".java",
+    ".swift",
+    ".rs",
+];
+
+/// Walks a workspace the way `git` sees it: `.gitignore`, `.ignore` and `.git/info/exclude`
+/// are honoured (even outside a git repository), `.git` and `node_modules` are always skipped,
+/// and the `include`/`exclude` globs of `I18nConfig` are matched relative to the root.
+#[derive(Debug, Clone)]
+pub struct WorkspaceWalker {
+    root: PathBuf,
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+}
+
+impl WorkspaceWalker {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            include: None,
+            exclude: None,
+        }
+    }
+
+    /// Only scan source files matching these globs instead of the default extensions.
+    pub fn with_include(mut self, patterns: &[String]) -> Self {
+        self.include = build_glob_set(patterns);
+        self
+    }
+
+    /// Skip files and directories matching these globs.
+    pub fn with_exclude(mut self, patterns: &[String]) -> Self {
+        self.exclude = build_glob_set(patterns);
+        self
+    }
+
+    /// Files and directories under `dir` (itself included) that are neither ignored nor
+    /// excluded, sorted by name within each directory. `include` globs do not apply.
+    pub fn walk(&self, dir: &Path) -> Vec<PathBuf> {
+        let root = self.root.clone();
+        let exclude = self.exclude.clone();
+
+        WalkBuilder::new(dir)
+            .hidden(false)
+            .require_git(false)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(move |entry| {
+                let is_skipped_dir = entry.file_type().is_some_and(|kind| kind.is_dir())
+                    && matches!(entry.file_name().to_str(), Some(".git" | "node_modules"));
+                !is_skipped_dir
+                    && !exclude
+                        .as_ref()
+                        .is_some_and(|exclude| exclude.is_match(relative_to(&root, entry.path())))
+            })
+            .build()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.into_path())
+            .collect()
+    }
+
+    /// Source files under `dir`: those matching the `include` globs, or with a supported
+    /// extension when none are configured.
+    pub fn source_files(&self, dir: &Path) -> Vec<PathBuf> {
+        self.walk(dir)
+            .into_iter()
+            .filter(|path| path.is_file() && self.is_source_file(path))
+            .collect()
+    }
+
+    fn is_source_file(&self, path: &Path) -> bool {
+        match self.include.as_ref() {
+            Some(include) => include.is_match(relative_to(&self.root, path)),
+            None => has_source_extension(path),
+        }
+    }
+}
+
+pub fn has_source_extension(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| SOURCE_EXTENSIONS.iter().any(|ext| name.ends_with(ext)))
+}
+
+fn relative_to<'a>(root: &Path, path: &'a Path) -> &'a Path {
+    path.strip_prefix(root).unwrap_or(path)
+}
+
+pub(crate) fn build_glob_set(patterns: &[String]) -> Option<GlobSet> {
+    if patterns.is_empty() {
+        return None;
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        match Glob::new(pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(e) => tracing::warn!("Invalid glob {}: {}", pattern, e),
+        }
+    }
+    builder.build().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    fn test_workspace(name: &str) -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time before unix epoch")
+            .as_nanos();
+        std::env::temp_dir().join(format!("intl-lens-{name}-{unique}"))
+    }
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).expect("create parent dir");
+        fs::write(path, content).expect("write file");
+    }
+
+    fn relative_files(root: &Path, files: Vec<PathBuf>) -> Vec<String> {
+        let mut files: Vec<String> = files
+            .iter()
+            .map(|path| relative_to(root, path).to_string_lossy().replace('\\', "/"))
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_source_files_respect_gitignore_and_globs() {
+        let root = test_workspace("walker");
+        write(&root, ".gitignore", "dist/\n*.generated.ts\n");
+        write(&root, "src/components/BuildStatus.tsx", "");
+        write(&root, "src/api.generated.ts", "");
+        write(&root, "src/legacy/Old.tsx", "");
+        write(&root, "src/styles.css", "");
+        write(&root, "dist/bundle.js", "");
+        write(&root, "node_modules/lib/index.js", "");
+
+        let walker = WorkspaceWalker::new(&root).with_exclude(&["src/legacy/**".to_string()]);
+        assert_eq!(
+            relative_files(&root, walker.source_files(&root)),
+            vec!["src/components/BuildStatus.tsx"]
+        );
+
+        let walker = WorkspaceWalker::new(&root).with_include(&["**/*.css".to_string()]);
+        assert_eq!(
+            relative_files(&root, walker.source_files(&root)),
+            vec!["src/styles.css"]
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+}
@@ -180,6 +180,422 @@ fn ignore_file_suppresses_issues_used_only_by_ignored_files() {
     .stdout(contains("\"missing_translations\": 0"));
 }
 
+#[test]
+fn template_keys_count_as_usages_and_follow_dynamic_key_policy() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"status":{"active":"Active","closed":"Closed"},"legacy":{"title":"Legacy"}}"#,
+        ),
+        (
+            "src/App.tsx",
+            r#"export const App = ({ status, code, label }) => [t(`status.${status}`), t(`errors.${code}`), t(label)];"#,
+        ),
+    ]);
+
+    let assert = run_json(workspace.path(), &["audit"]).failure();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    let unused: Vec<&str> = report["unused"]
+        .as_array()
+        .expect("unused keys")
+        .iter()
+        .filter_map(|item| item["key"].as_str())
+        .collect();
+    assert_eq!(unused, vec!["legacy.title"]);
+    assert_eq!(report["summary"]["dynamic_keys"], 2);
+    // `status.` matches translation keys, so only the prefix without matches is reported.
+    assert_eq!(report["dynamic_keys"][0]["kind"], "template");
+    assert_eq!(report["dynamic_keys"][0]["key"], "errors.");
+    assert_eq!(report["dynamic_keys"][0]["matched_keys"], 0);
+    assert_eq!(report["dynamic_keys"][1]["kind"], "dynamic");
+    assert_eq!(report["dynamic_keys"][1]["key"], "label");
+
+    run_json(workspace.path(), &["ci", "--fail-on", "dynamic"]).failure();
+
+    fs::write(
+        workspace.path().join(".i18nlens.json"),
+        r#"{"localePaths":["locales"],"dynamicKeyPolicy":"error","allowedDynamicPrefixes":["errors.","legacy."]}"#,
+    )
+    .expect("config");
+    run_json(workspace.path(), &["ci"])
+        .failure()
+        .stdout(contains("\"unused_keys\": 0"))
+        .stdout(contains("\"dynamic_keys\": 1"));
+
+    fs::write(
+        workspace.path().join(".i18nlens.json"),
+        r#"{"localePaths":["locales"],"dynamicKeyPolicy":"ignore"}"#,
+    )
+    .expect("config");
+    run_json(workspace.path(), &["ci", "--fail-on", "dynamic"])
+        .success()
+        .stdout(contains("\"dynamic_keys\": 0"));
+}
+
+#[test]
+fn duplicate_keys_across_locale_files_are_reported() {
+    let workspace = write_workspace(&[
+        ("locales/en.json", "{\n  \"title\": \"Home\"\n}\n"),
+        (
+            "locales/en/common.json",
+            "{\n  \"save\": \"Save\",\n  \"title\": \"Start\"\n}\n",
+        ),
+        (
+            "src/App.tsx",
+            r#"export const App = () => [t("title"), t("save")];"#,
+        ),
+    ]);
+
+    let assert = run_json(workspace.path(), &["ci"]).success();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    assert_eq!(report["summary"]["duplicate_keys"], 1);
+    let duplicate = &report["duplicate_keys"][0];
+    assert_eq!(duplicate["key"], "title");
+    assert_eq!(duplicate["locale"], "en");
+    assert_eq!(duplicate["conflicting"], true);
+    let files: Vec<String> = duplicate["defined_in"]
+        .as_array()
+        .expect("definitions")
+        .iter()
+        .map(|location| {
+            let file = location["file_path"].as_str().expect("file path");
+            format!("{}:{}", file.replace('\\', "/"), location["line"])
+        })
+        .collect();
+    assert_eq!(files.len(), 2);
+    assert!(files[0].ends_with("locales/en/common.json:2"));
+    assert!(files[1].ends_with("locales/en.json:1"));
+
+    run_json(workspace.path(), &["ci", "--fail-on", "duplicate"]).failure();
+    run_json(
+        workspace.path(),
+        &[
+            "ci",
+            "--fail-on",
+            "duplicate",
+            "--ignore-file",
+            "locales/en/**",
+        ],
+    )
+    .success()
+    .stdout(contains("\"duplicate_keys\": 0"));
+
+    // A glob overlapping the locale directory loads each file once.
+    fs::write(
+        workspace.path().join(".i18nlens.json"),
+        r#"{"localePaths":["locales","locales/*.json"],"sourceLocale":"en"}"#,
+    )
+    .expect("config");
+    fs::remove_file(workspace.path().join("locales/en/common.json")).expect("remove common");
+    run_json(workspace.path(), &["ci", "--fail-on", "duplicate"])
+        .success()
+        .stdout(contains("\"duplicate_keys\": 0"));
+}
+
+#[test]
+fn scanner_respects_gitignore_and_exclude_globs() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"build":{"status":"Build"},"legacy":{"title":"Legacy"},"generated":{"label":"Generated"}}"#,
+        ),
+        (
+            "src/components/BuildStatus.tsx",
+            r#"export const BuildStatus = () => t("build.status");"#,
+        ),
+        (
+            "src/legacy/Old.tsx",
+            r#"export const Old = () => t("legacy.title");"#,
+        ),
+        (
+            "src/generated/api.ts",
+            r#"export const label = t("generated.label");"#,
+        ),
+        (".gitignore", "src/generated/\n"),
+        (
+            ".i18nlens.json",
+            r#"{"localePaths":["locales"],"exclude":["src/legacy/**"]}"#,
+        ),
+    ]);
+
+    let assert = run_json(workspace.path(), &["audit"]).failure();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    let mut unused: Vec<&str> = report["unused"]
+        .as_array()
+        .expect("unused keys")
+        .iter()
+        .filter_map(|item| item["key"].as_str())
+        .collect();
+    unused.sort();
+    assert_eq!(unused, vec!["generated.label", "legacy.title"]);
+}
+
+#[test]
+fn audit_scans_python_ruby_go_and_elixir_sources() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"Welcome back":"Welcome back","users":{"created":"User created"},"PersonCats":"{{.Name}} has cats","Hello":"Hello","unused":{"key":"Unused"}}"#,
+        ),
+        ("app/views.py", r#"title = _("Welcome back")"#),
+        (
+            "app/controllers/users_controller.rb",
+            r#"flash[:notice] = I18n.t("users.created")"#,
+        ),
+        (
+            "cmd/main.go",
+            r#"msg := localizer.MustLocalize(&i18n.LocalizeConfig{MessageID: "PersonCats"})"#,
+        ),
+        ("lib/app_web/page.ex", r#"gettext("Hello")"#),
+    ]);
+
+    let assert = run_json(workspace.path(), &["audit"]).failure();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    let unused: Vec<&str> = report["unused"]
+        .as_array()
+        .expect("unused keys")
+        .iter()
+        .filter_map(|item| item["key"].as_str())
+        .collect();
+    assert_eq!(unused, vec!["unused.key"]);
+    assert_eq!(report["missing"].as_array().map(Vec::len), Some(0));
+}
+
+#[test]
+fn detected_presets_keep_default_patterns_for_other_languages() {
+    let workspace = write_workspace(&[
+        ("package.json", r#"{"dependencies":{"i18next":"23.0.0"}}"#),
+        (
+            "locales/en.json",
+            r#"{"users":{"title":"Users","created":"User created"},"nav":{"home":"Home"}}"#,
+        ),
+        (
+            "app/views/users/index.html.erb",
+            r#"<h1><%= t("users.title") %></h1>"#,
+        ),
+        (
+            "app/controllers/users_controller.rb",
+            r#"flash[:notice] = I18n.t("users.created")"#,
+        ),
+        (
+            "app/javascript/nav.js",
+            r#"export const home = () => i18next.t("nav.home");"#,
+        ),
+    ]);
+
+    let assert = run_json(workspace.path(), &["audit"]).success();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    assert_eq!(report["summary"]["unused_keys"], 0);
+    assert_eq!(report["summary"]["missing_translations"], 0);
+}
+
+#[test]
+fn audit_scans_kotlin_swift_and_rust_sources() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"app_title":"App","settings":{"title":"Settings","save":"Save"},"hello":"Hello","unused":{"key":"Unused"}}"#,
+        ),
+        (
+            "android/app/src/main/java/com/example/MainActivity.kt",
+            r#"val title = getString(R.string.app_title)"#,
+        ),
+        (
+            "ios/App/SettingsView.swift",
+            r#"VStack { Text("settings.title"); Button("settings.save") { save() } }"#,
+        ),
+        ("src/main.rs", r#"println!("{}", t!("hello"));"#),
+    ]);
+
+    let assert = run_json(workspace.path(), &["audit"]).failure();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    let unused: Vec<&str> = report["unused"]
+        .as_array()
+        .expect("unused keys")
+        .iter()
+        .filter_map(|item| item["key"].as_str())
+        .collect();
+    assert_eq!(unused, vec!["unused.key"]);
+    assert_eq!(report["missing"].as_array().map(Vec::len), Some(0));
+}
+
+#[test]
+fn audit_scans_html_astro_and_mdx_templates() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"nav":{"home":"Home","docs":"Docs"},"hero":{"title":"Title"},"intro":"Intro","unused":{"key":"Unused"}}"#,
+        ),
+        (
+            "src/app/nav/nav.component.html",
+            r#"<a i18n="@@nav.home">Home</a> <a>{{ 'nav.docs' | translate }}</a>"#,
+        ),
+        (
+            "src/pages/index.astro",
+            "---
+const title = t(\"hero.title\");
+---
+<h1>{title}</h1>",
+        ),
+        ("docs/intro.mdx", r#"<Trans i18nKey="intro" />"#),
+    ]);
+
+    let assert = run_json(workspace.path(), &["audit"]).failure();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+    let unused: Vec<&str> = report["unused"]
+        .as_array()
+        .expect("unused keys")
+        .iter()
+        .filter_map(|item| item["key"].as_str())
+        .collect();
+    assert_eq!(unused, vec!["unused.key"]);
+    assert_eq!(report["missing"].as_array().map(Vec::len), Some(0));
+}
+
+#[test]
+fn audit_index_cache_picks_up_changed_files() {
+    let workspace = write_workspace(&[
+        (".i18nlens.json", r#"{"cache":true}"#),
+        (
+            "locales/en.json",
+            r#"{"home":{"title":"Home"},"about":{"title":"About"}}"#,
+        ),
+        (
+            "src/App.tsx",
+            r#"export const App = () => t("home.title");"#,
+        ),
+    ]);
+
+    let unused_keys = |assert: assert_cmd::assert::Assert| -> Vec<String> {
+        let report: Value =
+            serde_json::from_slice(&assert.get_output().stdout).expect("audit report json");
+        report["unused"]
+            .as_array()
+            .expect("unused keys")
+            .iter()
+            .filter_map(|item| item["key"].as_str().map(str::to_string))
+            .collect()
+    };
+
+    let first = unused_keys(run_json(workspace.path(), &["audit"]).failure());
+    assert_eq!(first, vec!["about.title"]);
+    assert!(workspace
+        .path()
+        .join(".i18nlens/cache/index.json")
+        .is_file());
+    assert_eq!(
+        fs::read_to_string(workspace.path().join(".i18nlens/cache/.gitignore"))
+            .expect("cache gitignore"),
+        "*\n"
+    );
+
+    fs::write(
+        workspace.path().join("src/App.tsx"),
+        r#"export const App = () => [t("home.title"), t("about.title")];"#,
+    )
+    .expect("rewrite source");
+    let second = unused_keys(run_json(workspace.path(), &["audit"]).success());
+    assert!(second.is_empty());
+}
+
+#[test]
+fn audit_output_is_identical_across_runs() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"a":"A","b":"B","c":"C","d":"D","e":"E","f":"F","g":"G","h":"H"}"#,
+        ),
+        ("locales/de.json", r#"{"a":"A","h":"H"}"#),
+        ("locales/fr.json", r#"{"b":"B","g":"G"}"#),
+        ("locales/vi.json", r#"{"c":"C"}"#),
+        (
+            "src/App.tsx",
+            r#"export const App = () => [t("a"), t("c"), t("e"), t("g"), t("x"), t("y"), t("z")];"#,
+        ),
+    ]);
+
+    let output = || {
+        run_json(workspace.path(), &["audit"])
+            .failure()
+            .get_output()
+            .stdout
+            .clone()
+    };
+    let first = output();
+    for _ in 0..3 {
+        assert_eq!(
+            String::from_utf8_lossy(&output()),
+            String::from_utf8_lossy(&first)
+        );
+    }
+}
+
+#[test]
+fn audit_leaves_no_cache_behind_by_default() {
+    let workspace = write_workspace(&[
+        ("locales/en.json", r#"{"home":{"title":"Home"}}"#),
+        (
+            "src/App.tsx",
+            r#"export const App = () => t("home.title");"#,
+        ),
+    ]);
+
+    run_json(workspace.path(), &["audit"]).success();
+    assert!(!workspace.path().join(".i18nlens").exists());
+}
+
+#[test]
+fn suppression_comments_and_locale_markers_skip_findings() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"@i18nlens-ignore":["fixtures.sample"],"fixtures":{"sample":"Sample"},"beta":{"banner":"New!"},"home":{"title":"Home"}}"#,
+        ),
+        ("locales/vi.json", r#"{"home":{"title":"Trang chủ"}}"#),
+        (
+            "src/App.tsx",
+            r#"// i18nlens-ignore-next-line
+export const Beta = () => [t("beta.banner"), t("beta.unreleased")];
+export const Home = ({ key }) => [t("home.title"), t(key)]; // i18nlens-ignore dynamic
+"#,
+        ),
+    ]);
+
+    let assert = run_json(
+        workspace.path(),
+        &["ci", "--fail-on", "missing,unused,dynamic"],
+    )
+    .success();
+    let report: Value =
+        serde_json::from_slice(&assert.get_output().stdout).expect("ci report json");
+    assert_eq!(report["summary"]["missing_translations"], 0);
+    assert_eq!(report["summary"]["unused_keys"], 0);
+    assert_eq!(report["summary"]["dynamic_keys"], 0);
+    assert_eq!(report["summary"]["suppressed"], 3);
+
+    let app = workspace.path().join("src/App.tsx");
+    run_json(workspace.path(), &["check", app.to_str().unwrap()]).success();
+
+    fs::write(
+        &app,
+        r#"export const Beta = () => [t("beta.banner"), t("beta.unreleased")];"#,
+    )
+    .expect("rewrite source");
+    run_json(workspace.path(), &["check", app.to_str().unwrap()])
+        .failure()
+        .stdout(contains("beta.unreleased"));
+    run_json(workspace.path(), &["ci", "--fail-on", "missing"])
+        .failure()
+        .stdout(contains("\"missing_translations\": 1"));
+}
+
 #[test]
 fn baseline_write_and_read_suppresses_existing_issues() {
     let workspace = write_workspace(&[
@@ -341,6 +757,40 @@ fn fix_add_missing_writes_json_placeholder_values() {
     assert_eq!(json["checkout"]["submit"], "_TODO_");
 }
 
+#[test]
+fn fix_add_missing_preserves_jsonc_comments() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.jsonc",
+            "{\n  // Checkout flow\n  \"checkout\": {\n    \"submit\": \"Submit\", // primary button\n  },\n}\n",
+        ),
+        (
+            "locales/vi.jsonc",
+            "{\n  /* Vietnamese */\n  \"checkout\": {\n    \"title\": \"Thanh toán\" // header\n  }\n}\n",
+        ),
+        (
+            "src/App.tsx",
+            r#"export const App = () => [t("checkout.submit"), t("checkout.title")];"#,
+        ),
+    ]);
+
+    let mut command = i18nlens();
+    command
+        .arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--add-missing")
+        .arg("--placeholder")
+        .arg("_TODO_");
+    command.assert().success();
+
+    let content = fs::read_to_string(workspace.path().join("locales/vi.jsonc")).expect("vi jsonc");
+    assert_eq!(
+        content,
+        "{\n  /* Vietnamese */\n  \"checkout\": {\n    \"title\": \"Thanh toán\", // header\n    \"submit\": \"_TODO_\"\n  }\n}\n"
+    );
+}
+
 #[test]
 fn fix_add_missing_defaults_to_source_text() {
     let workspace = write_workspace(&[
@@ -391,6 +841,84 @@ fn fix_add_missing_writes_yaml_locale_files() {
     assert_eq!(yaml["checkout"]["submit"], "_TODO_");
 }
 
+#[test]
+fn fix_add_missing_writes_under_rails_yaml_locale_root() {
+    let workspace = write_workspace(&[
+        (
+            ".i18nlens.json",
+            r#"{"localePaths":["config/locales"],"sourceLocale":"en"}"#,
+        ),
+        (
+            "config/locales/en.yml",
+            "en:\n  users:\n    index:\n      title: Users\n",
+        ),
+        ("config/locales/vi.yml", "vi:\n  users:\n    new: Mới\n"),
+        (
+            "app/views/users/index.html.erb",
+            r#"<h1><%= t(".title") %></h1>"#,
+        ),
+    ]);
+
+    let mut command = i18nlens();
+    command
+        .arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--add-missing")
+        .arg("--placeholder")
+        .arg("_TODO_");
+    command
+        .assert()
+        .success()
+        .stdout(contains("Added 2 missing translations."));
+
+    let content = fs::read_to_string(workspace.path().join("config/locales/en.yml")).expect("en");
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&content).expect("updated yaml");
+    assert_eq!(yaml["en"]["users"]["new"], "_TODO_");
+
+    let content = fs::read_to_string(workspace.path().join("config/locales/vi.yml")).expect("vi");
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&content).expect("updated yaml");
+    assert_eq!(yaml["vi"]["users"]["index"]["title"], "_TODO_");
+    assert_eq!(yaml["vi"]["users"]["new"], "Mới");
+
+    let output = run_json(workspace.path(), &["audit"])
+        .get_output()
+        .stdout
+        .clone();
+    let report: Value = serde_json::from_slice(&output).expect("json report");
+    assert_eq!(report["summary"]["unused_keys"], 1);
+    assert_eq!(report["unused"][0]["key"], "users.new");
+}
+
+#[test]
+fn fix_add_missing_writes_laravel_json_keys_literally() {
+    let workspace = write_workspace(&[
+        (
+            ".i18nlens.json",
+            r#"{"localePaths":["lang"],"sourceLocale":"en"}"#,
+        ),
+        ("lang/en/auth.php", "<?php\nreturn [];\n"),
+        ("lang/en.json", r#"{"Log in.": "Log in."}"#),
+        ("lang/vi.json", r#"{}"#),
+        ("resources/views/login.blade.php", "{{ __('Log in.') }}"),
+    ]);
+
+    let mut command = i18nlens();
+    command
+        .arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--add-missing");
+    command
+        .assert()
+        .success()
+        .stdout(contains("Added 1 missing translations."));
+
+    let content = fs::read_to_string(workspace.path().join("lang/vi.json")).expect("vi json");
+    let json: Value = serde_json::from_str(&content).expect("updated json");
+    assert_eq!(json["Log in."], "Log in.");
+}
+
 #[test]
 fn fix_add_missing_writes_arb_locale_files() {
     let workspace = write_workspace(&[
@@ -518,6 +1046,32 @@ fn fix_sort_keys_sorts_nested_yaml_locale_files() {
     assert!(content.find("  a: A").unwrap() < content.find("  z: Z").unwrap());
 }
 
+#[test]
+fn fix_sort_keys_sorts_toml_locale_files() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.toml",
+            "z = \"Z\"\nm = \"M\"\n\n[a]\nz = \"Z\"\na = \"A\"\n",
+        ),
+        ("src/App.tsx", "export const App = () => null;"),
+    ]);
+
+    let mut command = i18nlens();
+    command
+        .arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--sort-keys");
+    command
+        .assert()
+        .success()
+        .stdout(contains("Sorted 1 translation files."));
+
+    let content = fs::read_to_string(workspace.path().join("locales/en.toml")).expect("en toml");
+    assert!(content.find("m = ").unwrap() < content.find("z = \"Z\"").unwrap());
+    assert!(content.find("a = \"A\"").unwrap() < content.rfind("z = \"Z\"").unwrap());
+}
+
 #[test]
 fn fix_sort_keys_sorts_arb_locale_files() {
     let workspace = write_workspace(&[
@@ -659,6 +1213,43 @@ fn fix_to_flat_converts_nested_yaml_keys() {
     assert_eq!(yaml["app"], "App");
 }
 
+#[test]
+fn fix_to_flat_and_nested_round_trip_toml_keys() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.toml",
+            "app = \"App\"\n\n[checkout]\nsubmit = \"Submit\"\ncancel = \"Cancel\"\n",
+        ),
+        ("src/App.tsx", "export const App = () => null;"),
+    ]);
+
+    let mut flat = i18nlens();
+    flat.arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--to-flat");
+    flat.assert()
+        .success()
+        .stdout(contains("Converted 1 translation files."));
+
+    let content = fs::read_to_string(workspace.path().join("locales/en.toml")).expect("en toml");
+    let table: toml::Table = toml::from_str(&content).expect("flat toml");
+    assert_eq!(table["checkout.submit"].as_str(), Some("Submit"));
+    assert_eq!(table["app"].as_str(), Some("App"));
+
+    let mut nested = i18nlens();
+    nested
+        .arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--to-nested");
+    nested.assert().success();
+
+    let content = fs::read_to_string(workspace.path().join("locales/en.toml")).expect("en toml");
+    let table: toml::Table = toml::from_str(&content).expect("nested toml");
+    assert_eq!(table["checkout"]["cancel"].as_str(), Some("Cancel"));
+}
+
 #[test]
 fn fix_conversion_runs_with_sort_keys() {
     let workspace = write_workspace(&[
@@ -748,3 +1339,156 @@ fn fix_to_nested_rejects_conflicting_json_keys() {
     let after = fs::read_to_string(workspace.path().join("locales/en.json")).expect("en json");
     assert_eq!(after, before);
 }
+
+#[test]
+fn check_resolves_vue_component_i18n_blocks() {
+    let workspace = write_workspace(&[
+        ("locales/en.json", r#"{"common":{"save":"Save"}}"#),
+        (
+            "src/Cart.vue",
+            r#"<template>
+  <h1>{{ t('cart.title') }}</h1>
+  <button>{{ t('common.save') }}</button>
+  <p>{{ t('cart.unknown') }}</p>
+</template>
+
+<i18n lang="json">
+{ "en": { "cart": { "title": "Cart" } } }
+</i18n>
+"#,
+        ),
+    ]);
+    let component = workspace.path().join("src/Cart.vue");
+
+    let output = run_json(workspace.path(), &["check", component.to_str().unwrap()])
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("json output");
+
+    let missing: Vec<&str> = json["missing"]
+        .as_array()
+        .expect("missing array")
+        .iter()
+        .map(|item| item["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(missing, vec!["cart.unknown"]);
+    assert_eq!(json["found"].as_array().expect("found array").len(), 2);
+}
+
+#[test]
+fn audit_resolves_i18next_plural_and_context_suffixes() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"item_one":"{{count}} item","item_other":"{{count}} items","friend_male":"A boyfriend","friend_female":"A girlfriend"}"#,
+        ),
+        (
+            "locales/ja.json",
+            r#"{"item_other":"{{count}} 個","friend_male":"彼氏","friend_female":"彼女"}"#,
+        ),
+        (
+            "src/App.tsx",
+            r#"export const App = () => [t("item", { count }), t("friend", { context: "male" })];"#,
+        ),
+    ]);
+
+    run_json(workspace.path(), &["audit"])
+        .success()
+        .stdout(contains("\"missing_translations\": 0"))
+        .stdout(contains("\"unused_keys\": 0"));
+
+    let component = workspace.path().join("src/App.tsx");
+    let output = run_json(workspace.path(), &["check", component.to_str().unwrap()])
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("json output");
+    assert!(json["missing"]
+        .as_array()
+        .expect("missing array")
+        .is_empty());
+}
+
+#[test]
+fn namespaces_resolve_scoped_keys_and_write_namespace_files() {
+    let workspace = write_workspace(&[
+        (
+            ".i18nlens.json",
+            r#"{"localePaths":["locales"],"sourceLocale":"en","namespaceEnabled":true,"defaultNamespace":"common"}"#,
+        ),
+        (
+            "locales/en/common.json",
+            r#"{"button":{"save":"Save","cancel":"Cancel"}}"#,
+        ),
+        ("locales/en/home.json", r#"{"title":"Home"}"#),
+        ("locales/vi/common.json", r#"{"button":{"save":"Lưu"}}"#),
+        ("locales/vi/home.json", r#"{"title":"Trang chủ"}"#),
+        (
+            "src/Home.tsx",
+            r#"export const Home = () => {
+  const { t } = useTranslation("home");
+  return [t("title"), t("common:button.save"), t("common:button.cancel"), t("home:missing")];
+};"#,
+        ),
+    ]);
+
+    let file = workspace.path().join("src/Home.tsx");
+    let output = run_json(workspace.path(), &["check", file.to_str().unwrap()])
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("json output");
+    let missing: Vec<&str> = json["missing"]
+        .as_array()
+        .expect("missing array")
+        .iter()
+        .map(|item| item["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(missing, vec!["home:missing"]);
+
+    let mut command = i18nlens();
+    command
+        .arg("--workspace")
+        .arg(workspace.path())
+        .arg("fix")
+        .arg("--add-missing");
+    command.assert().success();
+
+    let content =
+        fs::read_to_string(workspace.path().join("locales/vi/common.json")).expect("vi common");
+    let json: Value = serde_json::from_str(&content).expect("vi common json");
+    assert_eq!(json["button"]["cancel"], "Cancel");
+    assert!(json.get("common:button").is_none());
+}
+
+#[test]
+fn check_resolves_keys_of_scoped_translators() {
+    let workspace = write_workspace(&[
+        (
+            "locales/en.json",
+            r#"{"checkout":{"title":"Checkout","pay":"Pay"}}"#,
+        ),
+        (
+            "src/Checkout.tsx",
+            r#"export function Checkout() {
+  const t = useTranslations("checkout");
+  return [t("title"), t("pay"), t("total")];
+}"#,
+        ),
+    ]);
+
+    let file = workspace.path().join("src/Checkout.tsx");
+    let output = run_json(workspace.path(), &["check", file.to_str().unwrap()])
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("json output");
+    let missing: Vec<&str> = json["missing"]
+        .as_array()
+        .expect("missing array")
+        .iter()
+        .map(|item| item["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(missing, vec!["checkout.total"]);
+}